@@ -284,6 +284,10 @@ pub enum Commands {
         #[arg(long, default_value = ".")]
         repo_root: String,
 
+        /// Embed the contract into the witness as `contractSnapshot`
+        #[arg(long)]
+        embed_contract: bool,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
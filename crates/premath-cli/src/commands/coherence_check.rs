@@ -1,14 +1,21 @@
-use premath_coherence::{CoherenceWitness, run_coherence_check};
+use premath_coherence::{
+    CoherenceCheckOptions, CoherenceWitness, run_coherence_check_with_options,
+};
 use std::path::PathBuf;
 
-pub fn run(contract: String, repo_root: String, json_output: bool) {
+pub fn run(contract: String, repo_root: String, embed_contract: bool, json_output: bool) {
     let repo_root_path = PathBuf::from(repo_root);
     let contract_path = PathBuf::from(contract);
 
-    let witness = run_coherence_check(&repo_root_path, &contract_path).unwrap_or_else(|err| {
-        eprintln!("error: coherence-check failed: {err}");
-        std::process::exit(2);
-    });
+    let options = CoherenceCheckOptions {
+        embed_contract,
+        ..CoherenceCheckOptions::default()
+    };
+    let witness = run_coherence_check_with_options(&repo_root_path, &contract_path, options)
+        .unwrap_or_else(|err| {
+            eprintln!("error: coherence-check failed: {err}");
+            std::process::exit(2);
+        });
 
     if json_output {
         let rendered = serde_json::to_string_pretty(&witness).unwrap_or_else(|err| {
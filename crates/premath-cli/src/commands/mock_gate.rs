@@ -36,6 +36,7 @@ pub fn run(args: Args) {
             args.outcomes
         },
         constraints: None,
+        parent_run_id: None,
     };
     let intent_id = compute_intent_id(&intent_spec);
 
@@ -53,6 +54,8 @@ pub fn run(args: Args) {
         normalizer_id: args.normalizer_id,
         policy_digest: args.policy_digest,
         cover_strategy_digest: args.cover_strategy_digest,
+        ancestor_run_ids: Vec::new(),
+        options_digest: None,
     };
 
     let diagnostics = args
@@ -143,8 +143,9 @@ fn main() {
         Commands::CoherenceCheck {
             contract,
             repo_root,
+            embed_contract,
             json,
-        } => commands::coherence_check::run(contract, repo_root, json),
+        } => commands::coherence_check::run(contract, repo_root, embed_contract, json),
 
         Commands::ProposalCheck { proposal, json } => commands::proposal_check::run(proposal, json),
 
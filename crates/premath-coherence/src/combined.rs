@@ -0,0 +1,59 @@
+//! Coherence check followed by tusk descent evaluation, as one artifact.
+//!
+//! The descent pack is evaluated only once the coherence witness is
+//! accepted; a rejected contract short-circuits with `descent: None`.
+
+use crate::{CoherenceError, CoherenceWitness, run_coherence_check};
+use premath_tusk::{DescentPack, EvalOutcome, evaluate_descent_pack, map_tusk_failure_kind};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CombinedWitness {
+    /// `accepted` only when coherence is accepted and descent selects a glue
+    /// result without diagnostics.
+    pub result: String,
+    pub coherence: CoherenceWitness,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descent: Option<EvalOutcome>,
+    /// Coherence failure classes plus the Gate classes of descent diagnostics.
+    pub failure_classes: Vec<String>,
+}
+
+/// Run `run_coherence_check`, then `evaluate_descent_pack` on `pack` if the
+/// coherence witness is accepted.
+pub fn run_combined_check(
+    repo_root: impl AsRef<Path>,
+    contract_path: impl AsRef<Path>,
+    pack: &DescentPack,
+) -> Result<CombinedWitness, CoherenceError> {
+    let coherence = run_coherence_check(repo_root, contract_path)?;
+    let descent = (coherence.result == "accepted").then(|| evaluate_descent_pack(pack));
+
+    let mut failure_classes: BTreeSet<String> = coherence.failure_classes.iter().cloned().collect();
+    if let Some(outcome) = &descent {
+        failure_classes.extend(
+            outcome
+                .diagnostics
+                .iter()
+                .map(|diagnostic| map_tusk_failure_kind(diagnostic.kind).class.to_string()),
+        );
+    }
+    let descent_accepted = descent
+        .as_ref()
+        .is_some_and(|outcome| outcome.diagnostics.is_empty() && outcome.glue_result.is_some());
+
+    Ok(CombinedWitness {
+        result: if descent_accepted {
+            "accepted"
+        } else {
+            "rejected"
+        }
+        .to_string(),
+        coherence,
+        descent,
+        failure_classes: failure_classes.into_iter().collect(),
+    })
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// Versioned digest prefix scheme.
+///
+/// Every digest family carries the scheme version in its prefix
+/// (`cohctr{v}_`, `sem{v}_`, `sqw{v}_`, `sqlw{v}_`, `inputs{v}_`, `wit{v}_`,
+/// `semantic_digest_depth_exceeded{v}_`), so witnesses
+/// produced under different schemes never share a digest namespace. All
+/// versions hash with SHA-256; a version bump is how a future algorithm or
+/// canonicalization change is introduced. The scheme is passed explicitly to
+/// every digest helper; a coherence run uses its contract's `digestScheme`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestScheme {
+    pub version: u32,
+}
+
+impl Default for DigestScheme {
+    fn default() -> Self {
+        Self { version: 1 }
+    }
+}
+
+impl DigestScheme {
+    pub const fn new(version: u32) -> Self {
+        Self { version }
+    }
+
+    /// Prefix for a digest `family`, e.g. `sem` -> `sem1_`.
+    pub fn prefix(&self, family: &str) -> String {
+        format!("{family}{}_", self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_carries_the_scheme_version() {
+        assert_eq!(DigestScheme::default(), DigestScheme::new(1));
+        assert_eq!(DigestScheme::default().prefix("sem"), "sem1_");
+        assert_eq!(DigestScheme::new(3).prefix("sqlw"), "sqlw3_");
+    }
+}
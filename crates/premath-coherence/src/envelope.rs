@@ -0,0 +1,271 @@
+//! Signed coherence-contract envelopes.
+//!
+//! An envelope pins a contract to an HMAC-SHA-256 signature over the
+//! canonical JSON (sorted keys, no whitespace) of `{signer, contract}`, so a
+//! contract edited or relabeled between fetch and evaluation is rejected
+//! before any obligation runs. Loading verifies the signature over the
+//! contract exactly as it appears in the file, so fields the typed contract
+//! does not model are covered too; the loaded envelope keeps those signed
+//! bytes for `verify`.
+
+use crate::{CoherenceContract, CoherenceError, display_path, read_bytes};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use std::path::Path;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoherenceContractEnvelope {
+    pub contract: CoherenceContract,
+    /// Lowercase hex HMAC-SHA-256 of the canonical `{signer, contract}` JSON.
+    pub signature: String,
+    pub signer: String,
+    /// Contract JSON the signature covers, as loaded or signed; `Null` falls
+    /// back to the serialized typed contract.
+    #[serde(skip)]
+    signed_contract: Value,
+}
+
+impl CoherenceContractEnvelope {
+    pub fn sign(contract: CoherenceContract, key: &[u8], signer: &str) -> Self {
+        let signed_contract = contract_value(&contract);
+        let signature = contract_signature(&signed_contract, signer, key);
+        Self {
+            contract,
+            signature,
+            signer: signer.to_string(),
+            signed_contract,
+        }
+    }
+
+    /// Check the signature over the signer and signed contract JSON, and that
+    /// the typed contract still matches it.
+    pub fn verify(&self, key: &[u8]) -> bool {
+        let signed = match &self.signed_contract {
+            Value::Null => contract_value(&self.contract),
+            signed => signed.clone(),
+        };
+        signature_matches(&signed, &self.signer, &self.signature, key)
+            && serde_json::from_value::<CoherenceContract>(signed)
+                .is_ok_and(|contract| contract == self.contract)
+    }
+}
+
+#[derive(Deserialize)]
+struct RawEnvelope {
+    contract: Value,
+    signature: String,
+    signer: String,
+}
+
+/// Load an envelope from `path` and verify it was signed by `signer` with `key`.
+pub fn load_coherence_contract_envelope(
+    path: impl AsRef<Path>,
+    key: &[u8],
+    signer: &str,
+) -> Result<CoherenceContractEnvelope, CoherenceError> {
+    let path = path.as_ref();
+    let parse_error = |source| CoherenceError::ParseJson {
+        path: display_path(path),
+        source,
+    };
+    let raw: RawEnvelope = serde_json::from_slice(&read_bytes(path)?).map_err(parse_error)?;
+    if raw.signer != signer || !signature_matches(&raw.contract, &raw.signer, &raw.signature, key) {
+        return Err(CoherenceError::SignatureVerificationFailed {
+            path: display_path(path),
+            signer: raw.signer,
+        });
+    }
+    Ok(CoherenceContractEnvelope {
+        contract: serde_json::from_value(raw.contract.clone()).map_err(parse_error)?,
+        signature: raw.signature,
+        signer: raw.signer,
+        signed_contract: raw.contract,
+    })
+}
+
+fn contract_value(contract: &CoherenceContract) -> Value {
+    serde_json::to_value(contract).expect("contract should serialize")
+}
+
+fn contract_mac(contract: &Value, signer: &str, key: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(canonical_json(&json!({ "signer": signer, "contract": contract })).as_bytes());
+    mac
+}
+
+fn contract_signature(contract: &Value, signer: &str, key: &[u8]) -> String {
+    hex(&contract_mac(contract, signer, key).finalize().into_bytes())
+}
+
+fn signature_matches(contract: &Value, signer: &str, signature: &str, key: &[u8]) -> bool {
+    decode_hex(signature).is_some_and(|bytes| {
+        contract_mac(contract, signer, key)
+            .verify_slice(&bytes)
+            .is_ok()
+    })
+}
+
+fn canonical_json(value: &Value) -> String {
+    serde_json::to_string(&sort_json_value(value)).expect("contract json should serialize")
+}
+
+fn sort_json_value(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            Value::Object(
+                keys.into_iter()
+                    .map(|key| (key.clone(), sort_json_value(&map[key])))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.iter().map(sort_json_value).collect()),
+        _ => value.clone(),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Decode lowercase hex, rejecting odd lengths and any other character.
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2)
+        || !text
+            .bytes()
+            .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+    {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&text[index..index + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{TempDirGuard, real_contract, write_json_file};
+
+    #[test]
+    fn signature_is_hmac_sha256_over_signer_and_contract() {
+        let contract = json!({"contractId": "demo"});
+        let signature = contract_signature(&contract, "ci", b"Jefe");
+        assert_eq!(signature.len(), 64);
+        let mut mac = HmacSha256::new_from_slice(b"Jefe").expect("key");
+        mac.update(br#"{"contract":{"contractId":"demo"},"signer":"ci"}"#);
+        assert_eq!(signature, hex(&mac.finalize().into_bytes()));
+        assert!(signature_matches(&contract, "ci", &signature, b"Jefe"));
+        assert!(!signature_matches(
+            &contract,
+            "ci",
+            &signature.to_uppercase(),
+            b"Jefe"
+        ));
+        assert!(!signature_matches(
+            &contract,
+            "ci",
+            &signature[1..],
+            b"Jefe"
+        ));
+    }
+
+    #[test]
+    fn relabeling_the_signer_breaks_verification() {
+        let temp = TempDirGuard::new("contract-envelope-signer");
+        let path = temp.path().join("envelope.json");
+        let envelope = CoherenceContractEnvelope::sign(real_contract(), b"secret", "ci");
+        let mut relabeled = envelope.clone();
+        relabeled.signer = "release".to_string();
+        assert!(!relabeled.verify(b"secret"));
+
+        let mut payload = serde_json::to_value(&envelope).expect("envelope should serialize");
+        payload["signer"] = Value::String("release".to_string());
+        write_json_file(&path, &payload);
+        assert!(matches!(
+            load_coherence_contract_envelope(&path, b"secret", "release"),
+            Err(CoherenceError::SignatureVerificationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn envelope_verification_detects_single_field_tampering() {
+        let envelope = CoherenceContractEnvelope::sign(real_contract(), b"secret", "ci");
+        assert!(envelope.verify(b"secret"));
+        assert!(!envelope.verify(b"other-secret"));
+
+        let mut tampered = envelope.clone();
+        tampered.contract.binding.policy_digest.push('x');
+        assert!(!tampered.verify(b"secret"));
+
+        let mut tampered = envelope;
+        tampered.contract.enforce_obligation_order ^= true;
+        assert!(!tampered.verify(b"secret"));
+    }
+
+    #[test]
+    fn load_envelope_rejects_tampered_file_and_wrong_signer() {
+        let temp = TempDirGuard::new("contract-envelope");
+        let path = temp.path().join("envelope.json");
+        let envelope = CoherenceContractEnvelope::sign(real_contract(), b"secret", "ci");
+        let mut payload = serde_json::to_value(&envelope).expect("envelope should serialize");
+        write_json_file(&path, &payload);
+
+        let loaded = load_coherence_contract_envelope(&path, b"secret", "ci")
+            .expect("untampered envelope should load");
+        assert_eq!(loaded, envelope);
+        assert!(matches!(
+            load_coherence_contract_envelope(&path, b"secret", "someone-else"),
+            Err(CoherenceError::SignatureVerificationFailed { .. })
+        ));
+
+        payload["contract"]["contractId"] = Value::String("tampered".to_string());
+        write_json_file(&path, &payload);
+        assert!(matches!(
+            load_coherence_contract_envelope(&path, b"secret", "ci"),
+            Err(CoherenceError::SignatureVerificationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn load_envelope_signs_raw_contract_fields() {
+        let temp = TempDirGuard::new("contract-envelope-raw");
+        let path = temp.path().join("envelope.json");
+        let mut contract = contract_value(&real_contract());
+        contract["x-reviewer"] = Value::String("ci".to_string());
+        contract
+            .as_object_mut()
+            .expect("contract should be an object")
+            .remove("enforceObligationOrder");
+        let signature = contract_signature(&contract, "ci", b"secret");
+        let mut payload = json!({
+            "contract": contract,
+            "signature": signature,
+            "signer": "ci",
+        });
+        write_json_file(&path, &payload);
+        let loaded = load_coherence_contract_envelope(&path, b"secret", "ci")
+            .expect("raw-signed envelope should load");
+        assert_eq!(loaded.contract, real_contract());
+        assert!(loaded.verify(b"secret"));
+        assert!(!loaded.verify(b"other-secret"));
+
+        let mut tampered = loaded;
+        tampered.contract.binding.policy_digest.push('x');
+        assert!(!tampered.verify(b"secret"));
+
+        payload["contract"]["x-reviewer"] = Value::String("someone-else".to_string());
+        write_json_file(&path, &payload);
+        assert!(matches!(
+            load_coherence_contract_envelope(&path, b"secret", "ci"),
+            Err(CoherenceError::SignatureVerificationFailed { .. })
+        ));
+    }
+}
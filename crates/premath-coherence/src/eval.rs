@@ -0,0 +1,255 @@
+//! Per-obligation evaluators and digest helpers for fixture tooling.
+//!
+//! These wrap the evaluators `run_coherence_check` applies to each vector, so
+//! a fixture author can dry-run a `case.json` or compute the digests an
+//! `expect.json` should pin without running the whole contract.
+//!
+//! Stability: function names, signatures, and `CaseEvaluation` fields are
+//! kept stable. Failure class strings follow the coherence spec, and digest
+//! prefixes (`sem1_`, `sqw1_`, `sqlw1_`) follow the [`DigestScheme`] passed
+//! in; use the contract's `digest_scheme` to match what a run would pin. The
+//! shape of `details` is diagnostic and may grow between releases.
+
+use crate::{CoherenceError, DigestScheme, SiteEvaluation, TransportEvaluation};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Outcome of evaluating one fixture case.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaseEvaluation {
+    pub result: String,
+    pub failure_classes: Vec<String>,
+    pub details: Value,
+}
+
+impl From<SiteEvaluation> for CaseEvaluation {
+    fn from(evaluation: SiteEvaluation) -> Self {
+        Self {
+            result: evaluation.result,
+            failure_classes: evaluation.failure_classes,
+            details: evaluation.details,
+        }
+    }
+}
+
+impl From<TransportEvaluation> for CaseEvaluation {
+    fn from(evaluation: TransportEvaluation) -> Self {
+        Self {
+            result: evaluation.result,
+            failure_classes: evaluation.failure_classes,
+            details: evaluation.details,
+        }
+    }
+}
+
+/// Evaluate a `coherence-transport` case payload (the whole `case.json`).
+pub fn evaluate_transport_case(
+    case_payload: &Value,
+    case_path: &Path,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_transport_case(case_payload, case_path, scheme).map(CaseEvaluation::from)
+}
+
+/// Evaluate `coverage_base_change` site artifacts (`case.json` `artifacts`).
+pub fn evaluate_site_case_coverage_base_change(
+    artifacts: &Value,
+    case_path: &Path,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_coverage_base_change(artifacts, case_path, scheme)
+        .map(CaseEvaluation::from)
+}
+
+/// Evaluate `coverage_transitivity` site artifacts.
+pub fn evaluate_site_case_coverage_transitivity(
+    artifacts: &Value,
+    case_path: &Path,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_coverage_transitivity(artifacts, case_path, scheme)
+        .map(CaseEvaluation::from)
+}
+
+/// Evaluate `glue_or_witness_contractibility` site artifacts.
+pub fn evaluate_site_case_glue_or_witness_contractibility(
+    artifacts: &Value,
+    case_path: &Path,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_glue_or_witness_contractibility(artifacts, case_path, scheme)
+        .map(CaseEvaluation::from)
+}
+
+/// Evaluate `cwf_substitution_identity` site artifacts.
+pub fn evaluate_site_case_cwf_substitution_identity(
+    artifacts: &Value,
+    case_path: &Path,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_cwf_substitution_identity(artifacts, case_path, scheme)
+        .map(CaseEvaluation::from)
+}
+
+/// Evaluate `cwf_substitution_composition` site artifacts.
+pub fn evaluate_site_case_cwf_substitution_composition(
+    artifacts: &Value,
+    case_path: &Path,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_cwf_substitution_composition(artifacts, case_path, scheme)
+        .map(CaseEvaluation::from)
+}
+
+/// Evaluate `cwf_comprehension_beta` site artifacts.
+pub fn evaluate_site_case_cwf_comprehension_beta(
+    artifacts: &Value,
+    case_path: &Path,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_cwf_comprehension_beta(artifacts, case_path, scheme)
+        .map(CaseEvaluation::from)
+}
+
+/// Evaluate `cwf_comprehension_eta` site artifacts.
+pub fn evaluate_site_case_cwf_comprehension_eta(
+    artifacts: &Value,
+    case_path: &Path,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_cwf_comprehension_eta(artifacts, case_path, scheme)
+        .map(CaseEvaluation::from)
+}
+
+/// Evaluate `span_square_commutation` site artifacts. Pass the control-plane
+/// `spanKindRegistry` to enforce span kinds; `None` skips that check.
+pub fn evaluate_site_case_span_square_commutation(
+    artifacts: &Value,
+    case_path: &Path,
+    span_kind_registry: Option<&BTreeSet<String>>,
+    scheme: DigestScheme,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_span_square_commutation(
+        artifacts,
+        case_path,
+        span_kind_registry,
+        scheme,
+    )
+    .map(CaseEvaluation::from)
+}
+
+/// Evaluate `gate_chain_parity` site artifacts against the default
+/// control-plane `contractKind`.
+pub fn evaluate_site_case_gate_chain_parity(
+    artifacts: &Value,
+    case_path: &Path,
+) -> Result<CaseEvaluation, CoherenceError> {
+    evaluate_site_case_gate_chain_parity_with_contract_kind(
+        artifacts,
+        case_path,
+        crate::DEFAULT_CONTROL_PLANE_CONTRACT_KIND,
+    )
+}
+
+/// Evaluate `gate_chain_parity` site artifacts, requiring the control-plane
+/// contract to carry `expected_contract_kind`.
+pub fn evaluate_site_case_gate_chain_parity_with_contract_kind(
+    artifacts: &Value,
+    case_path: &Path,
+    expected_contract_kind: &str,
+) -> Result<CaseEvaluation, CoherenceError> {
+    crate::evaluate_site_case_gate_chain_parity(artifacts, case_path, expected_contract_kind)
+        .map(CaseEvaluation::from)
+}
+
+/// `sem1_` digest over the normalized semantics of `value`.
+pub fn semantic_digest(value: &Value, scheme: DigestScheme) -> String {
+    crate::semantic_digest(value, scheme)
+}
+
+/// `sqw1_` digest pinned by span-square witnesses.
+pub fn square_witness_digest(
+    top: &str,
+    bottom: &str,
+    left: &str,
+    right: &str,
+    result: &str,
+    failure_classes: &[String],
+    scheme: DigestScheme,
+) -> String {
+    crate::square_witness_digest(top, bottom, left, right, result, failure_classes, scheme)
+}
+
+/// `sqlw1_` digest pinned by span-square composition laws.
+pub fn composition_law_digest(
+    kind: &str,
+    law: &str,
+    left: &Value,
+    right: &Value,
+    result: &str,
+    failure_classes: &[String],
+    scheme: DigestScheme,
+) -> String {
+    crate::composition_law_digest(kind, law, left, right, result, failure_classes, scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DigestScheme, eval};
+    use serde_json::{Value, json};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn fixture_case(rel_path: &str) -> (PathBuf, Value) {
+        let case_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .and_then(|path| path.parent())
+            .expect("repo root should resolve")
+            .join("tests/conformance/fixtures")
+            .join(rel_path);
+        let bytes = fs::read(&case_path).expect("fixture case should be readable");
+        let payload = serde_json::from_slice(&bytes).expect("fixture case should parse");
+        (case_path, payload)
+    }
+
+    #[test]
+    fn facade_evaluates_site_and_transport_fixtures() {
+        let (case_path, case) =
+            fixture_case("coherence-site/golden/cwf_substitution_identity_accept/case.json");
+        let site = eval::evaluate_site_case_cwf_substitution_identity(
+            &case["artifacts"],
+            &case_path,
+            DigestScheme::default(),
+        )
+        .expect("site case should evaluate");
+        assert_eq!(site.result, "accepted");
+        assert!(site.failure_classes.is_empty());
+
+        let (case_path, case) =
+            fixture_case("coherence-transport/golden/functorial_transport_accept/case.json");
+        let transport = eval::evaluate_transport_case(&case, &case_path, DigestScheme::default())
+            .expect("transport case should evaluate");
+        assert_eq!(transport.result, "accepted");
+        assert!(transport.failure_classes.is_empty());
+    }
+
+    #[test]
+    fn facade_digests_match_crate_digests() {
+        let value = json!({"b": [2, 1], "a": "x"});
+        let scheme = DigestScheme::default();
+        assert_eq!(
+            eval::semantic_digest(&value, scheme),
+            crate::semantic_digest(&value, scheme)
+        );
+        assert!(eval::semantic_digest(&value, scheme).starts_with("sem1_"));
+        assert!(eval::semantic_digest(&value, DigestScheme::new(2)).starts_with("sem2_"));
+        let classes = vec!["coherence.test.failure".to_string()];
+        assert_eq!(
+            eval::square_witness_digest("t", "b", "l", "r", "rejected", &classes, scheme),
+            crate::square_witness_digest("t", "b", "l", "r", "rejected", &classes, scheme)
+        );
+    }
+}
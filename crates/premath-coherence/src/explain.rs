@@ -0,0 +1,356 @@
+use serde::Serialize;
+
+/// What a failure class means and how to clear it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassHelp {
+    pub description: &'static str,
+    pub remediation: &'static str,
+}
+
+/// `(class, description, remediation)`, sorted by class. Obligation-specific
+/// entries are keyed by the full `coherence.{obligation}.{kind}` class; generic
+/// families such as `surface_error` are keyed by the bare kind and apply to
+/// every obligation.
+const CLASS_HELP: &[(&str, &str, &str)] = &[
+    (
+        "coherence.capability_parity.conformance_capability_mismatch",
+        "The conformance doc's capability section differs from the executable registry.",
+        "list exactly the registry's executableCapabilities under surfaces.conformanceCapabilityHeading",
+    ),
+    (
+        "coherence.capability_parity.conformance_readme_section_missing",
+        "The conformance README lacks the configured capability section start marker.",
+        "add surfaces.capabilityReadmeSectionStart and its end marker around the conformance README capability list",
+    ),
+    (
+        "coherence.capability_parity.conformance_readme_set_mismatch",
+        "The conformance README capability list differs from the executable registry.",
+        "sync the conformance README capability list with CAPABILITY-REGISTRY.json",
+    ),
+    (
+        "coherence.capability_parity.manifest_set_mismatch",
+        "The capability manifest set differs from the executable registry.",
+        "add or remove capability manifests to match CAPABILITY-REGISTRY.json",
+    ),
+    (
+        "coherence.capability_parity.readme_section_missing",
+        "The README lacks the configured capability section start marker.",
+        "add surfaces.capabilityReadmeSectionStart and its end marker around the README capability list",
+    ),
+    (
+        "coherence.capability_parity.readme_set_mismatch",
+        "The README capability list differs from the executable registry.",
+        "sync the README capability list with CAPABILITY-REGISTRY.json",
+    ),
+    (
+        "coherence.capability_parity.spec_index_set_mismatch",
+        "The SPEC-INDEX capability section differs from the executable registry.",
+        "sync the SPEC-INDEX capability section with CAPABILITY-REGISTRY.json",
+    ),
+    (
+        "coherence.gate_chain_parity.baseline_set_mismatch",
+        "The mise baseline task set differs from the CI closure baseline section.",
+        "make the CI-CLOSURE baseline list match the tasks run by the mise baseline task",
+    ),
+    (
+        "coherence.gate_chain_parity.evidence_factorization_ambiguous",
+        "Evidence factorization names more than one canonical route.",
+        "ensure evidenceFactorization.factorizationRoutes and crossLaneRoutes name exactly one canonical route",
+    ),
+    (
+        "coherence.gate_chain_parity.evidence_factorization_invalid",
+        "Evidence factorization is malformed or maps to non-canonical classes.",
+        "ensure evidenceFactorization.profileKind is set, routeKind is `eta_F_to_Ev`, and failureClasses are canonical",
+    ),
+    (
+        "coherence.gate_chain_parity.evidence_factorization_missing",
+        "Evidence factorization lacks a required route.",
+        "ensure evidenceFactorization.factorizationRoutes is non-empty and crossLaneRoutes.pullbackBaseChange is `span_square_commutation`",
+    ),
+    (
+        "coherence.gate_chain_parity.evidence_factorization_unbound",
+        "Evidence factorization is not bound to the normalizer and policy digest.",
+        "ensure evidenceFactorization.binding refs are `normalizerId` and `policyDigest`",
+    ),
+    (
+        "coherence.gate_chain_parity.instruction_witness_shape_invalid",
+        "The instruction witness declaration is incomplete.",
+        "ensure instructionWitness.witnessKind, policyKind and policyDigestPrefix are non-empty",
+    ),
+    (
+        "coherence.gate_chain_parity.lane_artifact_kind_cross_lane_duplicate",
+        "One artifact kind is claimed by more than one lane.",
+        "list each artifact kind under a single lane in laneArtifactKinds",
+    ),
+    (
+        "coherence.gate_chain_parity.lane_artifact_kind_unknown",
+        "A lane declares an artifact kind outside the known set.",
+        "use a kind from allowedLaneArtifactKinds or add the new kind there",
+    ),
+    (
+        "coherence.gate_chain_parity.projection_check_order_empty",
+        "The control-plane projection lists no checks.",
+        "ensure requiredGateProjection.checkOrder lists the projected check ids",
+    ),
+    (
+        "coherence.gate_chain_parity.projection_policy_invalid",
+        "The control-plane projection policy is empty.",
+        "ensure requiredGateProjection.projectionPolicy is set",
+    ),
+    (
+        "coherence.gate_chain_parity.projection_set_mismatch",
+        "The projected check set differs from the CI closure projection section.",
+        "make requiredGateProjection.checkOrder and the CI-CLOSURE projected check ids agree",
+    ),
+    (
+        "coherence.gate_chain_parity.required_witness_shape_invalid",
+        "The required witness declaration is incomplete.",
+        "ensure requiredWitness.witnessKind and decisionKind are non-empty",
+    ),
+    (
+        "coherence.gate_chain_parity.schema_lifecycle_freeze_reason_too_long",
+        "The schema freeze reason exceeds the length limit.",
+        "shorten schemaLifecycle.governance.freezeReason to at most 256 characters",
+    ),
+    (
+        "coherence.gate_chain_parity.schema_lifecycle_freeze_reason_too_short",
+        "The schema freeze reason is too short to be meaningful.",
+        "ensure schemaLifecycle.governance.freezeReason is a phrase of at least 10 characters",
+    ),
+    (
+        "coherence.gate_chain_parity.schema_lifecycle_invalid",
+        "Schema lifecycle governance or kind resolution is inconsistent.",
+        "see details.structuredReasons for the offending schemaLifecycle field",
+    ),
+    (
+        "coherence.gate_chain_parity.section_absent",
+        "A section the gate chain reads from is missing.",
+        "restore the section markers named in the contract surfaces",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_parity_invalid",
+        "Stage 1 parity is malformed.",
+        "ensure evidenceStage1Parity.profileKind is non-empty",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_parity_mismatch",
+        "Stage 1 parity maps to non-canonical failure classes.",
+        "ensure evidenceStage1Parity.failureClasses uses the canonical unification.evidence_stage1.parity.* classes",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_parity_missing",
+        "Stage 1 parity lacks its route or comparison refs.",
+        "ensure evidenceStage1Parity.authorityToTypedCoreRoute and comparisonTuple digest refs are set",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_parity_unbound",
+        "Stage 1 parity is not bound to the normalizer and policy digest.",
+        "ensure evidenceStage1Parity.comparisonTuple refs are `normalizerId` and `policyDigest`",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_rollback_failure_class_mismatch",
+        "Stage 1 rollback maps to non-canonical failure classes.",
+        "ensure evidenceStage1Rollback.failureClasses uses the canonical unification.evidence_stage1.rollback.* classes",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_rollback_invalid",
+        "Stage 1 rollback is malformed.",
+        "ensure evidenceStage1Rollback.profileKind and witnessKind are set and it rolls back from `stage1` to `stage0`",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_rollback_precondition_missing",
+        "Stage 1 rollback lacks its trigger classes or identity refs.",
+        "ensure evidenceStage1Rollback.triggerFailureClasses covers every parity class and identityRefs are distinct",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_rollback_trigger_drift",
+        "Stage 1 rollback triggers differ from the stage 1 parity classes.",
+        "make evidenceStage1Rollback.triggerFailureClasses match evidenceStage1Parity.failureClasses",
+    ),
+    (
+        "coherence.gate_chain_parity.stage1_rollback_unbound",
+        "Stage 1 rollback is not bound to the normalizer and policy digest.",
+        "ensure evidenceStage1Rollback.identityRefs are `normalizerId` and `policyDigest`",
+    ),
+    (
+        "coherence.gate_chain_parity.stage2_authority_alias_violation",
+        "The stage 2 compatibility alias is not a projection-only alias of typed authority.",
+        "ensure evidenceStage2Authority.compatibilityAlias.role is `projection_only` and its digest ref differs from typedAuthority",
+    ),
+    (
+        "coherence.gate_chain_parity.stage2_authority_alias_window_violation",
+        "The stage 2 compatibility alias window is invalid or expired.",
+        "align evidenceStage2Authority.compatibilityAlias.supportUntilEpoch with the schemaLifecycle rollover epoch",
+    ),
+    (
+        "coherence.gate_chain_parity.stage2_authority_invalid",
+        "Stage 2 authority is malformed.",
+        "ensure evidenceStage2Authority.profileKind is set, activeStage is `stage2`, and failureClasses are canonical",
+    ),
+    (
+        "coherence.gate_chain_parity.stage2_authority_unbound",
+        "Stage 2 typed authority is not bound to the normalizer and policy digest.",
+        "ensure evidenceStage2Authority.typedAuthority refs are `normalizerId` and `policyDigest`",
+    ),
+    (
+        "coherence.gate_chain_parity.stage2_kernel_compliance_drift",
+        "Stage 2 kernel compliance routing drifts from the canonical BIDIR obligations.",
+        "make evidenceStage2Authority.bidirEvidenceRoute and kernelComplianceSentinel match the canonical Stage 2 kernel obligations",
+    ),
+    (
+        "coherence.gate_chain_parity.stage2_kernel_compliance_missing",
+        "Stage 2 kernel compliance omits required BIDIR obligations.",
+        "list every required obligation in evidenceStage2Authority.bidirEvidenceRoute.requiredObligations",
+    ),
+    (
+        "coherence.gate_chain_parity.stage_digest_ref_drift",
+        "Stage 2 typed authority names a different typed-core digest than Stage 1 parity.",
+        "make evidenceStage2Authority.typedAuthority.digestRef equal evidenceStage1Parity.comparisonTuple.typedCoreDigestRef",
+    ),
+    (
+        "coherence.gate_chain_parity.vector_missing_case_context",
+        "A gate chain vector does not bind its case context.",
+        "add `caseContext.obligationId` = `gate_chain_parity` to the vector artifacts",
+    ),
+    (
+        "coherence.glue_or_witness_contractibility.unknown_obstruction_class",
+        "A descent obstruction names a class outside the fixture's allowed set.",
+        "fix the obstruction `class` or add it to descent.allowedObstructionClasses",
+    ),
+    (
+        "coherence.glue_or_witness_contractibility.witness_references_unknown_local",
+        "A compatibility witness overlap names a local that is not declared.",
+        "make each witness `overlap` join ids from descent.locals with `_`",
+    ),
+    (
+        "coherence.scope_noncontradiction.profile_overlay_unsorted",
+        "The registry's profile overlay claims are not in sorted order.",
+        "sort CAPABILITY-REGISTRY.json profileOverlayClaims lexicographically",
+    ),
+    (
+        "duplicate_vector_id",
+        "A vector is listed more than once for the same obligation.",
+        "remove the repeated vector id from manifest.json obligationVectors",
+    ),
+    (
+        "manifest_empty",
+        "The fixture manifest declares no vectors.",
+        "add on-disk `vectors` or `inlineVectors` to manifest.json",
+    ),
+    (
+        "manifest_inline_vector_collision",
+        "An inline vector reuses the id of an on-disk vector.",
+        "rename the inline vector or drop the duplicate on-disk entry",
+    ),
+    (
+        "manifest_invalid_schema",
+        "The fixture manifest schema is not supported.",
+        "set manifest.json `schema` to 1",
+    ),
+    (
+        "manifest_invalid_status",
+        "The fixture manifest status is not recognized.",
+        "set manifest.json `status` to a known fixture status such as `executable`",
+    ),
+    (
+        "manifest_missing_obligation_vectors",
+        "The fixture manifest does not scope vectors to obligations.",
+        "add an `obligationVectors` map to manifest.json",
+    ),
+    (
+        "manifest_obligation_vector_not_declared",
+        "An obligation references a vector the manifest does not declare.",
+        "declare the vector under `vectors` or `inlineVectors`, or drop the reference",
+    ),
+    (
+        "manifest_unreferenced_vectors",
+        "Declared vectors are not claimed by any obligation.",
+        "reference each vector from `obligationVectors` or remove it from the manifest",
+    ),
+    (
+        "semantic_depth_exceeded",
+        "Obligation details nest deeper than semantic normalization allows, so they were digested raw.",
+        "flatten the offending fixture or artifact payload below 64 levels",
+    ),
+    (
+        "surface_error",
+        "A surface file named by the contract could not be read or parsed.",
+        "check the coherence contract `surfaces` paths exist and parse",
+    ),
+    (
+        "unknown_expected_failure_class",
+        "A fixture expects a failure class its evaluator never emits.",
+        "fix the class name in expect.json or drop it",
+    ),
+];
+
+/// Description and remediation hint for `class`: the exact entry when there
+/// is one, otherwise the generic entry for its last dot segment. `None` for
+/// classes outside the table.
+pub fn explain_class_with_remediation(class: &str) -> Option<ClassHelp> {
+    let lookup = |key: &str| {
+        CLASS_HELP
+            .binary_search_by_key(&key, |(key, _, _)| key)
+            .ok()
+    };
+    let index = lookup(class).or_else(|| lookup(class.rsplit('.').next()?))?;
+    let (_, description, remediation) = CLASS_HELP[index];
+    Some(ClassHelp {
+        description,
+        remediation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn class_help_table_is_sorted_and_unique() {
+        assert!(CLASS_HELP.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn stage2_alias_violation_has_remediation() {
+        let help = explain_class_with_remediation(
+            "coherence.gate_chain_parity.stage2_authority_alias_violation",
+        )
+        .expect("alias violation should be explained");
+        assert!(!help.description.is_empty());
+        assert!(
+            help.remediation
+                .contains("evidenceStage2Authority.compatibilityAlias.role is `projection_only`")
+        );
+
+        assert!(
+            explain_class_with_remediation("coherence.span_square_commutation.surface_error")
+                .is_some()
+        );
+        assert_eq!(
+            explain_class_with_remediation("coherence.unknown.nope"),
+            None
+        );
+    }
+
+    #[test]
+    fn obligation_specific_classes_do_not_match_other_obligations() {
+        assert!(
+            explain_class_with_remediation("coherence.capability_parity.readme_set_mismatch")
+                .is_some()
+        );
+        assert_eq!(
+            explain_class_with_remediation("coherence.gate_chain_parity.readme_set_mismatch"),
+            None
+        );
+        assert_eq!(
+            explain_class_with_remediation("coherence.transport_functoriality.manifest_empty"),
+            explain_class_with_remediation("coherence.span_square_commutation.manifest_empty")
+        );
+        assert!(
+            CLASS_HELP
+                .iter()
+                .all(|(key, _, _)| key.starts_with("coherence.") || !key.contains('.'))
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::CoherenceWitness;
+use std::collections::BTreeMap;
+
+/// Prefix tree over dot-separated failure class segments.
+///
+/// Prefixes match whole segments, so `coherence.gate_chain` does not match
+/// `coherence.gate_chain_parity.lane_unknown`. A trailing `.` or `.*` is
+/// accepted and ignored.
+#[derive(Debug, Clone, Default)]
+pub struct FailureClassTrie<'a> {
+    root: TrieNode<'a>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode<'a> {
+    class: Option<&'a String>,
+    children: BTreeMap<&'a str, TrieNode<'a>>,
+}
+
+impl<'a> FailureClassTrie<'a> {
+    pub fn from_classes(classes: &'a [String]) -> Self {
+        let mut root = TrieNode::default();
+        for class in classes {
+            let mut node = &mut root;
+            for segment in class.split('.') {
+                node = node.children.entry(segment).or_default();
+            }
+            node.class = Some(class);
+        }
+        Self { root }
+    }
+
+    /// Classes under `prefix`, in segment order. An empty prefix matches all.
+    pub fn prefix_matches(&self, prefix: &str) -> Vec<&'a String> {
+        let mut out = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            node.collect_into(&mut out);
+        }
+        out
+    }
+
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    fn find(&self, prefix: &str) -> Option<&TrieNode<'a>> {
+        let prefix = prefix.trim_end_matches('*').trim_end_matches('.');
+        if prefix.is_empty() {
+            return (!self.root.children.is_empty()).then_some(&self.root);
+        }
+        prefix
+            .split('.')
+            .try_fold(&self.root, |node, segment| node.children.get(segment))
+    }
+}
+
+impl<'a> TrieNode<'a> {
+    fn collect_into(&self, out: &mut Vec<&'a String>) {
+        if let Some(class) = self.class {
+            out.push(class);
+        }
+        for child in self.children.values() {
+            child.collect_into(out);
+        }
+    }
+}
+
+impl CoherenceWitness {
+    /// Aggregate failure classes under a dot-separated `prefix`.
+    pub fn failure_classes_with_prefix(&self, prefix: &str) -> Vec<&String> {
+        FailureClassTrie::from_classes(&self.failure_classes).prefix_matches(prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_classes() -> Vec<String> {
+        let namespaces = [
+            "gate_chain_parity",
+            "gate_chain",
+            "span_square_commutation",
+            "transport_functoriality",
+            "overlay_traceability",
+        ];
+        let kinds = [
+            "violation",
+            "manifest_empty",
+            "lane.unknown",
+            "lane.unbound",
+        ];
+        namespaces
+            .iter()
+            .flat_map(|namespace| {
+                kinds
+                    .iter()
+                    .map(move |kind| format!("coherence.{namespace}.{kind}"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn prefix_matches_whole_segments_across_namespaces() {
+        let classes = sample_classes();
+        assert_eq!(classes.len(), 20);
+        let trie = FailureClassTrie::from_classes(&classes);
+
+        assert_eq!(trie.prefix_matches("coherence").len(), 20);
+        assert_eq!(trie.prefix_matches("").len(), 20);
+        assert_eq!(
+            trie.prefix_matches("coherence.gate_chain_parity"),
+            vec![
+                "coherence.gate_chain_parity.lane.unbound",
+                "coherence.gate_chain_parity.lane.unknown",
+                "coherence.gate_chain_parity.manifest_empty",
+                "coherence.gate_chain_parity.violation",
+            ]
+        );
+        assert_eq!(trie.prefix_matches("coherence.gate_chain.*").len(), 4);
+        assert_eq!(
+            trie.prefix_matches("coherence.span_square_commutation.lane."),
+            vec![
+                "coherence.span_square_commutation.lane.unbound",
+                "coherence.span_square_commutation.lane.unknown",
+            ]
+        );
+        assert_eq!(
+            trie.prefix_matches("coherence.overlay_traceability.violation"),
+            vec!["coherence.overlay_traceability.violation"]
+        );
+
+        assert!(trie.has_prefix("coherence.transport_functoriality"));
+        assert!(!trie.has_prefix("coherence.transport"));
+        assert!(!trie.has_prefix("coherence.cwf_comprehension_eta"));
+        assert!(trie.prefix_matches("coherence.transport").is_empty());
+        assert!(!FailureClassTrie::from_classes(&[]).has_prefix(""));
+    }
+}
@@ -16,6 +16,9 @@ const POLICY_DIGEST_PREFIX: &str = "pol1_";
 const SUPPORTED_INSTRUCTION_TYPES: [&str; 3] =
     ["ci.gate.check", "ci.gate.pre_commit", "ci.gate.pre_push"];
 const INSTRUCTION_WITNESS_KIND: &str = "ci.instruction.v1";
+/// Rule names accepted by `InstructionTypingPolicy::with_override`, spelled as
+/// in the envelope's `typingPolicy` object.
+const TYPING_POLICY_RULES: &[&str] = &["allowUnknown"];
 
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 #[error("{failure_class}: {message}")]
@@ -33,12 +36,56 @@ impl InstructionError {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct InstructionTypingPolicy {
     pub allow_unknown: bool,
 }
 
+impl InstructionTypingPolicy {
+    pub fn builder() -> InstructionTypingPolicyBuilder {
+        InstructionTypingPolicyBuilder::default()
+    }
+
+    /// Set one typing rule by its envelope name (e.g. `allowUnknown`).
+    pub fn with_override(mut self, rule: &str, value: bool) -> Result<Self, InstructionError> {
+        match rule {
+            "allowUnknown" => self.allow_unknown = value,
+            _ => {
+                return Err(InstructionError::new(
+                    "instruction_typing_policy_invalid",
+                    format!(
+                        "typingPolicy rule {rule:?} is not one of {:?}",
+                        TYPING_POLICY_RULES
+                    ),
+                ));
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InstructionTypingPolicyBuilder {
+    policy: InstructionTypingPolicy,
+}
+
+impl InstructionTypingPolicyBuilder {
+    pub fn allow_unknown(mut self, allow_unknown: bool) -> Self {
+        self.policy.allow_unknown = allow_unknown;
+        self
+    }
+
+    pub fn with_override(mut self, rule: &str, value: bool) -> Result<Self, InstructionError> {
+        self.policy = self.policy.with_override(rule, value)?;
+        Ok(self)
+    }
+
+    pub fn build(self) -> InstructionTypingPolicy {
+        self.policy
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "state", rename_all = "snake_case")]
 pub enum InstructionClassification {
@@ -89,6 +136,31 @@ pub struct ValidatedInstructionEnvelope {
     pub proposal: Option<ValidatedInstructionProposal>,
 }
 
+impl ValidatedInstructionEnvelope {
+    /// Swap in `typing_policy` and re-derive the execution decision under it.
+    /// A policy that differs from the current one is folded into
+    /// `instruction_digest`, so witnesses built under an override never share
+    /// the envelope's digest.
+    pub fn with_typing_policy(mut self, typing_policy: InstructionTypingPolicy) -> Self {
+        if typing_policy != self.typing_policy {
+            self.instruction_digest = format!(
+                "instr1_{}",
+                stable_hash(&json!({
+                    "instructionDigest": self.instruction_digest,
+                    "typingPolicyOverride": typing_policy,
+                }))
+            );
+        }
+        self.execution_decision = derive_execution_decision(
+            &self.instruction_classification,
+            &typing_policy,
+            self.proposal.as_ref(),
+        );
+        self.typing_policy = typing_policy;
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ExecutedInstructionCheck {
@@ -1174,14 +1246,7 @@ pub fn validate_instruction_envelope_payload(
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn repo_root() -> PathBuf {
-        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .and_then(|path| path.parent())
-            .expect("repo root should resolve")
-            .to_path_buf()
-    }
+    use crate::tests::repo_root;
 
     fn runtime_for(instruction_id: &str, failed: bool) -> InstructionWitnessRuntime {
         InstructionWitnessRuntime {
@@ -1277,6 +1342,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn typing_policy_override_reroutes_unknown_instruction() {
+        let root = repo_root();
+        let fixture_path = root
+            .join("tests")
+            .join("ci")
+            .join("fixtures")
+            .join("instructions")
+            .join("20260221T010000Z-ci-wiring-golden.json");
+        let mut payload: Value =
+            serde_json::from_slice(&fs::read(&fixture_path).expect("fixture should be readable"))
+                .expect("fixture json should parse");
+        payload["instructionType"] = Value::String("ci.gate.unknown".to_string());
+        let checked = validate_instruction_envelope_payload(&payload, &fixture_path, &root)
+            .expect("fixture should validate with unknown classification");
+        // Like `tools/ci/run_instruction.py`, run the requested checks only
+        // when the execution decision allows it.
+        let runtime_under = |checked: &ValidatedInstructionEnvelope| {
+            let mut runtime = runtime_for("20260221T010000Z-ci-wiring-golden", false);
+            runtime.instruction_digest = checked.instruction_digest.clone();
+            if checked.execution_decision != InstructionExecutionDecision::Execute {
+                runtime.results.clear();
+            }
+            runtime
+        };
+
+        let strict = build_instruction_witness(&checked, runtime_under(&checked))
+            .expect("strict witness should build");
+        assert_eq!(strict.verdict_class, "rejected");
+        assert!(strict.results.is_empty());
+        assert!(strict.executed_checks.is_empty());
+
+        let policy = checked
+            .typing_policy
+            .clone()
+            .with_override("allowUnknown", true)
+            .expect("allowUnknown should be a known rule");
+        assert_eq!(
+            policy,
+            InstructionTypingPolicy::builder()
+                .allow_unknown(true)
+                .build()
+        );
+        let unchanged = checked
+            .clone()
+            .with_typing_policy(checked.typing_policy.clone());
+        assert_eq!(unchanged.instruction_digest, checked.instruction_digest);
+        let relaxed = checked.clone().with_typing_policy(policy);
+        assert_eq!(
+            relaxed.execution_decision,
+            InstructionExecutionDecision::Execute
+        );
+        assert!(relaxed.instruction_digest.starts_with("instr1_"));
+        assert_ne!(relaxed.instruction_digest, checked.instruction_digest);
+        let witness = build_instruction_witness(&relaxed, runtime_under(&relaxed))
+            .expect("relaxed witness should build");
+        assert_ne!(witness.instruction_digest, strict.instruction_digest);
+        assert_ne!(
+            witness.typed_core_projection_digest,
+            strict.typed_core_projection_digest
+        );
+        assert_eq!(witness.verdict_class, "accepted");
+        assert_eq!(
+            witness.results,
+            vec![ExecutedInstructionCheck {
+                check_id: "ci-wiring-check".to_string(),
+                status: "passed".to_string(),
+                exit_code: 0,
+                duration_ms: 25,
+            }]
+        );
+        assert_eq!(witness.executed_checks, relaxed.requested_checks);
+        assert_ne!(witness.executed_checks, strict.executed_checks);
+        assert!(witness.typing_policy.allow_unknown);
+
+        let err = InstructionTypingPolicy::builder()
+            .with_override("allowEverything", true)
+            .expect_err("unknown rule should fail");
+        assert_eq!(err.failure_class, "instruction_typing_policy_invalid");
+    }
+
     #[test]
     fn validate_instruction_envelope_rejects_on_proposal_discharge_before_execution() {
         let root = repo_root();
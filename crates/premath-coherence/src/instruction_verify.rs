@@ -0,0 +1,154 @@
+use crate::instruction::{
+    InstructionWitness, InstructionWitnessRuntime, ValidatedInstructionEnvelope,
+    build_instruction_witness,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionWitnessVerifyResult {
+    pub errors: Vec<String>,
+    /// Witness rebuilt from the envelope; absent when the recorded runtime
+    /// fields cannot be replayed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub derived: Option<InstructionWitness>,
+}
+
+fn runtime_from_witness(witness: &InstructionWitness) -> InstructionWitnessRuntime {
+    InstructionWitnessRuntime {
+        instruction_id: witness.instruction_id.clone(),
+        instruction_ref: witness.instruction_ref.clone(),
+        instruction_digest: witness.instruction_digest.clone(),
+        squeak_site_profile: witness.squeak_site_profile.clone(),
+        run_started_at: witness.run_started_at.clone(),
+        run_finished_at: witness.run_finished_at.clone(),
+        run_duration_ms: witness.run_duration_ms,
+        results: witness.results.clone(),
+    }
+}
+
+fn witness_fields(witness: &InstructionWitness) -> serde_json::Map<String, Value> {
+    match serde_json::to_value(witness).expect("instruction witness should serialize") {
+        Value::Object(fields) => fields,
+        _ => unreachable!("instruction witness serializes to an object"),
+    }
+}
+
+/// Replay `build_instruction_witness` over `envelope` with the runtime
+/// recorded in `expected` (ids, timestamps, check results) and diff the
+/// rebuilt witness against `expected` field by field.
+pub fn verify_instruction_witness(
+    envelope: &ValidatedInstructionEnvelope,
+    expected: &InstructionWitness,
+) -> InstructionWitnessVerifyResult {
+    let derived = match build_instruction_witness(envelope, runtime_from_witness(expected)) {
+        Ok(derived) => derived,
+        Err(err) => {
+            return InstructionWitnessVerifyResult {
+                errors: vec![format!("runtime replay failed ({err})")],
+                derived: None,
+            };
+        }
+    };
+
+    let recorded = witness_fields(expected);
+    let rederived = witness_fields(&derived);
+    let keys: BTreeSet<&String> = recorded.keys().chain(rederived.keys()).collect();
+    let errors = keys
+        .into_iter()
+        .filter_map(|key| {
+            let recorded = recorded.get(key).unwrap_or(&Value::Null);
+            let rederived = rederived.get(key).unwrap_or(&Value::Null);
+            (recorded != rederived)
+                .then(|| format!("{key} mismatch (recorded={recorded}, rederived={rederived})"))
+        })
+        .collect();
+
+    InstructionWitnessVerifyResult {
+        errors,
+        derived: Some(derived),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::{ExecutedInstructionCheck, validate_instruction_envelope_payload};
+    use crate::tests::repo_root;
+    use std::fs;
+
+    fn fixture_envelope() -> ValidatedInstructionEnvelope {
+        let root = repo_root();
+        let fixture_path =
+            root.join("tests/ci/fixtures/instructions/20260221T010000Z-ci-wiring-golden.json");
+        let payload: Value =
+            serde_json::from_slice(&fs::read(&fixture_path).expect("fixture should be readable"))
+                .expect("fixture json should parse");
+        validate_instruction_envelope_payload(&payload, &fixture_path, &root)
+            .expect("fixture should validate")
+    }
+
+    fn fixture_witness(envelope: &ValidatedInstructionEnvelope) -> InstructionWitness {
+        build_instruction_witness(
+            envelope,
+            InstructionWitnessRuntime {
+                instruction_id: "20260221T010000Z-ci-wiring-golden".to_string(),
+                instruction_ref: "instructions/20260221T010000Z-ci-wiring-golden.json".to_string(),
+                instruction_digest: envelope.instruction_digest.clone(),
+                squeak_site_profile: "local".to_string(),
+                run_started_at: "2026-02-22T00:00:00Z".to_string(),
+                run_finished_at: "2026-02-22T00:00:01Z".to_string(),
+                run_duration_ms: 1000,
+                results: vec![ExecutedInstructionCheck {
+                    check_id: "ci-wiring-check".to_string(),
+                    status: "passed".to_string(),
+                    exit_code: 0,
+                    duration_ms: 25,
+                }],
+            },
+        )
+        .expect("witness should build")
+    }
+
+    #[test]
+    fn verify_instruction_witness_accepts_replayed_witness() {
+        let envelope = fixture_envelope();
+        let witness = fixture_witness(&envelope);
+        let verified = verify_instruction_witness(&envelope, &witness);
+        assert!(verified.errors.is_empty(), "{:?}", verified.errors);
+        assert_eq!(verified.derived, Some(witness));
+    }
+
+    #[test]
+    fn verify_instruction_witness_reports_tampered_fields() {
+        let envelope = fixture_envelope();
+        let mut witness = fixture_witness(&envelope);
+        witness.verdict_class = "rejected".to_string();
+        witness.failure_classes = vec!["check_failed".to_string()];
+        witness.typed_core_projection_digest = None;
+
+        let verified = verify_instruction_witness(&envelope, &witness);
+        let keys: Vec<&str> = verified
+            .errors
+            .iter()
+            .filter_map(|error| error.split_once(' ').map(|(key, _)| key))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "failureClasses",
+                "typedCoreProjectionDigest",
+                "verdictClass"
+            ]
+        );
+        assert!(verified.errors[2].contains("recorded=\"rejected\""));
+
+        witness.instruction_id = " ".to_string();
+        let verified = verify_instruction_witness(&envelope, &witness);
+        assert!(verified.derived.is_none());
+        assert_eq!(verified.errors.len(), 1);
+        assert!(verified.errors[0].starts_with("runtime replay failed"));
+    }
+}
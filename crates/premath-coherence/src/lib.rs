@@ -3,46 +3,70 @@
 //! This crate evaluates a machine contract artifact against repository surfaces
 //! and emits deterministic witnesses.
 
+#[cfg(feature = "tusk")]
+mod combined;
+mod digest_scheme;
+mod envelope;
+pub mod eval;
+mod explain;
+mod failure_trie;
 mod instruction;
+mod instruction_verify;
 mod proposal;
+mod report;
 mod required;
 mod required_decide;
 mod required_decision_verify;
 mod required_gate_ref;
 mod required_projection;
+mod required_projection_verify;
 mod required_verify;
 
+#[cfg(feature = "tusk")]
+pub use combined::{CombinedWitness, run_combined_check};
+pub use digest_scheme::DigestScheme;
+pub use envelope::{CoherenceContractEnvelope, load_coherence_contract_envelope};
+pub use explain::{ClassHelp, explain_class_with_remediation};
+pub use failure_trie::FailureClassTrie;
 pub use instruction::{
     ExecutedInstructionCheck, InstructionError, InstructionProposalIngest, InstructionTypingPolicy,
-    InstructionWitness, InstructionWitnessRuntime, ValidatedInstructionEnvelope,
-    ValidatedInstructionProposal, build_instruction_witness, build_pre_execution_reject_witness,
-    validate_instruction_envelope_payload,
+    InstructionTypingPolicyBuilder, InstructionWitness, InstructionWitnessRuntime,
+    ValidatedInstructionEnvelope, ValidatedInstructionProposal, build_instruction_witness,
+    build_pre_execution_reject_witness, validate_instruction_envelope_payload,
 };
+pub use instruction_verify::{InstructionWitnessVerifyResult, verify_instruction_witness};
 pub use proposal::{
     CanonicalProposal, ProposalBinding, ProposalDischarge, ProposalError, ProposalObligation,
     ProposalStep, ProposalTargetJudgment, ValidatedProposal, compile_proposal_obligations,
     compute_proposal_digest, compute_proposal_kcir_ref, discharge_proposal_obligations,
-    validate_proposal_payload,
+    discharge_proposal_obligations_batched, validate_proposal_payload,
+};
+pub use report::{
+    obligation_failure_class_histogram, render_witness_text, sort_witnesses_by_severity,
 };
 pub use required::{
     ExecutedRequiredCheck, RequiredGateWitnessRef, RequiredWitness, RequiredWitnessError,
     RequiredWitnessRuntime, build_required_witness,
 };
 pub use required_decide::{
-    RequiredWitnessDecideRequest, RequiredWitnessDecideResult, decide_required_witness_request,
+    DecisionStep, RequiredWitnessDecideRequest, RequiredWitnessDecideResult,
+    decide_required_witness_request,
 };
 pub use required_decision_verify::{
     RequiredDecisionVerifyDerived, RequiredDecisionVerifyRequest, RequiredDecisionVerifyResult,
     verify_required_decision_request,
 };
 pub use required_gate_ref::{
-    RequiredGateRefFallback, RequiredGateRefRequest, RequiredGateRefResult, build_required_gate_ref,
+    RequiredGateRefFallback, RequiredGateRefFallbackReason, RequiredGateRefRequest,
+    RequiredGateRefResult, build_required_gate_ref,
 };
+use required_projection::normalize_task_set;
 pub use required_projection::{
     PROJECTION_POLICY, PROJECTION_SCHEMA, RequiredProjectionRequest, RequiredProjectionResult,
     normalize_paths as normalize_projection_paths, project_required_checks,
     projection_plan_payload,
 };
+pub use required_projection_verify::{RequiredProjectionVerifyResult, verify_required_projection};
 pub use required_verify::{
     RequiredWitnessVerifyDerived, RequiredWitnessVerifyRequest, RequiredWitnessVerifyResult,
     verify_required_witness_payload, verify_required_witness_request,
@@ -52,15 +76,19 @@ use premath_kernel::{
     obligation_gate_registry, obligation_gate_registry_json, parse_operation_route_rows,
     validate_world_route_bindings,
 };
-use regex::Regex;
+#[cfg(feature = "markdown-surfaces")]
+use regex::{Regex, RegexBuilder};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value, json};
 use sha2::{Digest, Sha256};
+use std::cell::{Cell, RefCell};
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+#[cfg(feature = "markdown-surfaces")]
 const REQUIRED_OBLIGATION_IDS: &[&str] = &[
     "scope_noncontradiction",
     "capability_parity",
@@ -78,6 +106,40 @@ const REQUIRED_OBLIGATION_IDS: &[&str] = &[
     "cwf_comprehension_eta",
 ];
 
+/// Without `markdown-surfaces` the markdown-parsing obligations are compiled
+/// out and contracts naming them are rejected up front.
+#[cfg(not(feature = "markdown-surfaces"))]
+const REQUIRED_OBLIGATION_IDS: &[&str] = &[
+    "gate_chain_parity",
+    "operation_reachability",
+    "transport_functoriality",
+    "span_square_commutation",
+    "coverage_base_change",
+    "coverage_transitivity",
+    "glue_or_witness_contractibility",
+    "cwf_substitution_identity",
+    "cwf_substitution_composition",
+    "cwf_comprehension_beta",
+    "cwf_comprehension_eta",
+];
+
+#[cfg(not(feature = "markdown-surfaces"))]
+const MARKDOWN_OBLIGATION_IDS: &[&str] = &[
+    "scope_noncontradiction",
+    "capability_parity",
+    "overlay_traceability",
+];
+
+/// The `cwf_` entries of `REQUIRED_OBLIGATION_IDS`: the obligations a lane
+/// registry must list as `checkerCoreOnlyObligations`.
+pub fn cwf_core_obligations() -> BTreeSet<String> {
+    REQUIRED_OBLIGATION_IDS
+        .iter()
+        .filter(|id| id.starts_with("cwf_"))
+        .map(|id| (*id).to_string())
+        .collect()
+}
+
 const REQUIRED_LANE_FAILURE_CLASSES: &[&str] = &[
     "lane_unknown",
     "lane_kind_unbound",
@@ -86,8 +148,24 @@ const REQUIRED_LANE_FAILURE_CLASSES: &[&str] = &[
 ];
 
 const REQUIRED_PULLBACK_ROUTE: &str = "span_square_commutation";
+const DEFAULT_SURFACE_READ_RETRIES: u32 = 2;
+/// Upper bound on `surfaceReadRetries`, so a typo cannot stall a run.
+const MAX_SURFACE_READ_RETRIES: u32 = 8;
+/// Delay before the first retry of a transient read; later retries wait
+/// proportionally longer.
+const SURFACE_READ_RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_millis(10);
+/// Canonical `contractKind` a control-plane contract must resolve to unless
+/// the coherence contract configures another.
+pub const DEFAULT_CONTROL_PLANE_CONTRACT_KIND: &str = "premath.control_plane.contract.v1";
 const GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE: &str =
     "coherence.gate_chain_parity.schema_lifecycle_invalid";
+const GATE_CHAIN_SCHEMA_LIFECYCLE_FREEZE_REASON_TOO_SHORT_FAILURE: &str =
+    "coherence.gate_chain_parity.schema_lifecycle_freeze_reason_too_short";
+const GATE_CHAIN_SCHEMA_LIFECYCLE_FREEZE_REASON_TOO_LONG_FAILURE: &str =
+    "coherence.gate_chain_parity.schema_lifecycle_freeze_reason_too_long";
+/// Bounds on `governance.freezeReason` in characters, after trimming.
+const FREEZE_REASON_MIN_CHARS: usize = 10;
+const FREEZE_REASON_MAX_CHARS: usize = 256;
 const GATE_CHAIN_STAGE1_PARITY_INVALID_FAILURE: &str =
     "coherence.gate_chain_parity.stage1_parity_invalid";
 const GATE_CHAIN_STAGE1_PARITY_MISSING_FAILURE: &str =
@@ -105,6 +183,8 @@ const GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE: &str =
     "coherence.gate_chain_parity.stage1_rollback_precondition_missing";
 const GATE_CHAIN_STAGE1_ROLLBACK_MISMATCH_FAILURE: &str =
     "coherence.gate_chain_parity.stage1_rollback_failure_class_mismatch";
+const GATE_CHAIN_STAGE1_ROLLBACK_TRIGGER_DRIFT_FAILURE: &str =
+    "coherence.gate_chain_parity.stage1_rollback_trigger_drift";
 const GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE: &str =
     "coherence.gate_chain_parity.stage1_rollback_unbound";
 const STAGE1_ROLLBACK_CLASS_PRECONDITION: &str =
@@ -124,6 +204,21 @@ const GATE_CHAIN_STAGE2_KERNEL_MISSING_FAILURE: &str =
     "coherence.gate_chain_parity.stage2_kernel_compliance_missing";
 const GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE: &str =
     "coherence.gate_chain_parity.stage2_kernel_compliance_drift";
+const GATE_CHAIN_STAGE_DIGEST_REF_DRIFT_FAILURE: &str =
+    "coherence.gate_chain_parity.stage_digest_ref_drift";
+const GATE_CHAIN_LANE_ARTIFACT_KIND_CROSS_LANE_DUPLICATE_FAILURE: &str =
+    "coherence.gate_chain_parity.lane_artifact_kind_cross_lane_duplicate";
+#[cfg(feature = "markdown-surfaces")]
+const OVERLAY_DOC_REF_FORMAT_INVALID_FAILURE: &str =
+    "coherence.scope_noncontradiction.overlay_doc_ref_format_invalid";
+#[cfg(feature = "markdown-surfaces")]
+const OVERLAY_DOC_FILENAME_COLLISION_FAILURE: &str =
+    "coherence.scope_noncontradiction.overlay_doc_filename_collision";
+const GATE_CHAIN_SECTION_ABSENT_FAILURE: &str = "coherence.gate_chain_parity.section_absent";
+const GATE_CHAIN_LANE_ARTIFACT_KIND_UNKNOWN_FAILURE: &str =
+    "coherence.gate_chain_parity.lane_artifact_kind_unknown";
+const GATE_CHAIN_VECTOR_MISSING_CASE_CONTEXT_FAILURE: &str =
+    "coherence.gate_chain_parity.vector_missing_case_context";
 const GATE_CHAIN_EVIDENCE_FACTORIZATION_INVALID_FAILURE: &str =
     "coherence.gate_chain_parity.evidence_factorization_invalid";
 const GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE: &str =
@@ -190,6 +285,13 @@ pub enum CoherenceError {
         source: std::io::Error,
     },
 
+    #[error("failed to write file: {path}: {source}")]
+    WriteFile {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("invalid json at {path}: {source}")]
     ParseJson {
         path: String,
@@ -204,25 +306,44 @@ pub enum CoherenceError {
         source: toml::de::Error,
     },
 
+    #[error("schema mismatch at {path} ({field}): expected {expected}, got {got}")]
+    SchemaMismatch {
+        expected: u32,
+        got: u32,
+        path: String,
+        field: String,
+    },
+
+    #[error("contract kind mismatch at {path} ({field}): expected {expected:?}, got {got:?}")]
+    ContractKindMismatch {
+        expected: String,
+        got: String,
+        path: String,
+        field: String,
+    },
+
+    #[error("contract signature verification failed at {path} (signer {signer:?})")]
+    SignatureVerificationFailed { path: String, signer: String },
+
     #[error("{0}")]
     Contract(String),
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoherenceBinding {
     pub normalizer_id: String,
     pub policy_digest: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConditionalCapabilityDoc {
     pub doc_ref: String,
     pub capability_id: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoherenceObligationSpec {
     pub id: String,
@@ -230,16 +351,26 @@ pub struct CoherenceObligationSpec {
     pub description: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoherenceSurfaces {
     pub capability_registry_path: String,
     pub capability_registry_kind: String,
     #[serde(default = "default_conformance_path")]
     pub conformance_path: String,
+    /// Heading of the conformance-doc section listing capability IDs. Empty
+    /// skips the conformance capability parity sub-check.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub conformance_capability_heading: String,
     pub capability_manifest_root: String,
     pub readme_path: String,
     pub conformance_readme_path: String,
+    /// Markers bounding the capability list in the READMEs. Empty, or a start
+    /// marker missing from a README, means the whole README is scanned.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub capability_readme_section_start: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub capability_readme_section_end: String,
     pub spec_index_path: String,
     pub spec_index_capability_heading: String,
     pub spec_index_informative_heading: String,
@@ -267,10 +398,20 @@ pub struct CoherenceSurfaces {
     pub coherence_spec_obligation_end: String,
     pub obligation_registry_kind: String,
     pub informative_clause_needle: String,
+    /// When set, matched as a regex instead of substring-matching the needle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub informative_clause_regex: Option<String>,
     pub transport_fixture_root_path: String,
     pub site_fixture_root_path: String,
 }
 
+impl CoherenceSurfaces {
+    /// The `surfaces` object as it appears in a coherence contract file.
+    pub fn to_contract_json_fragment(&self) -> Value {
+        serde_json::to_value(self).expect("CoherenceSurfaces must serialize")
+    }
+}
+
 fn default_conformance_path() -> String {
     "specs/premath/draft/CONFORMANCE.md".to_string()
 }
@@ -283,6 +424,23 @@ fn default_doctrine_operation_registry_path() -> String {
     "specs/premath/draft/DOCTRINE-OP-REGISTRY.json".to_string()
 }
 
+fn default_control_plane_contract_kind() -> String {
+    DEFAULT_CONTROL_PLANE_CONTRACT_KIND.to_string()
+}
+
+fn is_default_control_plane_contract_kind(kind: &str) -> bool {
+    kind == DEFAULT_CONTROL_PLANE_CONTRACT_KIND
+}
+
+fn default_surface_read_retries() -> u32 {
+    DEFAULT_SURFACE_READ_RETRIES
+}
+
+fn is_default_surface_read_retries(retries: &u32) -> bool {
+    *retries == DEFAULT_SURFACE_READ_RETRIES
+}
+
+#[cfg(feature = "markdown-surfaces")]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct CapabilityRegistry {
@@ -299,6 +457,8 @@ struct ControlPlaneProjectionContract {
     schema: u32,
     contract_kind: String,
     #[serde(default)]
+    completeness: ControlPlaneCompleteness,
+    #[serde(default)]
     schema_lifecycle: Option<ControlPlaneSchemaLifecycle>,
     #[serde(default)]
     evidence_stage1_parity: Option<ControlPlaneStage1Parity>,
@@ -313,16 +473,29 @@ struct ControlPlaneProjectionContract {
     #[serde(default)]
     lane_artifact_kinds: Option<BTreeMap<String, Vec<String>>>,
     #[serde(default)]
+    allowed_lane_artifact_kinds: Option<BTreeSet<String>>,
+    #[serde(default)]
     lane_ownership: Option<ControlPlaneLaneOwnership>,
     #[serde(default)]
     lane_failure_classes: Option<Vec<String>>,
     #[serde(default)]
     worker_lane_authority: Option<ControlPlaneWorkerLaneAuthority>,
+    #[serde(default)]
+    span_kind_registry: Option<Vec<String>>,
     required_gate_projection: RequiredGateProjection,
     required_witness: ControlPlaneRequiredWitness,
     instruction_witness: ControlPlaneInstructionWitness,
 }
 
+/// Whether a control-plane contract must declare every optional section.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum ControlPlaneCompleteness {
+    Full,
+    #[default]
+    Partial,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ControlPlaneEvidenceLanes {
@@ -450,6 +623,10 @@ struct RequiredGateProjection {
 struct ControlPlaneRequiredWitness {
     witness_kind: String,
     decision_kind: String,
+    /// Delta kind consumed by `premath required-delta`; resolved through the
+    /// `requiredDeltaKind` family, defaulting to its canonical kind.
+    #[serde(default)]
+    delta_kind: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -675,7 +852,7 @@ struct ControlPlaneStage2KernelComplianceSentinel {
     failure_classes: ControlPlaneStage2BidirEvidenceFailureClasses,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoherenceContract {
     pub schema: u32,
@@ -692,18 +869,85 @@ pub struct CoherenceContract {
     pub overlay_docs: Vec<String>,
     #[serde(default)]
     pub required_bidir_obligations: Vec<String>,
+    /// Promote `obligations` order drift from a warning to a failure.
+    #[serde(default)]
+    pub enforce_obligation_order: bool,
+    /// Require each overlay doc to be a doctrine node reachable from the root.
+    #[serde(default)]
+    pub enforce_overlay_doctrine_reachability: bool,
+    /// Require the registry's `profileOverlayClaims` to be listed in sorted order.
+    #[serde(default)]
+    pub require_sorted_profile_claims: bool,
+    /// Record affirmative `satisfiedReasons` for passing control-plane sub-checks.
+    #[serde(default)]
+    pub verbose_reasons: bool,
+    /// Obligations whose `surface_error` is recorded as a warning instead of
+    /// rejecting, for surfaces that may be absent in partial repo states.
+    /// Only a declared surface or overlay doc that does not exist is
+    /// tolerated; every entry must name a required or contract obligation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tolerate_surface_errors: Vec<String>,
+    /// Vector-id prefixes that classify site and transport fixture vectors.
+    #[serde(default, skip_serializing_if = "PolarityPrefixes::is_default")]
+    pub polarity_prefixes: PolarityPrefixes,
+    /// Canonical `contractKind` expected of the control-plane contract, for
+    /// downstream control planes that reuse the gate-chain checks.
+    #[serde(
+        default = "default_control_plane_contract_kind",
+        skip_serializing_if = "is_default_control_plane_contract_kind"
+    )]
+    pub control_plane_contract_kind: String,
+    /// Extra attempts for surface reads that fail with a transient I/O error,
+    /// clamped to `MAX_SURFACE_READ_RETRIES`.
+    #[serde(
+        default = "default_surface_read_retries",
+        skip_serializing_if = "is_default_surface_read_retries"
+    )]
+    pub surface_read_retries: u32,
+    /// Capability registry used in place of `surfaces.capabilityRegistryPath`,
+    /// for tests and generated contracts. Validated like the on-disk registry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inline_capability_registry: Option<Value>,
+    /// Digest prefix scheme for the contract digest and every witness digest.
+    #[serde(default)]
+    pub digest_scheme: DigestScheme,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ObligationWitness {
     pub obligation_id: String,
     pub result: String,
     pub failure_classes: Vec<String>,
     pub details: Value,
+    /// `semantic_digest` of `details`, so detail-level drift is visible even
+    /// when the failure classes are unchanged.
+    #[serde(default)]
+    pub details_digest: String,
+    /// Digest of the sorted `(path, content digest)` pairs of every surface
+    /// file the obligation read, for cache invalidation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inputs_digest: Option<String>,
+}
+
+impl ObligationWitness {
+    /// Compare `failure_classes` against `expected` as sets.
+    ///
+    /// On mismatch the error lists the missing and extra classes.
+    pub fn assert_classes(&self, expected: &[&str]) -> Result<(), String> {
+        let expected: Vec<String> = expected.iter().map(|class| (*class).to_string()).collect();
+        let diff = failure_class_set_diff(&expected, &self.failure_classes);
+        if diff.is_empty() {
+            return Ok(());
+        }
+        Err(format!(
+            "{}: failure classes mismatch (missing={:?}, extra={:?})",
+            self.obligation_id, diff.missing, diff.unexpected
+        ))
+    }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoherenceWitness {
     pub schema: u32,
@@ -712,15 +956,133 @@ pub struct CoherenceWitness {
     pub contract_id: String,
     pub contract_ref: String,
     pub contract_digest: String,
+    /// Content address of the verdict inputs: contract digest, digest of every
+    /// surface file read, checker version, obligation registry and the
+    /// verdict-affecting check options. Identical inputs give the same id.
+    #[serde(default)]
+    pub witness_id: String,
     pub binding: CoherenceBinding,
     pub result: String,
     pub obligations: Vec<ObligationWitness>,
+    /// Digest over every obligation's `detailsDigest`, keyed by obligation id.
+    /// Like the per-obligation digests, not part of `contract_digest` material.
+    #[serde(default)]
+    pub obligations_details_digest: String,
     pub failure_classes: Vec<String>,
     pub constructor: CoherenceConstructor,
+    /// Surface field name (camelCase) -> resolved repo-relative path, for the
+    /// surfaces this run actually read (a fixture root counts once any file
+    /// beneath it was read).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub consulted_surfaces: BTreeMap<String, String>,
+    /// Surface field name (camelCase) -> lexically normalized path, for every
+    /// surface the contract declared, whether or not this run read it.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub declared_surfaces: BTreeMap<String, String>,
+    /// Non-failing classes (e.g. obligation order drift when not enforced).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Contract JSON as read, present when `embed_contract` is requested.
+    /// Not part of `contract_digest` material.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_snapshot: Option<Value>,
+    /// Scheme every digest in this witness was produced under.
+    #[serde(default)]
+    pub digest_scheme: DigestScheme,
+}
+
+/// Optional coherence-check behaviors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CoherenceCheckOptions {
+    /// Embed the contract under `contractSnapshot` for self-contained witnesses.
+    pub embed_contract: bool,
+    /// Promote obligation order drift to a failure regardless of the contract flag.
+    pub enforce_obligation_order: bool,
+    /// Record `satisfiedReasons` regardless of the contract flag.
+    pub verbose_reasons: bool,
+    /// Promote every witness-level warning, and every obligation's
+    /// `details.warnings`, into `failureClasses`; the warnings stay listed
+    /// where they were.
+    pub warnings_as_errors: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SurfaceRepoint {
+    pub field: String,
+    pub old_path: String,
+    pub new_path: String,
+}
+
+/// Surface path changes between a prior witness and a contract.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SurfaceDrift {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub repointed: Vec<SurfaceRepoint>,
+}
+
+impl SurfaceDrift {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.repointed.is_empty()
+    }
+}
+
+/// Set difference between expected and actual failure classes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailureClassDiff {
+    /// Expected classes that were not reported.
+    pub missing: Vec<String>,
+    /// Reported classes that were not expected.
+    pub unexpected: Vec<String>,
+}
+
+impl FailureClassDiff {
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty()
+    }
+}
+
+/// Compare failure classes as sets; both sides of the diff are sorted and
+/// deduplicated.
+pub fn failure_class_set_diff(expected: &[String], actual: &[String]) -> FailureClassDiff {
+    let expected: BTreeSet<&String> = expected.iter().collect();
+    let actual: BTreeSet<&String> = actual.iter().collect();
+    FailureClassDiff {
+        missing: expected
+            .difference(&actual)
+            .map(|class| (*class).clone())
+            .collect(),
+        unexpected: actual
+            .difference(&expected)
+            .map(|class| (*class).clone())
+            .collect(),
+    }
 }
 
+/// Outcome of re-running one fixture vector outside its obligation sweep.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
+pub struct SingleVectorEvaluation {
+    pub obligation_id: String,
+    pub vector_id: String,
+    pub result: String,
+    pub failure_classes: Vec<String>,
+    pub expected_result: String,
+    pub expected_failure_classes: Vec<String>,
+    /// Whether the evaluation agrees with `expect.json`, using the same
+    /// comparison the full obligation check applies per vector.
+    pub matched: bool,
+    /// Failure class diff against `expect.json`; `None` when the vector does
+    /// not pin failure classes.
+    pub failure_class_diff: Option<FailureClassDiff>,
+    pub details: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CoherenceConstructorSources {
     pub control_plane_contract_path: String,
     pub doctrine_site_path: String,
@@ -728,7 +1090,7 @@ pub struct CoherenceConstructorSources {
     pub doctrine_operation_registry_path: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoherenceConstructor {
     pub schema: u32,
@@ -742,38 +1104,39 @@ pub struct CoherenceConstructor {
     pub sources: CoherenceConstructorSources,
 }
 
+/// Doctrine site graph (`surfaces.doctrineSitePath`).
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct DoctrineSite {
+pub struct DoctrineSite {
     #[serde(default)]
-    nodes: Vec<DoctrineNode>,
+    pub nodes: Vec<DoctrineNode>,
     #[serde(default)]
-    covers: Vec<DoctrineCover>,
+    pub covers: Vec<DoctrineCover>,
     #[serde(default)]
-    edges: Vec<DoctrineEdge>,
+    pub edges: Vec<DoctrineEdge>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct DoctrineNode {
-    id: String,
-    path: String,
-    kind: String,
+pub struct DoctrineNode {
+    pub id: String,
+    pub path: String,
+    pub kind: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct DoctrineCover {
-    over: String,
+pub struct DoctrineCover {
+    pub over: String,
     #[serde(default)]
-    parts: Vec<String>,
+    pub parts: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct DoctrineEdge {
-    from: String,
-    to: String,
+pub struct DoctrineEdge {
+    pub from: String,
+    pub to: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -783,6 +1146,10 @@ struct TransportManifest {
     status: String,
     #[serde(default)]
     vectors: Vec<String>,
+    /// Per-entry status overrides; a non-executable entry sets aside only
+    /// that vector.
+    #[serde(default)]
+    vector_statuses: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -795,6 +1162,91 @@ struct TransportExpect {
     expected_failure_classes: Vec<String>,
 }
 
+/// Recognized vector statuses, from a case's `status` or the manifest's
+/// `vectorStatuses` entry. A manifest's own `status` must be `executable`.
+///
+/// `skipped` vectors are counted but neither evaluated nor failing; `pending`
+/// vectors are not evaluated and surface a soft `pending_vector` warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixtureStatus {
+    Executable,
+    Skipped,
+    Pending,
+}
+
+impl FixtureStatus {
+    fn parse(status: &str) -> Option<Self> {
+        match status {
+            "executable" => Some(Self::Executable),
+            "skipped" => Some(Self::Skipped),
+            "pending" => Some(Self::Pending),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Executable => "executable",
+            Self::Skipped => "skipped",
+            Self::Pending => "pending",
+        }
+    }
+}
+
+/// Vectors set aside as `skipped` or `pending` during a fixture sweep.
+#[derive(Debug, Default)]
+struct NonExecutableVectors {
+    skipped: Vec<String>,
+    pending: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl NonExecutableVectors {
+    /// Record `vector_id` under `status`; false when it is executable and
+    /// should be evaluated.
+    fn record(
+        &mut self,
+        failure_prefix: &str,
+        vector_id: &str,
+        status: FixtureStatus,
+        vector_rows: &mut Vec<Value>,
+    ) -> bool {
+        match status {
+            FixtureStatus::Executable => return false,
+            FixtureStatus::Skipped => self.skipped.push(vector_id.to_string()),
+            FixtureStatus::Pending => {
+                self.warnings
+                    .push(format!("{failure_prefix}.pending_vector"));
+                self.pending.push(vector_id.to_string());
+            }
+        }
+        vector_rows.push(json!({"vectorId": vector_id, "status": status.as_str()}));
+        true
+    }
+
+    /// Apply the manifest's `vectorStatuses` entry for `vector_id`; true when
+    /// the vector was set aside. An unrecognized entry status is a failure.
+    fn record_manifest_entry(
+        &mut self,
+        failure_prefix: &str,
+        vector_statuses: &BTreeMap<String, String>,
+        vector_id: &str,
+        failures: &mut Vec<String>,
+        vector_rows: &mut Vec<Value>,
+    ) -> bool {
+        let Some(status) = vector_statuses.get(vector_id) else {
+            return false;
+        };
+        match FixtureStatus::parse(status) {
+            Some(status) => self.record(failure_prefix, vector_id, status, vector_rows),
+            None => {
+                failures.push(format!("{failure_prefix}.manifest_invalid_status"));
+                false
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct SiteManifest {
@@ -804,6 +1256,156 @@ struct SiteManifest {
     vectors: Vec<String>,
     #[serde(default)]
     obligation_vectors: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    inline_vectors: BTreeMap<String, SiteInlineVector>,
+    /// Per-entry status overrides; a non-executable entry sets aside only
+    /// that vector.
+    #[serde(default)]
+    vector_statuses: BTreeMap<String, String>,
+}
+
+impl SiteManifest {
+    /// Declared vectors (on-disk and inline) not referenced by any
+    /// `obligationVectors` entry.
+    fn unclaimed_vectors(&self) -> Vec<String> {
+        let claimed: BTreeSet<&String> = self.obligation_vectors.values().flatten().collect();
+        dedupe_sorted(
+            self.vectors
+                .iter()
+                .chain(self.inline_vectors.keys())
+                .filter(|vector_id| !claimed.contains(vector_id))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Manifest-level failures `check_site_obligation` reports for
+    /// `obligation_id`, with the vector ids scoped to that obligation and the
+    /// declared vectors no obligation claims.
+    fn lint(&self, obligation_id: &str) -> SiteManifestLint {
+        let mut failures = Vec::new();
+        if self.schema != 1 {
+            failures.push(format!("coherence.{obligation_id}.manifest_invalid_schema"));
+        }
+        if FixtureStatus::parse(&self.status) != Some(FixtureStatus::Executable) {
+            failures.push(format!("coherence.{obligation_id}.manifest_invalid_status"));
+        }
+        if self.vectors.is_empty() && self.inline_vectors.is_empty() {
+            failures.push(format!("coherence.{obligation_id}.manifest_empty"));
+        }
+
+        let mut declared: BTreeSet<&String> = self.vectors.iter().collect();
+        for vector_id in self.inline_vectors.keys() {
+            if !declared.insert(vector_id) {
+                failures.push(format!(
+                    "coherence.{obligation_id}.manifest_inline_vector_collision"
+                ));
+            }
+        }
+        let scoped_vectors: Vec<String> = if self.obligation_vectors.is_empty() {
+            failures.push(format!(
+                "coherence.{obligation_id}.manifest_missing_obligation_vectors"
+            ));
+            self.vectors
+                .iter()
+                .chain(self.inline_vectors.keys())
+                .cloned()
+                .collect()
+        } else {
+            self.obligation_vectors
+                .get(obligation_id)
+                .cloned()
+                .unwrap_or_default()
+        };
+        if !self.undeclared_vectors(&scoped_vectors).is_empty() {
+            failures.push(format!(
+                "coherence.{obligation_id}.manifest_obligation_vector_not_declared"
+            ));
+        }
+        let mut seen = BTreeSet::new();
+        for vector_id in &scoped_vectors {
+            if !seen.insert(vector_id) {
+                failures.push(format!("coherence.{obligation_id}.duplicate_vector_id"));
+            }
+            if self
+                .vector_statuses
+                .get(vector_id)
+                .is_some_and(|status| FixtureStatus::parse(status).is_none())
+            {
+                failures.push(format!("coherence.{obligation_id}.manifest_invalid_status"));
+            }
+        }
+        let unclaimed_vectors = if self.obligation_vectors.is_empty() {
+            Vec::new()
+        } else {
+            self.unclaimed_vectors()
+        };
+        if !unclaimed_vectors.is_empty() {
+            failures.push(format!(
+                "coherence.{obligation_id}.manifest_unreferenced_vectors"
+            ));
+        }
+        SiteManifestLint {
+            failures,
+            scoped_vectors,
+            unclaimed_vectors,
+        }
+    }
+
+    /// Entries of `vector_ids` that are neither on-disk nor inline vectors.
+    fn undeclared_vectors(&self, vector_ids: &[String]) -> Vec<String> {
+        dedupe_sorted(
+            vector_ids
+                .iter()
+                .filter(|vector_id| {
+                    !self.vectors.contains(vector_id)
+                        && !self.inline_vectors.contains_key(*vector_id)
+                })
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+struct SiteManifestLint {
+    failures: Vec<String>,
+    scoped_vectors: Vec<String>,
+    unclaimed_vectors: Vec<String>,
+}
+
+/// Lint a site fixture `manifest.json` without evaluating any vectors.
+///
+/// Reports the manifest-level failures `check_site_obligation` would report
+/// for each obligation named in `obligationVectors` (or under `site_manifest`
+/// when there are none).
+pub fn validate_site_manifest(manifest: &Value) -> Vec<String> {
+    let Ok(manifest) = serde_json::from_value::<SiteManifest>(manifest.clone()) else {
+        return vec!["coherence.site_manifest.manifest_invalid".to_string()];
+    };
+    let obligation_ids: Vec<&str> = if manifest.obligation_vectors.is_empty() {
+        vec!["site_manifest"]
+    } else {
+        manifest
+            .obligation_vectors
+            .keys()
+            .map(String::as_str)
+            .collect()
+    };
+    dedupe_sorted(
+        obligation_ids
+            .into_iter()
+            .flat_map(|obligation_id| manifest.lint(obligation_id).failures)
+            .collect(),
+    )
+}
+
+/// A site vector carried directly in `manifest.json` instead of a
+/// `case.json`/`expect.json` directory.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SiteInlineVector {
+    case: Value,
+    expect: Value,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -846,6 +1448,61 @@ struct InvarianceObservation<'a> {
     failure_classes: &'a [String],
 }
 
+const POLARITY_BALANCE_WARNING_THRESHOLD: f64 = 0.5;
+
+/// Vector-id prefixes that classify fixture vectors by polarity. Invariance
+/// vectors are additionally grouped into semantic-scenario pairs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PolarityPrefixes {
+    pub golden: String,
+    pub adversarial: String,
+    pub invariance: String,
+}
+
+impl Default for PolarityPrefixes {
+    fn default() -> Self {
+        Self {
+            golden: "golden/".to_string(),
+            adversarial: "adversarial/".to_string(),
+            invariance: "invariance/".to_string(),
+        }
+    }
+}
+
+impl PolarityPrefixes {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn is_invariance(&self, vector_id: &str) -> bool {
+        vector_id.starts_with(self.invariance.as_str())
+    }
+
+    /// Every prefix must be non-empty and none may be a prefix of another,
+    /// so each vector id falls into at most one polarity.
+    fn validate(&self) -> Result<(), String> {
+        let named = [
+            ("golden", &self.golden),
+            ("adversarial", &self.adversarial),
+            ("invariance", &self.invariance),
+        ];
+        if let Some((name, _)) = named.iter().find(|(_, prefix)| prefix.is_empty()) {
+            return Err(format!("polarityPrefixes.{name} must be non-empty"));
+        }
+        for (index, (left_name, left)) in named.iter().enumerate() {
+            for (right_name, right) in &named[index + 1..] {
+                if left.starts_with(right.as_str()) || right.starts_with(left.as_str()) {
+                    return Err(format!(
+                        "polarityPrefixes.{left_name} ({left:?}) and polarityPrefixes.{right_name} ({right:?}) overlap"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 struct PolarityCoverage {
     matched_golden_count: usize,
@@ -856,12 +1513,12 @@ struct PolarityCoverage {
 }
 
 impl PolarityCoverage {
-    fn record_vector_id(&mut self, vector_id: &str) {
-        if vector_id.starts_with("golden/") {
+    fn record_vector_id(&mut self, vector_id: &str, prefixes: &PolarityPrefixes) {
+        if vector_id.starts_with(prefixes.golden.as_str()) {
             self.matched_golden_count += 1;
-        } else if vector_id.starts_with("adversarial/") {
+        } else if vector_id.starts_with(prefixes.adversarial.as_str()) {
             self.matched_adversarial_count += 1;
-        } else if vector_id.starts_with("invariance/") {
+        } else if prefixes.is_invariance(vector_id) {
             self.matched_invariance_count += 1;
         }
     }
@@ -897,6 +1554,30 @@ impl PolarityCoverage {
         }
     }
 
+    fn total_vectors(&self) -> usize {
+        self.matched_golden_count + self.matched_adversarial_count + self.matched_invariance_count
+    }
+
+    /// `min / max` of the golden and adversarial counts: 1.0 is balanced,
+    /// 0.0 is one-sided. With neither kind present there is nothing to skew,
+    /// so the score is 1.0 and the `missing_*` failures report the gap.
+    fn balance_score(&self) -> f64 {
+        let golden = self.matched_golden_count;
+        let adversarial = self.matched_adversarial_count;
+        match golden.max(adversarial) {
+            0 => 1.0,
+            max => golden.min(adversarial) as f64 / max as f64,
+        }
+    }
+
+    /// Advisory only: push `{prefix}.polarity_imbalance` to `warnings` when
+    /// the balance score is below `POLARITY_BALANCE_WARNING_THRESHOLD`.
+    fn emit_balance_warning(&self, warnings: &mut Vec<String>, failure_prefix: &str) {
+        if self.balance_score() < POLARITY_BALANCE_WARNING_THRESHOLD {
+            warnings.push(format!("{failure_prefix}.polarity_imbalance"));
+        }
+    }
+
     fn vector_kind_details(&self) -> Value {
         json!({
             "golden": self.matched_golden_count,
@@ -916,43 +1597,167 @@ impl PolarityCoverage {
 pub fn run_coherence_check(
     repo_root: impl AsRef<Path>,
     contract_path: impl AsRef<Path>,
+) -> Result<CoherenceWitness, CoherenceError> {
+    run_coherence_check_with_options(repo_root, contract_path, CoherenceCheckOptions::default())
+}
+
+pub fn run_coherence_check_with_options(
+    repo_root: impl AsRef<Path>,
+    contract_path: impl AsRef<Path>,
+    options: CoherenceCheckOptions,
 ) -> Result<CoherenceWitness, CoherenceError> {
     let repo_root = repo_root.as_ref().to_path_buf();
     let contract_path = resolve_path(&repo_root, contract_path.as_ref());
     let contract_bytes = read_bytes(&contract_path)?;
-    let contract: CoherenceContract =
+    let mut contract: CoherenceContract =
         serde_json::from_slice(&contract_bytes).map_err(|source| CoherenceError::ParseJson {
             path: display_path(&contract_path),
             source,
         })?;
+    contract.verbose_reasons |= options.verbose_reasons;
+    #[cfg(not(feature = "markdown-surfaces"))]
+    if let Some(item) = contract
+        .obligations
+        .iter()
+        .find(|item| MARKDOWN_OBLIGATION_IDS.contains(&item.id.trim()))
+    {
+        return Err(CoherenceError::Contract(format!(
+            "{}: obligation {:?} requires the premath-coherence `markdown-surfaces` feature",
+            display_path(&contract_path),
+            item.id.trim()
+        )));
+    }
+    if contract.digest_scheme.version == 0 {
+        return Err(CoherenceError::Contract(format!(
+            "{}: digestScheme.version must be at least 1",
+            display_path(&contract_path)
+        )));
+    }
+    if let Err(message) = contract.polarity_prefixes.validate() {
+        return Err(CoherenceError::Contract(format!(
+            "{}: {message}",
+            display_path(&contract_path)
+        )));
+    }
+    if let Some(unknown) = contract.tolerate_surface_errors.iter().find(|id| {
+        !REQUIRED_OBLIGATION_IDS.contains(&id.as_str())
+            && !contract
+                .obligations
+                .iter()
+                .any(|item| item.id.trim() == *id)
+    }) {
+        return Err(CoherenceError::Contract(format!(
+            "{}: tolerateSurfaceErrors entry {unknown:?} is not a required or contract obligation",
+            display_path(&contract_path)
+        )));
+    }
+    let retries = contract.surface_read_retries;
+    let witness = with_surface_read_retries(retries, || {
+        run_parsed_coherence_check(repo_root, contract_path, contract_bytes, contract, options)
+    })?;
+    debug_assert!(
+        witness.is_complete(),
+        "coherence witness is missing obligations: {:?}",
+        witness.missing_obligation_ids()
+    );
+    Ok(witness)
+}
+
+fn run_parsed_coherence_check(
+    repo_root: PathBuf,
+    contract_path: PathBuf,
+    contract_bytes: Vec<u8>,
+    contract: CoherenceContract,
+    options: CoherenceCheckOptions,
+) -> Result<CoherenceWitness, CoherenceError> {
+    let scheme = contract.digest_scheme;
     let constructor =
         compile_coherence_constructor(&repo_root, &contract_path, &contract_bytes, &contract);
+    let contract_snapshot = if options.embed_contract {
+        Some(
+            serde_json::from_slice::<Value>(&contract_bytes).map_err(|source| {
+                CoherenceError::ParseJson {
+                    path: display_path(&contract_path),
+                    source,
+                }
+            })?,
+        )
+    } else {
+        None
+    };
 
     let mut obligations: Vec<ObligationWitness> = Vec::new();
     let mut aggregate_failures: BTreeSet<String> = BTreeSet::new();
+    let mut surface_inputs: BTreeMap<PathBuf, String> = BTreeMap::new();
 
-    let contract_set_check = validate_contract_obligation_set(&constructor.declared_obligation_ids);
+    let mut warnings = Vec::new();
+    let mut contract_set_check = validate_contract_obligation_set(
+        &constructor.declared_obligation_ids,
+        ValidateObligationSetOptions::default(),
+    );
+    contract_set_check.extend(validate_contract_obligation_descriptions(&contract));
+    let order_check = validate_contract_obligation_order(&contract.obligations);
+    if contract.enforce_obligation_order || options.enforce_obligation_order {
+        contract_set_check.extend(order_check);
+    } else {
+        warnings.extend(order_check);
+    }
     if !contract_set_check.is_empty() {
-        let failure_classes = contract_set_check;
+        let failure_classes = dedupe_sorted(contract_set_check);
         for class_name in &failure_classes {
             aggregate_failures.insert(class_name.clone());
         }
+        let details = json!({
+            "constructorKind": constructor.constructor_kind,
+            "contractObligations": constructor.declared_obligation_ids,
+            "requiredObligations": constructor.required_obligation_ids,
+            "executionObligations": constructor.execution_obligation_ids,
+        });
         obligations.push(ObligationWitness {
             obligation_id: "contract_obligation_set".to_string(),
             result: "rejected".to_string(),
             failure_classes,
-            details: json!({
-                "constructorKind": constructor.constructor_kind,
-                "contractObligations": constructor.declared_obligation_ids,
-                "requiredObligations": constructor.required_obligation_ids,
-                "executionObligations": constructor.execution_obligation_ids,
-            }),
+            details_digest: semantic_digest(&details, scheme),
+            details,
+            inputs_digest: None,
         });
     }
 
     for obligation_id in &constructor.execution_obligation_ids {
-        let checked = execute_obligation(obligation_id, &repo_root, &contract);
-        for class_name in &checked.failure_classes {
+        let ((result, depth_exceeded), consulted_inputs) = with_consulted_inputs(|| {
+            with_semantic_depth_tracking(|| run_obligation(obligation_id, &repo_root, &contract))
+        });
+        let (mut checked, missing_surface) = match result {
+            Ok(checked) => (checked, false),
+            Err(err) => (
+                surface_error_check(obligation_id, &err),
+                is_missing_declared_surface(&repo_root, &contract.surfaces, &err),
+            ),
+        };
+        if depth_exceeded || exceeds_nesting_depth(&checked.details, SEMANTIC_NORMALIZE_MAX_DEPTH) {
+            mark_semantic_depth_exceeded(&mut checked.details, obligation_id);
+        }
+        if contract.tolerate_surface_errors.contains(obligation_id) {
+            let tolerated: Vec<String> = if missing_surface {
+                std::mem::take(&mut checked.failure_classes)
+            } else {
+                let (tolerated, kept) = std::mem::take(&mut checked.failure_classes)
+                    .into_iter()
+                    .partition(|class_name| {
+                        TOLERABLE_MISSING_FILE_CLASSES.contains(&class_name.as_str())
+                    });
+                checked.failure_classes = kept;
+                tolerated
+            };
+            if !tolerated.is_empty() {
+                checked.details["surfaceErrorTolerated"] = json!(true);
+                warnings.extend(tolerated);
+            }
+        }
+        if options.warnings_as_errors {
+            promote_detail_warnings(&mut checked);
+        }
+        for class_name in &checked.failure_classes {
             aggregate_failures.insert(class_name.clone());
         }
         obligations.push(ObligationWitness {
@@ -963,10 +1768,26 @@ pub fn run_coherence_check(
                 "rejected".to_string()
             },
             failure_classes: checked.failure_classes,
+            details_digest: semantic_digest(&checked.details, scheme),
             details: checked.details,
+            inputs_digest: Some(inputs_digest(&repo_root, &consulted_inputs, scheme)),
         });
+        surface_inputs.extend(consulted_inputs);
+    }
+    for obligation in &mut obligations {
+        obligation.details = sort_object_keys(&obligation.details);
+    }
+    if options.warnings_as_errors {
+        aggregate_failures.extend(warnings.iter().cloned());
     }
     let failure_classes: Vec<String> = aggregate_failures.into_iter().collect();
+    let obligations_details_digest = obligations_details_digest(&obligations, scheme);
+    let witness_id = witness_id(
+        &constructor.contract_digest,
+        &inputs_digest(&repo_root, &surface_inputs, scheme),
+        &options,
+        scheme,
+    );
 
     Ok(CoherenceWitness {
         schema: 1,
@@ -975,6 +1796,7 @@ pub fn run_coherence_check(
         contract_id: contract.contract_id,
         contract_ref: constructor.contract_ref.clone(),
         contract_digest: constructor.contract_digest.clone(),
+        witness_id,
         binding: contract.binding,
         result: if failure_classes.is_empty() {
             "accepted".to_string()
@@ -982,9 +1804,613 @@ pub fn run_coherence_check(
             "rejected".to_string()
         },
         obligations,
+        obligations_details_digest,
         failure_classes,
         constructor,
+        consulted_surfaces: consulted_surface_paths(
+            &repo_root,
+            &contract.surfaces,
+            &surface_inputs,
+        ),
+        declared_surfaces: declared_surface_paths(&contract.surfaces),
+        warnings,
+        contract_snapshot,
+        digest_scheme: contract.digest_scheme,
+    })
+}
+
+/// Id of a witness derived from `source_witness_id` by a post-processing
+/// step, bound to the derived obligation details.
+fn derived_witness_id(
+    source_witness_id: &str,
+    derivation: &str,
+    obligations_details_digest: &str,
+    scheme: DigestScheme,
+) -> String {
+    let material = [
+        ("sourceWitnessId", source_witness_id),
+        ("derivation", derivation),
+        ("obligationsDetailsDigest", obligations_details_digest),
+    ];
+    let canonical = serde_json::to_string(&material).expect("witness id serialization");
+    format!(
+        "{}{:x}",
+        scheme.prefix("wit"),
+        Sha256::digest(canonical.as_bytes())
+    )
+}
+
+/// Digest over `(contractDigest, surfaceSnapshotDigest, checkerVersion,
+/// obligationRegistryDigest, warningsAsErrors, enforceObligationOrder)`.
+fn witness_id(
+    contract_digest: &str,
+    surface_snapshot_digest: &str,
+    options: &CoherenceCheckOptions,
+    scheme: DigestScheme,
+) -> String {
+    let registry = serde_json::to_string(&obligation_gate_registry_json())
+        .expect("obligation registry serialization");
+    let obligation_registry_digest = format!("{:x}", Sha256::digest(registry.as_bytes()));
+    let material = [
+        ("contractDigest", contract_digest),
+        ("surfaceSnapshotDigest", surface_snapshot_digest),
+        ("checkerVersion", env!("CARGO_PKG_VERSION")),
+        (
+            "obligationRegistryDigest",
+            obligation_registry_digest.as_str(),
+        ),
+        (
+            "warningsAsErrors",
+            if options.warnings_as_errors {
+                "true"
+            } else {
+                "false"
+            },
+        ),
+        (
+            "enforceObligationOrder",
+            if options.enforce_obligation_order {
+                "true"
+            } else {
+                "false"
+            },
+        ),
+    ];
+    let canonical = serde_json::to_string(&material).expect("witness id serialization");
+    format!(
+        "{}{:x}",
+        scheme.prefix("wit"),
+        Sha256::digest(canonical.as_bytes())
+    )
+}
+
+/// `semantic_digest` over the `obligationId -> detailsDigest` map.
+fn obligations_details_digest(obligations: &[ObligationWitness], scheme: DigestScheme) -> String {
+    let digests: BTreeMap<&str, &str> = obligations
+        .iter()
+        .map(|obligation| {
+            (
+                obligation.obligation_id.as_str(),
+                obligation.details_digest.as_str(),
+            )
+        })
+        .collect();
+    semantic_digest(&json!(digests), scheme)
+}
+
+/// Re-evaluate a single file-backed fixture vector by id.
+///
+/// Only the vector's `case.json`/`expect.json` are read; manifest-level and
+/// polarity checks are skipped, so this is a debugging aid rather than a
+/// substitute for the obligation check.
+pub fn evaluate_single_vector(
+    repo_root: impl AsRef<Path>,
+    contract: &CoherenceContract,
+    obligation_id: &str,
+    vector_id: &str,
+) -> Result<SingleVectorEvaluation, CoherenceError> {
+    let repo_root = repo_root.as_ref();
+    let evaluated = if obligation_id == "transport_functoriality" {
+        let fixture_root = resolve_path(
+            repo_root,
+            contract.surfaces.transport_fixture_root_path.as_str(),
+        );
+        let vector_root = fixture_root.join(vector_id);
+        let case_path = vector_root.join("case.json");
+        let expect_path = vector_root.join("expect.json");
+        let case_payload = read_json_value(&case_path)?;
+        let expect: TransportExpect =
+            serde_json::from_slice(&read_bytes(&expect_path)?).map_err(|source| {
+                CoherenceError::ParseJson {
+                    path: display_path(&expect_path),
+                    source,
+                }
+            })?;
+        let evaluated = evaluate_transport_case(&case_payload, &case_path, contract.digest_scheme)?;
+        (
+            evaluated.result,
+            evaluated.failure_classes,
+            evaluated.details,
+            expect.result,
+            expect.expected_failure_classes,
+        )
+    } else {
+        let fixture_root =
+            resolve_path(repo_root, contract.surfaces.site_fixture_root_path.as_str());
+        let vector_root = fixture_root.join(vector_id);
+        let case_path = vector_root.join("case.json");
+        let expect_path = vector_root.join("expect.json");
+        let case_payload: SiteCase = load_site_vector_payload(&case_path, None)?;
+        if case_payload.obligation_id != obligation_id {
+            return Err(CoherenceError::Contract(format!(
+                "{}: vector obligationId `{}` does not match `{obligation_id}`",
+                display_path(&case_path),
+                case_payload.obligation_id
+            )));
+        }
+        let expect: SiteExpect = load_site_vector_payload(&expect_path, None)?;
+        let evaluated = evaluate_site_vector_artifacts(
+            repo_root,
+            contract,
+            obligation_id,
+            &case_payload.artifacts,
+            &case_path,
+        )?;
+        (
+            evaluated.result,
+            evaluated.failure_classes,
+            evaluated.details,
+            expect.result,
+            expect.expected_failure_classes,
+        )
+    };
+    let (result, failure_classes, details, expected_result, expected_failure_classes) = evaluated;
+    let failure_classes = dedupe_sorted(failure_classes);
+    let expected_failure_classes = dedupe_sorted(expected_failure_classes);
+    let failure_class_diff = (!expected_failure_classes.is_empty())
+        .then(|| failure_class_set_diff(&expected_failure_classes, &failure_classes));
+    let matched = result == expected_result
+        && failure_class_diff
+            .as_ref()
+            .is_none_or(FailureClassDiff::is_empty);
+    Ok(SingleVectorEvaluation {
+        obligation_id: obligation_id.to_string(),
+        vector_id: vector_id.to_string(),
+        result,
+        failure_classes,
+        expected_result,
+        expected_failure_classes,
+        matched,
+        failure_class_diff,
+        details,
+    })
+}
+
+/// Failure-class kinds (`coherence.{obligation_id}.{kind}`) each per-vector
+/// site evaluator can emit; `expect.json` classes outside this set are typos.
+const SITE_EVALUATOR_FAILURE_KINDS: &[(&str, &[&str])] = &[
+    ("coverage_base_change", &["violation"]),
+    (
+        "span_square_commutation",
+        &[
+            "unreferenced_artifact",
+            "unregistered_span_kind",
+            "violation",
+        ],
+    ),
+    (
+        "coverage_transitivity",
+        &["associativity_violation", "violation"],
+    ),
+    (
+        "glue_or_witness_contractibility",
+        &[
+            "unknown_obstruction_class",
+            "violation",
+            "witness_references_unknown_local",
+        ],
+    ),
+    ("cwf_substitution_identity", &["violation"]),
+    ("cwf_substitution_composition", &["violation"]),
+    ("cwf_comprehension_beta", &["violation"]),
+    ("cwf_comprehension_eta", &["violation"]),
+    (
+        "gate_chain_parity",
+        &[
+            "evidence_factorization_ambiguous",
+            "evidence_factorization_invalid",
+            "evidence_factorization_missing",
+            "evidence_factorization_unbound",
+            "lane_artifact_kind_cross_lane_duplicate",
+            "lane_artifact_kind_unknown",
+            "lane_failure_class_mismatch",
+            "lane_kind_unbound",
+            "lane_ownership_violation",
+            "lane_route_missing",
+            "lane_unknown",
+            "stage1_parity_invalid",
+            "stage1_parity_mismatch",
+            "stage1_parity_missing",
+            "stage1_parity_unbound",
+            "stage1_rollback_failure_class_mismatch",
+            "stage1_rollback_invalid",
+            "stage1_rollback_precondition_missing",
+            "stage1_rollback_trigger_drift",
+            "stage1_rollback_unbound",
+            "stage2_authority_alias_violation",
+            "stage2_authority_alias_window_violation",
+            "stage2_authority_invalid",
+            "stage2_authority_unbound",
+            "stage2_kernel_compliance_drift",
+            "stage2_kernel_compliance_missing",
+            "stage_digest_ref_drift",
+            "vector_missing_case_context",
+            "worker_lane_mutation_mode_drift",
+            "worker_lane_policy_drift",
+            "worker_lane_route_unbound",
+        ],
+    ),
+];
+
+/// Expected classes that `obligation_id`'s site evaluator can never emit.
+/// Obligations without a catalog entry report nothing.
+fn unknown_expected_failure_classes(obligation_id: &str, expected: &[String]) -> Vec<String> {
+    let Some((_, kinds)) = SITE_EVALUATOR_FAILURE_KINDS
+        .iter()
+        .find(|(id, _)| *id == obligation_id)
+    else {
+        return Vec::new();
+    };
+    let prefix = format!("coherence.{obligation_id}.");
+    expected
+        .iter()
+        .filter(|class| {
+            class
+                .strip_prefix(prefix.as_str())
+                .is_none_or(|kind| !kinds.contains(&kind))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Dispatch site-vector artifacts to the evaluator used by `obligation_id`.
+fn evaluate_site_vector_artifacts(
+    repo_root: &Path,
+    contract: &CoherenceContract,
+    obligation_id: &str,
+    artifacts: &Value,
+    case_path: &Path,
+) -> Result<SiteEvaluation, CoherenceError> {
+    match obligation_id {
+        "coverage_base_change" => {
+            evaluate_site_case_coverage_base_change(artifacts, case_path, contract.digest_scheme)
+        }
+        "span_square_commutation" => {
+            let span_kind_registry = load_span_kind_registry(repo_root, contract)?;
+            evaluate_site_case_span_square_commutation(
+                artifacts,
+                case_path,
+                span_kind_registry.as_ref(),
+                contract.digest_scheme,
+            )
+        }
+        "coverage_transitivity" => {
+            evaluate_site_case_coverage_transitivity(artifacts, case_path, contract.digest_scheme)
+        }
+        "glue_or_witness_contractibility" => evaluate_site_case_glue_or_witness_contractibility(
+            artifacts,
+            case_path,
+            contract.digest_scheme,
+        ),
+        "cwf_substitution_identity" => evaluate_site_case_cwf_substitution_identity(
+            artifacts,
+            case_path,
+            contract.digest_scheme,
+        ),
+        "cwf_substitution_composition" => evaluate_site_case_cwf_substitution_composition(
+            artifacts,
+            case_path,
+            contract.digest_scheme,
+        ),
+        "cwf_comprehension_beta" => {
+            evaluate_site_case_cwf_comprehension_beta(artifacts, case_path, contract.digest_scheme)
+        }
+        "cwf_comprehension_eta" => {
+            evaluate_site_case_cwf_comprehension_eta(artifacts, case_path, contract.digest_scheme)
+        }
+        "gate_chain_parity" => evaluate_site_case_gate_chain_parity(
+            artifacts,
+            case_path,
+            &contract.control_plane_contract_kind,
+        ),
+        other => Err(CoherenceError::Contract(format!(
+            "obligation `{other}` has no per-vector evaluator"
+        ))),
+    }
+}
+
+/// Aggregate failure classes gained and lost relative to a baseline witness.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoherenceRegressionSummary {
+    pub new_failures: Vec<String>,
+    pub resolved_failures: Vec<String>,
+    /// True only when `new_failures` is non-empty; resolving failures alone
+    /// is an improvement, not a regression.
+    pub is_regression: bool,
+}
+
+/// A coherence witness plus its comparison against an optional baseline.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoherenceCheckOutcome {
+    pub witness: CoherenceWitness,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regression: Option<CoherenceRegressionSummary>,
+}
+
+pub fn coherence_regression_summary(
+    baseline: &CoherenceWitness,
+    current: &CoherenceWitness,
+) -> CoherenceRegressionSummary {
+    let diff = failure_class_set_diff(&baseline.failure_classes, &current.failure_classes);
+    CoherenceRegressionSummary {
+        is_regression: !diff.unexpected.is_empty(),
+        new_failures: diff.unexpected,
+        resolved_failures: diff.missing,
+    }
+}
+
+/// Run the coherence check and, when `baseline` is given, summarize failure
+/// classes gained and resolved since that witness.
+pub fn run_coherence_check_with_baseline(
+    repo_root: impl AsRef<Path>,
+    contract_path: impl AsRef<Path>,
+    baseline: Option<&CoherenceWitness>,
+) -> Result<CoherenceCheckOutcome, CoherenceError> {
+    let witness = run_coherence_check(repo_root, contract_path)?;
+    let regression = baseline.map(|baseline| coherence_regression_summary(baseline, &witness));
+    Ok(CoherenceCheckOutcome {
+        witness,
+        regression,
+    })
+}
+
+/// Compare the surfaces a prior witness's contract declared against
+/// `new_contract`.
+///
+/// Paths are compared after lexical normalization, so `./a.md` and `a.md`
+/// are the same surface. Declared paths are compared whether or not either
+/// run read them, so an unread surface never shows up as drift.
+pub fn surface_drift(old: &CoherenceWitness, new_contract: &CoherenceContract) -> SurfaceDrift {
+    let new_surfaces = declared_surface_paths(&new_contract.surfaces);
+    let mut drift = SurfaceDrift::default();
+    for (field, old_path) in &old.declared_surfaces {
+        match new_surfaces.get(field) {
+            None => drift.removed.push(field.clone()),
+            Some(new_path) if new_path != old_path => drift.repointed.push(SurfaceRepoint {
+                field: field.clone(),
+                old_path: old_path.clone(),
+                new_path: new_path.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for field in new_surfaces.keys() {
+        if !old.declared_surfaces.contains_key(field) {
+            drift.added.push(field.clone());
+        }
+    }
+    drift
+}
+
+/// Declared surface paths, lexically normalized; the material `surface_drift`
+/// compares.
+fn declared_surface_paths(surfaces: &CoherenceSurfaces) -> BTreeMap<String, String> {
+    contract_surface_paths(surfaces)
+        .into_iter()
+        .map(|(field, path)| {
+            (
+                field,
+                display_path(&normalize_surface_path(Path::new(&path))),
+            )
+        })
+        .collect()
+}
+
+/// Surface fields (camelCase) each obligation reads, directly or through the
+/// helpers it calls. The path fields are checked against the files each
+/// obligation actually consults.
+const OBLIGATION_SURFACE_FIELDS: &[(&str, &[&str])] = &[
+    (
+        "scope_noncontradiction",
+        &[
+            "bidirSpecPath",
+            "bidirSpecSectionEnd",
+            "bidirSpecSectionStart",
+            "capabilityRegistryKind",
+            "capabilityRegistryPath",
+            "coherenceSpecObligationEnd",
+            "coherenceSpecObligationStart",
+            "coherenceSpecPath",
+            "conformancePath",
+            "informativeClauseNeedle",
+            "informativeClauseRegex",
+            "obligationRegistryKind",
+            "specIndexCapabilityHeading",
+            "specIndexInformativeHeading",
+            "specIndexPath",
+        ],
+    ),
+    (
+        "capability_parity",
+        &[
+            "capabilityManifestRoot",
+            "capabilityReadmeSectionEnd",
+            "capabilityReadmeSectionStart",
+            "capabilityRegistryKind",
+            "capabilityRegistryPath",
+            "conformanceCapabilityHeading",
+            "conformancePath",
+            "conformanceReadmePath",
+            "readmePath",
+            "specIndexCapabilityHeading",
+            "specIndexPath",
+        ],
+    ),
+    (
+        "gate_chain_parity",
+        &[
+            "ciClosureBaselineEnd",
+            "ciClosureBaselineStart",
+            "ciClosurePath",
+            "ciClosureProjectionEnd",
+            "ciClosureProjectionStart",
+            "controlPlaneContractPath",
+            "miseBaselineTask",
+            "misePath",
+            "siteFixtureRootPath",
+        ],
+    ),
+    (
+        "operation_reachability",
+        &[
+            "doctrineOperationRegistryPath",
+            "doctrineRootNodeId",
+            "doctrineSiteInputPath",
+            "doctrineSitePath",
+        ],
+    ),
+    (
+        "overlay_traceability",
+        &[
+            "doctrineRootNodeId",
+            "doctrineSitePath",
+            "profileReadmePath",
+            "specIndexOverlayHeading",
+            "specIndexPath",
+        ],
+    ),
+    ("transport_functoriality", &["transportFixtureRootPath"]),
+    (
+        "span_square_commutation",
+        &["controlPlaneContractPath", "siteFixtureRootPath"],
+    ),
+    ("coverage_base_change", &["siteFixtureRootPath"]),
+    ("coverage_transitivity", &["siteFixtureRootPath"]),
+    ("glue_or_witness_contractibility", &["siteFixtureRootPath"]),
+    ("cwf_substitution_identity", &["siteFixtureRootPath"]),
+    ("cwf_substitution_composition", &["siteFixtureRootPath"]),
+    ("cwf_comprehension_beta", &["siteFixtureRootPath"]),
+    ("cwf_comprehension_eta", &["siteFixtureRootPath"]),
+];
+
+/// Obligations of `new` that need re-running after changing the contract from
+/// `old`: those reading a surface field whose value changed. A change outside
+/// `surfaces` affects every obligation.
+pub fn affected_obligations(old: &CoherenceContract, new: &CoherenceContract) -> BTreeSet<String> {
+    let all_obligations = || {
+        new.obligations
+            .iter()
+            .map(|item| item.id.trim().to_string())
+            .collect()
+    };
+    let mut old_outside_surfaces = old.clone();
+    old_outside_surfaces.surfaces = new.surfaces.clone();
+    if old_outside_surfaces != *new {
+        return all_obligations();
+    }
+    let old_surfaces = serde_json::to_value(&old.surfaces).expect("surfaces serialization");
+    let new_surfaces = serde_json::to_value(&new.surfaces).expect("surfaces serialization");
+    if old_surfaces == new_surfaces {
+        return BTreeSet::new();
+    }
+    new.obligations
+        .iter()
+        .map(|item| item.id.trim())
+        .filter(|id| {
+            OBLIGATION_SURFACE_FIELDS
+                .iter()
+                .find(|(obligation_id, _)| obligation_id == id)
+                .is_none_or(|(_, fields)| {
+                    fields
+                        .iter()
+                        .any(|field| old_surfaces.get(field) != new_surfaces.get(field))
+                })
+        })
+        .map(ToString::to_string)
+        .collect()
+}
+
+fn contract_surface_paths(surfaces: &CoherenceSurfaces) -> BTreeMap<String, String> {
+    [
+        ("capabilityRegistryPath", &surfaces.capability_registry_path),
+        ("conformancePath", &surfaces.conformance_path),
+        ("capabilityManifestRoot", &surfaces.capability_manifest_root),
+        ("readmePath", &surfaces.readme_path),
+        ("conformanceReadmePath", &surfaces.conformance_readme_path),
+        ("specIndexPath", &surfaces.spec_index_path),
+        ("ciClosurePath", &surfaces.ci_closure_path),
+        ("misePath", &surfaces.mise_path),
+        (
+            "controlPlaneContractPath",
+            &surfaces.control_plane_contract_path,
+        ),
+        ("doctrineSitePath", &surfaces.doctrine_site_path),
+        ("doctrineSiteInputPath", &surfaces.doctrine_site_input_path),
+        (
+            "doctrineOperationRegistryPath",
+            &surfaces.doctrine_operation_registry_path,
+        ),
+        ("profileReadmePath", &surfaces.profile_readme_path),
+        ("bidirSpecPath", &surfaces.bidir_spec_path),
+        ("coherenceSpecPath", &surfaces.coherence_spec_path),
+        (
+            "transportFixtureRootPath",
+            &surfaces.transport_fixture_root_path,
+        ),
+        ("siteFixtureRootPath", &surfaces.site_fixture_root_path),
+    ]
+    .into_iter()
+    .filter_map(|(field, path)| {
+        non_empty_trimmed(Some(path.as_str())).map(|path| (field.to_string(), path))
     })
+    .collect()
+}
+
+/// Declared surface paths whose resolved file, or a file beneath the
+/// resolved root, was read; existence probes alone do not count.
+fn consulted_surface_paths(
+    repo_root: &Path,
+    surfaces: &CoherenceSurfaces,
+    consulted_inputs: &BTreeMap<PathBuf, String>,
+) -> BTreeMap<String, String> {
+    contract_surface_paths(surfaces)
+        .into_iter()
+        .filter_map(|(field, path)| {
+            let resolved = normalize_surface_path(&resolve_path(repo_root, &path));
+            consulted_inputs
+                .iter()
+                .filter(|(_, digest)| {
+                    !matches!(
+                        digest.as_str(),
+                        CONSULTED_INPUT_ABSENT | CONSULTED_INPUT_PRESENT
+                    )
+                })
+                .any(|(input, _)| normalize_surface_path(input).starts_with(&resolved))
+                .then(|| {
+                    let root = normalize_surface_path(repo_root);
+                    (field, to_repo_relative_or_absolute(&root, &resolved))
+                })
+        })
+        .collect()
+}
+
+/// Lexically drop `.` components so equivalent spellings compare equal.
+fn normalize_surface_path(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, std::path::Component::CurDir))
+        .collect()
 }
 
 fn compile_coherence_constructor(
@@ -1011,7 +2437,11 @@ fn compile_coherence_constructor(
         schema: 1,
         constructor_kind: "premath.coherence.constructor.v1".to_string(),
         contract_ref: to_repo_relative_or_absolute(repo_root, contract_path),
-        contract_digest: format!("cohctr1_{}", hex_sha256_from_bytes(contract_bytes)),
+        contract_digest: format!(
+            "{}{}",
+            contract.digest_scheme.prefix("cohctr"),
+            hex_sha256_from_bytes(contract_bytes)
+        ),
         binding: contract.binding.clone(),
         declared_obligation_ids,
         required_obligation_ids,
@@ -1028,16 +2458,27 @@ fn compile_coherence_constructor(
     }
 }
 
-fn execute_obligation(
+fn run_obligation(
     obligation_id: &str,
     repo_root: &Path,
     contract: &CoherenceContract,
-) -> ObligationCheck {
-    let result = match obligation_id {
-        "scope_noncontradiction" => check_scope_noncontradiction(repo_root, contract),
-        "capability_parity" => check_capability_parity(repo_root, contract),
+) -> Result<ObligationCheck, CoherenceError> {
+    match obligation_id {
+        #[cfg(feature = "markdown-surfaces")]
+        "scope_noncontradiction" => check_scope_noncontradiction(
+            repo_root,
+            contract,
+            contract.inline_capability_registry.as_ref(),
+        ),
+        #[cfg(feature = "markdown-surfaces")]
+        "capability_parity" => check_capability_parity(
+            repo_root,
+            contract,
+            contract.inline_capability_registry.as_ref(),
+        ),
         "gate_chain_parity" => check_gate_chain_parity(repo_root, contract),
         "operation_reachability" => check_operation_reachability(repo_root, contract),
+        #[cfg(feature = "markdown-surfaces")]
         "overlay_traceability" => check_overlay_traceability(repo_root, contract),
         "transport_functoriality" => check_transport_functoriality(repo_root, contract),
         "span_square_commutation" => check_span_square_commutation(repo_root, contract),
@@ -1053,20 +2494,61 @@ fn execute_obligation(
         _ => Err(CoherenceError::Contract(format!(
             "unknown obligation id: {obligation_id}"
         ))),
+    }
+}
+
+/// Add the classes listed under `details.warnings` (pending vectors,
+/// polarity imbalance, semantic depth hits) to `check.failure_classes`.
+fn promote_detail_warnings(check: &mut ObligationCheck) {
+    let Some(warnings) = check.details.get("warnings").and_then(Value::as_array) else {
+        return;
     };
+    let mut failure_classes = std::mem::take(&mut check.failure_classes);
+    failure_classes.extend(
+        warnings
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string),
+    );
+    check.failure_classes = dedupe_sorted(failure_classes);
+}
 
-    match result {
-        Ok(ok) => ok,
-        Err(err) => ObligationCheck {
-            failure_classes: vec![format!("coherence.{obligation_id}.surface_error")],
-            details: json!({ "error": err.to_string() }),
-        },
+fn surface_error_check(obligation_id: &str, err: &CoherenceError) -> ObligationCheck {
+    ObligationCheck {
+        failure_classes: vec![format!("coherence.{obligation_id}.surface_error")],
+        details: json!({ "error": err.to_string() }),
+    }
+}
+
+/// Failure classes an obligation reports for a declared file that does not
+/// exist yet; `tolerateSurfaceErrors` downgrades them like a missing surface.
+const TOLERABLE_MISSING_FILE_CLASSES: &[&str] =
+    &["coherence.overlay_traceability.overlay_file_missing"];
+
+/// True when `err` is a read of a declared surface (or a file beneath a
+/// declared fixture root) that does not exist.
+fn is_missing_declared_surface(
+    repo_root: &Path,
+    surfaces: &CoherenceSurfaces,
+    err: &CoherenceError,
+) -> bool {
+    let CoherenceError::ReadFile { path, source } = err else {
+        return false;
+    };
+    if source.kind() != std::io::ErrorKind::NotFound {
+        return false;
     }
+    let missing = normalize_surface_path(Path::new(path));
+    contract_surface_paths(surfaces).values().any(|declared| {
+        missing.starts_with(normalize_surface_path(&resolve_path(repo_root, declared)))
+    })
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn check_scope_noncontradiction(
     repo_root: &Path,
     contract: &CoherenceContract,
+    registry_override: Option<&Value>,
 ) -> Result<ObligationCheck, CoherenceError> {
     let spec_index_path = resolve_path(repo_root, contract.surfaces.spec_index_path.as_str());
     let spec_index_text = read_text(&spec_index_path)?;
@@ -1081,7 +2563,7 @@ fn check_scope_noncontradiction(
     let spec_index_doc_map = parse_spec_index_capability_doc_map(&section_54)?;
 
     let mut failures = Vec::new();
-    if !section_55.contains(contract.surfaces.informative_clause_needle.as_str()) {
+    if !contains_informative_clause(&section_55, &contract.surfaces)? {
         failures.push("coherence.scope_noncontradiction.informative_clause_missing".to_string());
     }
     for row in &contract.conditional_capability_docs {
@@ -1100,7 +2582,7 @@ fn check_scope_noncontradiction(
         }
     }
 
-    let capability_registry = load_capability_registry(repo_root, contract)?;
+    let capability_registry = load_capability_registry(repo_root, contract, registry_override)?;
     let conformance_path = resolve_path(repo_root, contract.surfaces.conformance_path.as_str());
     let conformance_text = read_text(&conformance_path)?;
     let conformance_overlay_section = extract_heading_section(&conformance_text, "2.4")?;
@@ -1116,10 +2598,36 @@ fn check_scope_noncontradiction(
             "coherence.scope_noncontradiction.profile_overlay_registry_duplicate".to_string(),
         );
     }
+    if contract.require_sorted_profile_claims
+        && !capability_registry.profile_overlay_claims.is_sorted()
+    {
+        failures.push("coherence.scope_noncontradiction.profile_overlay_unsorted".to_string());
+    }
     if registry_profile_claims != conformance_profile_claims {
         failures
             .push("coherence.scope_noncontradiction.profile_overlay_claim_mismatch".to_string());
     }
+    if conformance_profile_claims
+        .iter()
+        .chain(registry_profile_claims.iter())
+        .any(|claim| !validate_profile_claim_format(claim))
+    {
+        failures.push(
+            "coherence.scope_noncontradiction.profile_overlay_claim_format_invalid".to_string(),
+        );
+    }
+
+    let overlay_doc_filename_collisions = overlay_doc_filename_collisions(&contract.overlay_docs);
+    if contract
+        .overlay_docs
+        .iter()
+        .any(|overlay_ref| !validate_overlay_doc_ref(overlay_ref))
+    {
+        failures.push(OVERLAY_DOC_REF_FORMAT_INVALID_FAILURE.to_string());
+    }
+    if !overlay_doc_filename_collisions.is_empty() {
+        failures.push(OVERLAY_DOC_FILENAME_COLLISION_FAILURE.to_string());
+    }
 
     let bidir_spec_path = resolve_path(repo_root, contract.surfaces.bidir_spec_path.as_str());
     let bidir_spec_text = read_text(&bidir_spec_path)?;
@@ -1181,6 +2689,7 @@ fn check_scope_noncontradiction(
             "specIndexCapabilityDocMap": spec_index_doc_map,
             "registryProfileOverlayClaims": registry_profile_claims,
             "conformanceProfileOverlayClaims": conformance_profile_claims,
+            "overlayDocFilenameCollisions": overlay_doc_filename_collisions,
             "requiredBidirObligations": contract.required_bidir_obligations,
             "bidirSpecObligations": bidir_spec_obligations,
             "bidirCheckerObligations": bidir_checker_obligations,
@@ -1191,51 +2700,82 @@ fn check_scope_noncontradiction(
     })
 }
 
+/// Source label reported for a capability registry passed in memory.
+#[cfg(feature = "markdown-surfaces")]
+const INLINE_CAPABILITY_REGISTRY_SOURCE: &str = "inlineCapabilityRegistry";
+
+/// Load the capability registry from `registry_override` when present, else
+/// from `surfaces.capabilityRegistryPath`.
+#[cfg(feature = "markdown-surfaces")]
 fn load_capability_registry(
     repo_root: &Path,
     contract: &CoherenceContract,
+    registry_override: Option<&Value>,
 ) -> Result<CapabilityRegistry, CoherenceError> {
-    let capability_registry_path = resolve_path(
-        repo_root,
-        contract.surfaces.capability_registry_path.as_str(),
-    );
+    let (parsed, source) = match registry_override {
+        Some(registry) => (
+            serde_json::from_value(registry.clone()),
+            INLINE_CAPABILITY_REGISTRY_SOURCE.to_string(),
+        ),
+        None => {
+            let capability_registry_path = resolve_path(
+                repo_root,
+                contract.surfaces.capability_registry_path.as_str(),
+            );
+            (
+                serde_json::from_slice(&read_bytes(&capability_registry_path)?),
+                display_path(&capability_registry_path),
+            )
+        }
+    };
     let capability_registry: CapabilityRegistry =
-        serde_json::from_slice(&read_bytes(&capability_registry_path)?).map_err(|source| {
-            CoherenceError::ParseJson {
-                path: display_path(&capability_registry_path),
-                source,
-            }
+        parsed.map_err(|source_err| CoherenceError::ParseJson {
+            path: source.clone(),
+            source: source_err,
         })?;
     if capability_registry.schema != 1 {
-        return Err(CoherenceError::Contract(format!(
-            "capability registry schema must be 1: {}",
-            display_path(&capability_registry_path)
-        )));
+        return Err(CoherenceError::SchemaMismatch {
+            expected: 1,
+            got: capability_registry.schema,
+            path: source,
+            field: "schema".to_string(),
+        });
     }
     if capability_registry.registry_kind != contract.surfaces.capability_registry_kind {
-        return Err(CoherenceError::Contract(format!(
-            "capability registry kind mismatch at {}: expected {:?}, got {:?}",
-            display_path(&capability_registry_path),
-            contract.surfaces.capability_registry_kind,
-            capability_registry.registry_kind
-        )));
+        return Err(CoherenceError::ContractKindMismatch {
+            expected: contract.surfaces.capability_registry_kind.clone(),
+            got: capability_registry.registry_kind,
+            path: source,
+            field: "registryKind".to_string(),
+        });
     }
     Ok(capability_registry)
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn check_capability_parity(
     repo_root: &Path,
     contract: &CoherenceContract,
+    registry_override: Option<&Value>,
 ) -> Result<ObligationCheck, CoherenceError> {
     let capability_registry_path = resolve_path(
         repo_root,
         contract.surfaces.capability_registry_path.as_str(),
     );
-    let capability_registry = load_capability_registry(repo_root, contract)?;
+    let capability_registry_source = if registry_override.is_some() {
+        INLINE_CAPABILITY_REGISTRY_SOURCE.to_string()
+    } else {
+        to_repo_relative_or_absolute(repo_root, &capability_registry_path)
+    };
+    let capability_registry = load_capability_registry(repo_root, contract, registry_override)?;
     if capability_registry.executable_capabilities.is_empty() {
         return Err(CoherenceError::Contract(format!(
             "capability registry must include at least one capability: {}",
-            display_path(&capability_registry_path)
+            if registry_override.is_some() {
+                INLINE_CAPABILITY_REGISTRY_SOURCE.to_string()
+            } else {
+                display_path(&capability_registry_path)
+            }
         )));
     }
     let executable_capabilities = dedupe_sorted(capability_registry.executable_capabilities);
@@ -1264,11 +2804,39 @@ fn check_capability_parity(
         contract.surfaces.spec_index_capability_heading.as_str(),
     )?;
 
-    let readme_set = parse_backticked_capabilities(&readme_text)?;
-    let conformance_readme_set = parse_backticked_capabilities(&conformance_readme_text)?;
+    let mut failures = Vec::new();
+    // A configured but missing marker falls back to the whole README so the
+    // set comparison still runs, and is reported on its own.
+    let readme_section = extract_capability_section(&readme_text, &contract.surfaces)?
+        .unwrap_or_else(|| {
+            failures.push("coherence.capability_parity.readme_section_missing".to_string());
+            readme_text.clone()
+        });
+    let conformance_readme_section = extract_capability_section(
+        &conformance_readme_text,
+        &contract.surfaces,
+    )?
+    .unwrap_or_else(|| {
+        failures.push("coherence.capability_parity.conformance_readme_section_missing".to_string());
+        conformance_readme_text.clone()
+    });
+    let readme_set = parse_backticked_capabilities(&readme_section)?;
+    let conformance_readme_set = parse_backticked_capabilities(&conformance_readme_section)?;
     let spec_index_set = parse_backticked_capabilities(&section_54)?;
+    let conformance_heading = contract.surfaces.conformance_capability_heading.trim();
+    let conformance_set = if conformance_heading.is_empty() {
+        None
+    } else {
+        let conformance_text = read_text(&resolve_path(
+            repo_root,
+            contract.surfaces.conformance_path.as_str(),
+        ))?;
+        Some(parse_backticked_capabilities(&extract_heading_section(
+            &conformance_text,
+            conformance_heading,
+        )?)?)
+    };
 
-    let mut failures = Vec::new();
     if manifest_set != executable_set {
         failures.push("coherence.capability_parity.manifest_set_mismatch".to_string());
     }
@@ -1281,17 +2849,24 @@ fn check_capability_parity(
     if spec_index_set != executable_set {
         failures.push("coherence.capability_parity.spec_index_set_mismatch".to_string());
     }
+    if conformance_set
+        .as_ref()
+        .is_some_and(|conformance_set| *conformance_set != executable_set)
+    {
+        failures.push("coherence.capability_parity.conformance_capability_mismatch".to_string());
+    }
 
     Ok(ObligationCheck {
         failure_classes: dedupe_sorted(failures),
         details: json!({
             "expected": executable_capabilities,
             "capabilityRegistryKind": capability_registry.registry_kind,
-            "capabilityRegistryPath": to_repo_relative_or_absolute(repo_root, &capability_registry_path),
+            "capabilityRegistryPath": capability_registry_source,
             "manifest": sorted_vec_from_set(&manifest_set),
             "readme": sorted_vec_from_set(&readme_set),
             "conformanceReadme": sorted_vec_from_set(&conformance_readme_set),
             "specIndex": sorted_vec_from_set(&spec_index_set),
+            "conformance": conformance_set.as_ref().map(sorted_vec_from_set),
         }),
     })
 }
@@ -1338,29 +2913,59 @@ fn resolve_schema_lifecycle_kind(
         return Ok(canonical_kind.to_string());
     }
 
-    let mut seen_aliases: BTreeSet<String> = BTreeSet::new();
+    // alias kind -> (replacement kind, support-until epoch)
+    let mut aliases: BTreeMap<&str, (&str, &str)> = BTreeMap::new();
     for alias in &family.compatibility_aliases {
         let alias_kind = alias.alias_kind.trim();
         let support_until_epoch = alias.support_until_epoch.trim();
         let replacement_kind = alias.replacement_kind.trim();
         if alias_kind.is_empty()
-            || replacement_kind != canonical_kind
+            || alias_kind == canonical_kind
+            || replacement_kind.is_empty()
             || !is_valid_epoch(support_until_epoch)
-            || !seen_aliases.insert(alias_kind.to_string())
+            || aliases
+                .insert(alias_kind, (replacement_kind, support_until_epoch))
+                .is_some()
         {
             return Err(format!(
                 "kind family `{family_id}` has invalid compatibilityAliases rows"
             ));
         }
-        if alias_kind == kind.trim() {
-            if schema_lifecycle.active_epoch.as_str() > support_until_epoch {
+    }
+    // Aliases may replace into other aliases; every chain must reach the
+    // canonical kind without revisiting an alias.
+    for alias_kind in aliases.keys() {
+        let mut visited: BTreeSet<&str> = BTreeSet::new();
+        let mut current = *alias_kind;
+        while current != canonical_kind {
+            if !visited.insert(current) {
                 return Err(format!(
-                    "kind `{kind}` for `{family_id}` expired at `{support_until_epoch}` (activeEpoch=`{}`)",
-                    schema_lifecycle.active_epoch
+                    "kind family `{family_id}` compatibilityAliases chain from `{alias_kind}` cycles at `{current}`"
                 ));
             }
-            return Ok(canonical_kind.to_string());
+            let Some((replacement_kind, _)) = aliases.get(current) else {
+                return Err(format!(
+                    "kind family `{family_id}` compatibilityAliases chain from `{alias_kind}` ends at `{current}` instead of canonicalKind `{canonical_kind}`"
+                ));
+            };
+            current = replacement_kind;
+        }
+    }
+    if let Some(&(_, mut support_until_epoch)) = aliases.get(kind.trim()) {
+        // A chain is only supported while every hop is: its support window is
+        // the earliest supportUntilEpoch along the way.
+        let mut current = kind.trim();
+        while let Some((replacement_kind, hop_epoch)) = aliases.get(current) {
+            support_until_epoch = support_until_epoch.min(*hop_epoch);
+            current = replacement_kind;
         }
+        if schema_lifecycle.active_epoch.as_str() > support_until_epoch {
+            return Err(format!(
+                "kind `{kind}` for `{family_id}` expired at `{support_until_epoch}` (activeEpoch=`{}`)",
+                schema_lifecycle.active_epoch
+            ));
+        }
+        return Ok(canonical_kind.to_string());
     }
 
     Err(format!(
@@ -1368,24 +2973,85 @@ fn resolve_schema_lifecycle_kind(
     ))
 }
 
+/// A control-plane reason localized to the contract field it concerns and
+/// the failure class it accompanies.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct Reason {
+    field: String,
+    message: String,
+    class: String,
+}
+
+impl Reason {
+    fn new(field: &str, class: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+            class: class.to_string(),
+        }
+    }
+}
+
+/// Record `class` as a failure, explained by `message` about `field`.
+fn push_reason(
+    failures: &mut Vec<String>,
+    reasons: &mut Vec<Reason>,
+    class: &str,
+    field: &str,
+    message: impl Into<String>,
+) {
+    failures.push(class.to_string());
+    reasons.push(Reason::new(field, class, message));
+}
+
+/// The flat `details.reasons` messages.
+fn flat_reasons(reasons: &[Reason]) -> Vec<String> {
+    dedupe_sorted(
+        reasons
+            .iter()
+            .map(|reason| reason.message.clone())
+            .collect(),
+    )
+}
+
+/// The `details.structuredReasons` rows, ordered by field.
+fn structured_reasons(reasons: &[Reason]) -> Vec<Reason> {
+    reasons
+        .iter()
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
 fn resolve_or_record_schema_kind(
     schema_lifecycle: &ControlPlaneSchemaLifecycle,
     family_id: &str,
     field_name: &str,
     value: &str,
     failures: &mut Vec<String>,
-    reasons: &mut Vec<String>,
+    reasons: &mut Vec<Reason>,
 ) -> Option<String> {
     if value.trim().is_empty() {
-        failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-        reasons.push(format!("{field_name} must be non-empty"));
+        push_reason(
+            failures,
+            reasons,
+            GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+            field_name,
+            format!("{field_name} must be non-empty"),
+        );
         return None;
     }
     match resolve_schema_lifecycle_kind(schema_lifecycle, family_id, value) {
         Ok(resolved_kind) => Some(resolved_kind),
         Err(reason) => {
-            failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-            reasons.push(format!("{field_name}: {reason}"));
+            push_reason(
+                failures,
+                reasons,
+                GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                field_name,
+                format!("{field_name}: {reason}"),
+            );
             None
         }
     }
@@ -1393,6 +3059,7 @@ fn resolve_or_record_schema_kind(
 
 fn evaluate_control_plane_schema_lifecycle(
     control_plane_contract: &ControlPlaneProjectionContract,
+    expected_contract_kind: &str,
 ) -> ObligationCheck {
     let mut failures = Vec::new();
     let mut reasons = Vec::new();
@@ -1405,18 +3072,28 @@ fn evaluate_control_plane_schema_lifecycle(
             details: json!({
                 "present": false,
                 "requiredKindFamilies": REQUIRED_SCHEMA_LIFECYCLE_FAMILIES,
-                "reasons": ["schemaLifecycle missing"]
+                "reasons": ["schemaLifecycle missing"],
+                "structuredReasons": [Reason::new(
+                    "schemaLifecycle",
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                    "schemaLifecycle missing",
+                )],
             }),
         };
     };
 
     let active_epoch = schema_lifecycle.active_epoch.trim();
     if !is_valid_epoch(active_epoch) {
-        failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-        reasons.push(format!(
-            "schemaLifecycle.activeEpoch invalid (expected YYYY-MM, got `{}`)",
-            schema_lifecycle.active_epoch
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+            "schemaLifecycle.activeEpoch",
+            format!(
+                "schemaLifecycle.activeEpoch invalid (expected YYYY-MM, got `{}`)",
+                schema_lifecycle.active_epoch
+            ),
+        );
     }
 
     let expected_families: BTreeSet<String> = REQUIRED_SCHEMA_LIFECYCLE_FAMILIES
@@ -1462,8 +3139,13 @@ fn evaluate_control_plane_schema_lifecycle(
         "aliasRunwayMonths": Value::Null,
     });
     let Some(governance) = &schema_lifecycle.governance else {
-        failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-        reasons.push("schemaLifecycle.governance missing".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+            "schemaLifecycle.governance",
+            "schemaLifecycle.governance missing",
+        );
         return ObligationCheck {
             failure_classes: dedupe_sorted(failures),
             details: json!({
@@ -1475,7 +3157,8 @@ fn evaluate_control_plane_schema_lifecycle(
                 "unknownKindFamilies": unknown_families,
                 "resolvedKinds": resolved,
                 "governance": governance_details,
-                "reasons": dedupe_sorted(reasons),
+                "reasons": flat_reasons(&reasons),
+                "structuredReasons": structured_reasons(&reasons),
             }),
         };
     };
@@ -1490,16 +3173,31 @@ fn evaluate_control_plane_schema_lifecycle(
     governance_details["freezeReason"] = json!(governance.freeze_reason.as_deref());
 
     if governance_decision_ref.is_empty() {
-        failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-        reasons.push("schemaLifecycle.governance.decisionRef must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+            "schemaLifecycle.governance.decisionRef",
+            "schemaLifecycle.governance.decisionRef must be non-empty",
+        );
     }
     if governance_owner.is_empty() {
-        failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-        reasons.push("schemaLifecycle.governance.owner must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+            "schemaLifecycle.governance.owner",
+            "schemaLifecycle.governance.owner must be non-empty",
+        );
     }
     if governance_mode.is_empty() {
-        failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-        reasons.push("schemaLifecycle.governance.mode must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+            "schemaLifecycle.governance.mode",
+            "schemaLifecycle.governance.mode must be non-empty",
+        );
     }
 
     match governance_mode {
@@ -1507,41 +3205,51 @@ fn evaluate_control_plane_schema_lifecycle(
             let cadence_months = match governance.rollover_cadence_months {
                 Some(value) => value,
                 None => {
-                    failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                    reasons.push(
-                        "schemaLifecycle.governance.rolloverCadenceMonths required when mode=rollover"
-                            .to_string(),
+                    push_reason(
+                        &mut failures,
+                        &mut reasons,
+                        GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                        "schemaLifecycle.governance.rolloverCadenceMonths",
+                        "schemaLifecycle.governance.rolloverCadenceMonths required when mode=rollover",
                     );
                     0
                 }
             };
             if cadence_months == 0 || cadence_months > 12 {
-                failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                reasons.push(
-                    "schemaLifecycle.governance.rolloverCadenceMonths must be within 1..12"
-                        .to_string(),
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                    "schemaLifecycle.governance.rolloverCadenceMonths",
+                    "schemaLifecycle.governance.rolloverCadenceMonths must be within 1..12",
                 );
             }
             if let Some(freeze_reason) = governance.freeze_reason.as_deref()
                 && !freeze_reason.trim().is_empty()
             {
-                failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                reasons.push(
-                    "schemaLifecycle.governance.freezeReason is only allowed when mode=freeze"
-                        .to_string(),
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                    "schemaLifecycle.governance.freezeReason",
+                    "schemaLifecycle.governance.freezeReason is only allowed when mode=freeze",
                 );
             }
             if alias_support_epochs.is_empty() {
-                failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                reasons.push(
-                    "schemaLifecycle.governance.mode=rollover requires compatibility aliases"
-                        .to_string(),
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                    "schemaLifecycle.governance.mode",
+                    "schemaLifecycle.governance.mode=rollover requires compatibility aliases",
                 );
             } else if alias_support_epochs.len() != 1 {
-                failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                reasons.push(
-                    "schemaLifecycle.governance.mode=rollover requires one shared supportUntilEpoch"
-                        .to_string(),
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                    "schemaLifecycle.governance.mode",
+                    "schemaLifecycle.governance.mode=rollover requires one shared supportUntilEpoch",
                 );
             } else if let Some(rollover_epoch) = alias_support_epochs.iter().next() {
                 governance_details["rolloverEpoch"] = json!(rollover_epoch);
@@ -1553,22 +3261,33 @@ fn evaluate_control_plane_schema_lifecycle(
                         let runway = rollover - active;
                         governance_details["aliasRunwayMonths"] = json!(runway);
                         if runway < 1 {
-                            failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                            reasons.push(
-                                "schemaLifecycle rollover runway must be positive".to_string(),
+                            push_reason(
+                                &mut failures,
+                                &mut reasons,
+                                GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                                "schemaLifecycle",
+                                "schemaLifecycle rollover runway must be positive",
                             );
                         }
                         if cadence_months > 0 && runway > cadence_months as i32 {
-                            failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                            reasons.push(format!(
-                                "schemaLifecycle rollover runway exceeds rolloverCadenceMonths ({runway} > {cadence_months})"
-                            ));
+                            push_reason(
+                                &mut failures,
+                                &mut reasons,
+                                GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                                "schemaLifecycle",
+                                format!(
+                                    "schemaLifecycle rollover runway exceeds rolloverCadenceMonths ({runway} > {cadence_months})"
+                                ),
+                            );
                         }
                     }
                     _ => {
-                        failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                        reasons.push(
-                            "schemaLifecycle rollover runway could not be evaluated".to_string(),
+                        push_reason(
+                            &mut failures,
+                            &mut reasons,
+                            GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                            "schemaLifecycle",
+                            "schemaLifecycle rollover runway could not be evaluated",
                         );
                     }
                 }
@@ -1576,32 +3295,67 @@ fn evaluate_control_plane_schema_lifecycle(
         }
         "freeze" => {
             if governance.rollover_cadence_months.is_some() {
-                failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                reasons.push(
-                    "schemaLifecycle.governance.rolloverCadenceMonths is only allowed when mode=rollover"
-                        .to_string(),
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                    "schemaLifecycle.governance.rolloverCadenceMonths",
+                    "schemaLifecycle.governance.rolloverCadenceMonths is only allowed when mode=rollover",
                 );
             }
             let freeze_reason = governance.freeze_reason.as_deref().unwrap_or("").trim();
+            let freeze_reason_chars = freeze_reason.chars().count();
             if freeze_reason.is_empty() {
-                failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                reasons.push(
-                    "schemaLifecycle.governance.freezeReason required when mode=freeze".to_string(),
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                    "schemaLifecycle.governance.freezeReason",
+                    "schemaLifecycle.governance.freezeReason required when mode=freeze",
+                );
+            } else if freeze_reason_chars < FREEZE_REASON_MIN_CHARS
+                || !freeze_reason.contains(char::is_whitespace)
+            {
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FREEZE_REASON_TOO_SHORT_FAILURE,
+                    "schemaLifecycle.governance.freezeReason",
+                    format!(
+                        "schemaLifecycle.governance.freezeReason must be a phrase of at least {FREEZE_REASON_MIN_CHARS} characters"
+                    ),
+                );
+            } else if freeze_reason_chars > FREEZE_REASON_MAX_CHARS {
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FREEZE_REASON_TOO_LONG_FAILURE,
+                    "schemaLifecycle.governance.freezeReason",
+                    format!(
+                        "schemaLifecycle.governance.freezeReason must be at most {FREEZE_REASON_MAX_CHARS} characters"
+                    ),
                 );
             }
             if !alias_support_epochs.is_empty() {
-                failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-                reasons.push(
-                    "schemaLifecycle.governance.mode=freeze requires no compatibility aliases"
-                        .to_string(),
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                    "schemaLifecycle.governance.mode",
+                    "schemaLifecycle.governance.mode=freeze requires no compatibility aliases",
                 );
             }
         }
         _ => {
-            failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-            reasons.push(format!(
-                "schemaLifecycle.governance.mode unsupported `{governance_mode}` (expected `rollover` or `freeze`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                "schemaLifecycle.governance.mode",
+                format!(
+                    "schemaLifecycle.governance.mode unsupported `{governance_mode}` (expected `rollover` or `freeze`)"
+                ),
+            );
         }
     }
 
@@ -1614,11 +3368,16 @@ fn evaluate_control_plane_schema_lifecycle(
         &mut reasons,
     ) {
         resolved["contractKind"] = json!(kind.clone());
-        if kind != "premath.control_plane.contract.v1" {
-            failures.push(GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string());
-            reasons.push(format!(
-                "resolved contractKind must be `premath.control_plane.contract.v1` (actual `{kind}`)"
-            ));
+        if kind != expected_contract_kind {
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE,
+                "contractKind",
+                format!(
+                    "resolved contractKind must be `{expected_contract_kind}` (actual `{kind}`)"
+                ),
+            );
         }
     }
     if let Some(kind) = resolve_or_record_schema_kind(
@@ -1653,6 +3412,28 @@ fn evaluate_control_plane_schema_lifecycle(
     ) {
         resolved["requiredDecisionKind"] = json!(kind);
     }
+    let declared_delta_kind = control_plane_contract
+        .required_witness
+        .delta_kind
+        .clone()
+        .or_else(|| {
+            schema_lifecycle
+                .kind_families
+                .get("requiredDeltaKind")
+                .map(|family| family.canonical_kind.clone())
+        });
+    if let Some(declared_delta_kind) = declared_delta_kind
+        && let Some(kind) = resolve_or_record_schema_kind(
+            schema_lifecycle,
+            "requiredDeltaKind",
+            "requiredWitness.deltaKind",
+            &declared_delta_kind,
+            &mut failures,
+            &mut reasons,
+        )
+    {
+        resolved["requiredDeltaKind"] = json!(kind);
+    }
     if let Some(kind) = resolve_or_record_schema_kind(
         schema_lifecycle,
         "instructionWitnessKind",
@@ -1685,7 +3466,8 @@ fn evaluate_control_plane_schema_lifecycle(
             "unknownKindFamilies": unknown_families,
             "resolvedKinds": resolved,
             "governance": governance_details,
-            "reasons": dedupe_sorted(reasons),
+            "reasons": flat_reasons(&reasons),
+            "structuredReasons": structured_reasons(&reasons),
         }),
     }
 }
@@ -1701,7 +3483,7 @@ fn check_gate_chain_parity(
         contract.surfaces.mise_baseline_task.as_str(),
         &mise_path,
     )?;
-    let baseline_set: BTreeSet<String> = baseline_tasks.iter().cloned().collect();
+    let baseline_set = normalize_task_set(&baseline_tasks);
 
     let ci_closure_text = read_text(&resolve_path(
         repo_root,
@@ -1712,7 +3494,7 @@ fn check_gate_chain_parity(
         contract.surfaces.ci_closure_baseline_start.as_str(),
         contract.surfaces.ci_closure_baseline_end.as_str(),
     )?;
-    let ci_baseline_set = parse_backticked_tasks(ci_baseline_section)?;
+    let ci_baseline_set = normalize_task_set(parse_backticked_tasks(ci_baseline_section));
 
     let control_plane_contract_path = resolve_path(
         repo_root,
@@ -1726,25 +3508,23 @@ fn check_gate_chain_parity(
             }
         })?;
     if control_plane_contract.schema != 1 {
-        return Err(CoherenceError::Contract(format!(
-            "control-plane contract schema must be 1: {}",
-            display_path(&control_plane_contract_path)
-        )));
+        return Err(CoherenceError::SchemaMismatch {
+            expected: 1,
+            got: control_plane_contract.schema,
+            path: display_path(&control_plane_contract_path),
+            field: "schema".to_string(),
+        });
     }
-    let projection_checks = dedupe_sorted(
-        control_plane_contract
-            .required_gate_projection
-            .check_order
-            .clone(),
-    );
-    let projection_set: BTreeSet<String> = projection_checks.iter().cloned().collect();
+    let projection_set =
+        normalize_task_set(&control_plane_contract.required_gate_projection.check_order);
+    let projection_checks = sorted_vec_from_set(&projection_set);
 
     let ci_projection_section = extract_section_between(
         &ci_closure_text,
         contract.surfaces.ci_closure_projection_start.as_str(),
         contract.surfaces.ci_closure_projection_end.as_str(),
     )?;
-    let ci_projection_set = parse_backticked_tasks(ci_projection_section)?;
+    let ci_projection_set = normalize_task_set(parse_backticked_tasks(ci_projection_section));
 
     let mut failures = Vec::new();
     if control_plane_contract
@@ -1755,6 +3535,10 @@ fn check_gate_chain_parity(
     {
         failures.push("coherence.gate_chain_parity.projection_policy_invalid".to_string());
     }
+    // An empty CI projection section would otherwise match an empty checkOrder.
+    if projection_set.is_empty() {
+        failures.push("coherence.gate_chain_parity.projection_check_order_empty".to_string());
+    }
     if control_plane_contract
         .required_witness
         .witness_kind
@@ -1793,40 +3577,97 @@ fn check_gate_chain_parity(
         failures.push("coherence.gate_chain_parity.projection_set_mismatch".to_string());
     }
 
-    let schema_lifecycle_check = evaluate_control_plane_schema_lifecycle(&control_plane_contract);
+    let mut schema_lifecycle_check = evaluate_control_plane_schema_lifecycle(
+        &control_plane_contract,
+        &contract.control_plane_contract_kind,
+    );
     failures.extend(schema_lifecycle_check.failure_classes.clone());
 
-    let stage1_parity_check = evaluate_control_plane_stage1_parity(&control_plane_contract);
+    let mut stage1_parity_check = evaluate_control_plane_stage1_parity(&control_plane_contract);
     failures.extend(stage1_parity_check.failure_classes.clone());
 
-    let stage1_rollback_check = evaluate_control_plane_stage1_rollback(&control_plane_contract);
+    let mut stage1_rollback_check = evaluate_control_plane_stage1_rollback(&control_plane_contract);
     failures.extend(stage1_rollback_check.failure_classes.clone());
 
-    let stage2_authority_check = evaluate_control_plane_stage2_authority(
+    let mut stage2_authority_check = evaluate_control_plane_stage2_authority(
         &control_plane_contract,
         &contract.required_bidir_obligations,
     );
     failures.extend(stage2_authority_check.failure_classes.clone());
-    let evidence_factorization_check =
+    let mut evidence_factorization_check =
         evaluate_control_plane_evidence_factorization(&control_plane_contract);
     failures.extend(evidence_factorization_check.failure_classes.clone());
 
-    let lane_registry_check = evaluate_gate_chain_lane_registry(&control_plane_contract);
+    let mut lane_registry_check = evaluate_gate_chain_lane_registry(&control_plane_contract);
     failures.extend(lane_registry_check.failure_classes.clone());
-    let worker_lane_check = evaluate_gate_chain_worker_lane_authority(&control_plane_contract);
+    let mut worker_lane_check = evaluate_gate_chain_worker_lane_authority(&control_plane_contract);
     failures.extend(worker_lane_check.failure_classes.clone());
+    let completeness_check = evaluate_control_plane_completeness(&control_plane_contract);
+    failures.extend(completeness_check.failure_classes.clone());
+
+    if contract.verbose_reasons {
+        let lane_registry_present = control_plane_contract.evidence_lanes.is_some()
+            || control_plane_contract.lane_artifact_kinds.is_some()
+            || control_plane_contract.lane_ownership.is_some()
+            || control_plane_contract.lane_failure_classes.is_some();
+        for (check, present, satisfied) in [
+            (
+                &mut schema_lifecycle_check,
+                control_plane_contract.schema_lifecycle.is_some(),
+                SCHEMA_LIFECYCLE_SATISFIED_REASONS,
+            ),
+            (
+                &mut stage1_parity_check,
+                control_plane_contract.evidence_stage1_parity.is_some(),
+                STAGE1_PARITY_SATISFIED_REASONS,
+            ),
+            (
+                &mut stage1_rollback_check,
+                control_plane_contract.evidence_stage1_rollback.is_some(),
+                STAGE1_ROLLBACK_SATISFIED_REASONS,
+            ),
+            (
+                &mut stage2_authority_check,
+                control_plane_contract.evidence_stage2_authority.is_some(),
+                STAGE2_AUTHORITY_SATISFIED_REASONS,
+            ),
+            (
+                &mut evidence_factorization_check,
+                control_plane_contract.evidence_factorization.is_some(),
+                EVIDENCE_FACTORIZATION_SATISFIED_REASONS,
+            ),
+            (
+                &mut lane_registry_check,
+                lane_registry_present,
+                LANE_REGISTRY_SATISFIED_REASONS,
+            ),
+            (
+                &mut worker_lane_check,
+                control_plane_contract.worker_lane_authority.is_some(),
+                WORKER_LANE_AUTHORITY_SATISFIED_REASONS,
+            ),
+        ] {
+            record_satisfied_reasons(check, present, satisfied);
+        }
+    }
 
     let lane_vectors_check = if contract.surfaces.site_fixture_root_path.trim().is_empty() {
         None
     } else {
         let fixture_root =
             resolve_path(repo_root, contract.surfaces.site_fixture_root_path.as_str());
-        if fixture_root.join("manifest.json").exists() {
+        if probe_path_exists(&fixture_root.join("manifest.json")) {
             let check = check_site_obligation(
                 repo_root,
                 contract,
                 "gate_chain_parity",
-                evaluate_site_case_gate_chain_parity,
+                |artifacts, case_path, _scheme| {
+                    evaluate_site_case_gate_chain_parity(
+                        artifacts,
+                        case_path,
+                        &contract.control_plane_contract_kind,
+                    )
+                },
             )?;
             failures.extend(check.failure_classes.clone());
             Some(check)
@@ -1840,9 +3681,13 @@ fn check_gate_chain_parity(
         details: json!({
             "baselineFromMise": baseline_tasks,
             "baselineFromCiClosure": sorted_vec_from_set(&ci_baseline_set),
+            "baselineMissing": baseline_set.difference(&ci_baseline_set).collect::<Vec<_>>(),
+            "baselineExtra": ci_baseline_set.difference(&baseline_set).collect::<Vec<_>>(),
             "projectionPolicy": control_plane_contract.required_gate_projection.projection_policy,
             "projectionFromControlPlane": projection_checks,
             "projectionFromCiClosure": sorted_vec_from_set(&ci_projection_set),
+            "projectionMissing": projection_set.difference(&ci_projection_set).collect::<Vec<_>>(),
+            "projectionExtra": ci_projection_set.difference(&projection_set).collect::<Vec<_>>(),
             "requiredWitnessKind": control_plane_contract.required_witness.witness_kind,
             "requiredDecisionKind": control_plane_contract.required_witness.decision_kind,
             "instructionWitnessKind": control_plane_contract.instruction_witness.witness_kind,
@@ -1855,11 +3700,132 @@ fn check_gate_chain_parity(
             "evidenceFactorization": evidence_factorization_check.details,
             "laneRegistry": lane_registry_check.details,
             "workerLaneAuthority": worker_lane_check.details,
+            "completeness": completeness_check.details,
             "laneOwnershipVectors": lane_vectors_check.map(|check| check.details),
         }),
     })
 }
 
+const SCHEMA_LIFECYCLE_SATISFIED_REASONS: &[&str] = &[
+    "schemaLifecycle kind families are canonical with bounded alias windows",
+    "schemaLifecycle governance mode carries decision/accountability bindings",
+];
+const STAGE1_PARITY_SATISFIED_REASONS: &[&str] = &[
+    "evidenceStage1Parity authority-to-typed-core route is declared",
+    "evidenceStage1Parity comparison tuple bound to normalizerId/policyDigest",
+    "evidenceStage1Parity failureClasses map to canonical Stage 1 parity classes",
+];
+const STAGE1_ROLLBACK_SATISFIED_REASONS: &[&str] = &[
+    "evidenceStage1Rollback triggers cover canonical rollback classes",
+    "evidenceStage1Rollback binding bound to normalizerId/policyDigest",
+    "evidenceStage1Rollback failureClasses map to canonical Stage 1 rollback classes",
+];
+const STAGE2_AUTHORITY_SATISFIED_REASONS: &[&str] = &[
+    "evidenceStage2Authority profileKind is set and activeStage is `stage2`",
+    "typedAuthority refs bound to normalizerId/policyDigest",
+    "compatibilityAlias role, digest ref, and support window agree with schemaLifecycle",
+    "evidenceStage2Authority failureClasses map to canonical Stage 2 authority classes",
+    "bidirEvidenceRoute requiredObligations match canonical Stage 2 kernel obligations",
+];
+const EVIDENCE_FACTORIZATION_SATISFIED_REASONS: &[&str] = &[
+    "evidenceFactorization routes are unambiguous and bind pullbackBaseChange=span_square_commutation",
+    "evidenceFactorization binding bound to normalizerId/policyDigest",
+    "evidenceFactorization failureClasses map to canonical unification classes",
+];
+const LANE_REGISTRY_SATISFIED_REASONS: &[&str] = &[
+    "evidenceLanes ids are known and unique",
+    "laneArtifactKinds are bound to declared lanes",
+    "laneOwnership respects checker-core CwF boundaries",
+    "required cross-lane witness routes are present",
+];
+const WORKER_LANE_AUTHORITY_SATISFIED_REASONS: &[&str] = &[
+    "workerLaneAuthority mutation modes are instruction-linked first",
+    "workerLaneAuthority compatibility override windows are bounded",
+    "workerLaneAuthority mutation capability routes are bound",
+];
+
+/// Under `completeness: full`, list every optional control-plane section that
+/// is absent; `partial` contracts keep skipping absent sections silently.
+fn evaluate_control_plane_completeness(
+    control_plane_contract: &ControlPlaneProjectionContract,
+) -> ObligationCheck {
+    let sections = [
+        (
+            "evidenceStage1Parity",
+            control_plane_contract.evidence_stage1_parity.is_some(),
+        ),
+        (
+            "evidenceStage1Rollback",
+            control_plane_contract.evidence_stage1_rollback.is_some(),
+        ),
+        (
+            "evidenceStage2Authority",
+            control_plane_contract.evidence_stage2_authority.is_some(),
+        ),
+        (
+            "evidenceFactorization",
+            control_plane_contract.evidence_factorization.is_some(),
+        ),
+        (
+            "evidenceLanes",
+            control_plane_contract.evidence_lanes.is_some(),
+        ),
+        (
+            "laneArtifactKinds",
+            control_plane_contract.lane_artifact_kinds.is_some(),
+        ),
+        (
+            "laneOwnership",
+            control_plane_contract.lane_ownership.is_some(),
+        ),
+        (
+            "laneFailureClasses",
+            control_plane_contract.lane_failure_classes.is_some(),
+        ),
+        (
+            "workerLaneAuthority",
+            control_plane_contract.worker_lane_authority.is_some(),
+        ),
+        (
+            "spanKindRegistry",
+            control_plane_contract.span_kind_registry.is_some(),
+        ),
+    ];
+    let absent_sections: Vec<&str> =
+        if control_plane_contract.completeness == ControlPlaneCompleteness::Full {
+            sections
+                .iter()
+                .filter(|(_, present)| !present)
+                .map(|(section, _)| *section)
+                .collect()
+        } else {
+            Vec::new()
+        };
+    let failure_classes = if absent_sections.is_empty() {
+        Vec::new()
+    } else {
+        vec![GATE_CHAIN_SECTION_ABSENT_FAILURE.to_string()]
+    };
+    ObligationCheck {
+        failure_classes,
+        details: json!({
+            "mode": control_plane_contract.completeness,
+            "absentSections": absent_sections,
+        }),
+    }
+}
+
+/// Record what a passing control-plane sub-check confirmed. Absent or failing
+/// sections get an empty list so the key is stable across outcomes.
+fn record_satisfied_reasons(check: &mut ObligationCheck, present: bool, satisfied: &[&str]) {
+    let satisfied = if present && check.failure_classes.is_empty() {
+        satisfied
+    } else {
+        &[]
+    };
+    check.details["satisfiedReasons"] = json!(satisfied);
+}
+
 fn evaluate_control_plane_stage1_parity(
     control_plane_contract: &ControlPlaneProjectionContract,
 ) -> ObligationCheck {
@@ -1877,6 +3843,7 @@ fn evaluate_control_plane_stage1_parity(
         "failureClasses": null,
         "requiredFailureClasses": required_failure_classes,
         "reasons": [],
+        "structuredReasons": [],
     });
 
     let Some(stage1) = &control_plane_contract.evidence_stage1_parity else {
@@ -1895,14 +3862,23 @@ fn evaluate_control_plane_stage1_parity(
     let mut reasons = Vec::new();
 
     if stage1.profile_kind.trim().is_empty() {
-        failures.push(GATE_CHAIN_STAGE1_PARITY_INVALID_FAILURE.to_string());
-        reasons.push("evidenceStage1Parity.profileKind must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_PARITY_INVALID_FAILURE,
+            "evidenceStage1Parity.profileKind",
+            "evidenceStage1Parity.profileKind must be non-empty",
+        );
     }
 
     if stage1.authority_to_typed_core_route.trim().is_empty() {
-        failures.push(GATE_CHAIN_STAGE1_PARITY_MISSING_FAILURE.to_string());
-        reasons
-            .push("evidenceStage1Parity.authorityToTypedCoreRoute must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_PARITY_MISSING_FAILURE,
+            "evidenceStage1Parity.authorityToTypedCoreRoute",
+            "evidenceStage1Parity.authorityToTypedCoreRoute must be non-empty",
+        );
     }
 
     if stage1
@@ -1916,33 +3892,47 @@ fn evaluate_control_plane_stage1_parity(
             .trim()
             .is_empty()
     {
-        failures.push(GATE_CHAIN_STAGE1_PARITY_MISSING_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage1Parity.comparisonTuple authority/typed-core refs must be non-empty"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_PARITY_MISSING_FAILURE,
+            "evidenceStage1Parity.comparisonTuple",
+            "evidenceStage1Parity.comparisonTuple authority/typed-core refs must be non-empty",
         );
     }
 
     let normalizer_ref = stage1.comparison_tuple.normalizer_id_ref.trim();
     let policy_ref = stage1.comparison_tuple.policy_digest_ref.trim();
     if normalizer_ref.is_empty() || policy_ref.is_empty() {
-        failures.push(GATE_CHAIN_STAGE1_PARITY_UNBOUND_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage1Parity.comparisonTuple normalizer/policy refs must be non-empty"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_PARITY_UNBOUND_FAILURE,
+            "evidenceStage1Parity.comparisonTuple",
+            "evidenceStage1Parity.comparisonTuple normalizer/policy refs must be non-empty",
         );
     } else {
         if normalizer_ref != "normalizerId" {
-            failures.push(GATE_CHAIN_STAGE1_PARITY_UNBOUND_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceStage1Parity.comparisonTuple.normalizerIdRef must be `normalizerId` (got `{normalizer_ref}`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE1_PARITY_UNBOUND_FAILURE,
+                "evidenceStage1Parity.comparisonTuple.normalizerIdRef",
+                format!(
+                    "evidenceStage1Parity.comparisonTuple.normalizerIdRef must be `normalizerId` (got `{normalizer_ref}`)"
+                ),
+            );
         }
         if policy_ref != "policyDigest" {
-            failures.push(GATE_CHAIN_STAGE1_PARITY_UNBOUND_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceStage1Parity.comparisonTuple.policyDigestRef must be `policyDigest` (got `{policy_ref}`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE1_PARITY_UNBOUND_FAILURE,
+                "evidenceStage1Parity.comparisonTuple.policyDigestRef",
+                format!(
+                    "evidenceStage1Parity.comparisonTuple.policyDigestRef must be `policyDigest` (got `{policy_ref}`)"
+                ),
+            );
         }
     }
 
@@ -1961,13 +3951,19 @@ fn evaluate_control_plane_stage1_parity(
         || stage1.failure_classes.mismatch.trim() != STAGE1_PARITY_CLASS_MISMATCH
         || stage1.failure_classes.unbound.trim() != STAGE1_PARITY_CLASS_UNBOUND
     {
-        failures.push(GATE_CHAIN_STAGE1_PARITY_MISMATCH_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceStage1Parity.failureClasses must map to canonical classes ({STAGE1_PARITY_CLASS_MISSING}, {STAGE1_PARITY_CLASS_MISMATCH}, {STAGE1_PARITY_CLASS_UNBOUND})"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_PARITY_MISMATCH_FAILURE,
+            "evidenceStage1Parity.failureClasses",
+            format!(
+                "evidenceStage1Parity.failureClasses must map to canonical classes ({STAGE1_PARITY_CLASS_MISSING}, {STAGE1_PARITY_CLASS_MISMATCH}, {STAGE1_PARITY_CLASS_UNBOUND})"
+            ),
+        );
     }
 
-    details["reasons"] = json!(dedupe_sorted(reasons));
+    details["reasons"] = json!(flat_reasons(&reasons));
+    details["structuredReasons"] = json!(structured_reasons(&reasons));
 
     ObligationCheck {
         failure_classes: dedupe_sorted(failures),
@@ -1996,11 +3992,13 @@ fn evaluate_control_plane_stage1_rollback(
         "fromStage": null,
         "toStage": null,
         "triggerFailureClasses": null,
+        "parityDeclaredFailureClasses": null,
         "identityRefs": null,
         "failureClasses": null,
         "requiredTriggerFailureClasses": required_trigger_failure_classes,
         "requiredFailureClasses": required_failure_classes,
         "reasons": [],
+        "structuredReasons": [],
     });
 
     let Some(stage1_rollback) = &control_plane_contract.evidence_stage1_rollback else {
@@ -2022,26 +4020,51 @@ fn evaluate_control_plane_stage1_rollback(
     let mut reasons = Vec::new();
 
     if stage1_rollback.profile_kind.trim().is_empty() {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_INVALID_FAILURE.to_string());
-        reasons.push("evidenceStage1Rollback.profileKind must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_INVALID_FAILURE,
+            "evidenceStage1Rollback.profileKind",
+            "evidenceStage1Rollback.profileKind must be non-empty",
+        );
     }
     if stage1_rollback.witness_kind.trim().is_empty() {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_INVALID_FAILURE.to_string());
-        reasons.push("evidenceStage1Rollback.witnessKind must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_INVALID_FAILURE,
+            "evidenceStage1Rollback.witnessKind",
+            "evidenceStage1Rollback.witnessKind must be non-empty",
+        );
     }
     if stage1_rollback.from_stage.trim() != "stage1" {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_INVALID_FAILURE.to_string());
-        reasons.push("evidenceStage1Rollback.fromStage must be `stage1`".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_INVALID_FAILURE,
+            "evidenceStage1Rollback.fromStage",
+            "evidenceStage1Rollback.fromStage must be `stage1`",
+        );
     }
     if stage1_rollback.to_stage.trim() != "stage0" {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_INVALID_FAILURE.to_string());
-        reasons.push("evidenceStage1Rollback.toStage must be `stage0`".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_INVALID_FAILURE,
+            "evidenceStage1Rollback.toStage",
+            "evidenceStage1Rollback.toStage must be `stage0`",
+        );
     }
 
     let trigger_classes = dedupe_sorted(stage1_rollback.trigger_failure_classes.clone());
     if trigger_classes.is_empty() {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE.to_string());
-        reasons.push("evidenceStage1Rollback.triggerFailureClasses must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE,
+            "evidenceStage1Rollback.triggerFailureClasses",
+            "evidenceStage1Rollback.triggerFailureClasses must be non-empty",
+        );
     } else {
         for required in [
             STAGE1_PARITY_CLASS_MISSING,
@@ -2049,52 +4072,102 @@ fn evaluate_control_plane_stage1_rollback(
             STAGE1_PARITY_CLASS_UNBOUND,
         ] {
             if !trigger_classes.iter().any(|class_id| class_id == required) {
-                failures.push(GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE.to_string());
-                reasons.push(format!(
-                    "evidenceStage1Rollback.triggerFailureClasses must include `{required}`"
-                ));
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE,
+                    "evidenceStage1Rollback.triggerFailureClasses",
+                    format!(
+                        "evidenceStage1Rollback.triggerFailureClasses must include `{required}`"
+                    ),
+                );
             }
         }
     }
 
+    // Cross-check against the parity section as declared, not the canonical
+    // constants, so a rename on one side is caught even if the other is stale.
+    if let Some(stage1_parity) = &control_plane_contract.evidence_stage1_parity {
+        let parity_declared: BTreeSet<String> = [
+            &stage1_parity.failure_classes.missing,
+            &stage1_parity.failure_classes.mismatch,
+            &stage1_parity.failure_classes.unbound,
+        ]
+        .into_iter()
+        .map(|class_id| class_id.trim().to_string())
+        .filter(|class_id| !class_id.is_empty())
+        .collect();
+        let trigger_set: BTreeSet<String> = trigger_classes
+            .iter()
+            .map(|class_id| class_id.trim().to_string())
+            .collect();
+        details["parityDeclaredFailureClasses"] = json!(&parity_declared);
+        if trigger_set != parity_declared {
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE1_ROLLBACK_TRIGGER_DRIFT_FAILURE,
+                "evidenceStage1Rollback.triggerFailureClasses",
+                "evidenceStage1Rollback.triggerFailureClasses must match evidenceStage1Parity.failureClasses",
+            );
+        }
+    }
+
     let authority_ref = stage1_rollback.identity_refs.authority_digest_ref.trim();
     let rollback_ref = stage1_rollback
         .identity_refs
         .rollback_authority_digest_ref
         .trim();
     if authority_ref.is_empty() || rollback_ref.is_empty() {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage1Rollback.identityRefs authority/rollback refs must be non-empty"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE,
+            "evidenceStage1Rollback.identityRefs",
+            "evidenceStage1Rollback.identityRefs authority/rollback refs must be non-empty",
         );
     } else if authority_ref == rollback_ref {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage1Rollback.identityRefs authority/rollback refs must differ".to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE,
+            "evidenceStage1Rollback.identityRefs",
+            "evidenceStage1Rollback.identityRefs authority/rollback refs must differ",
         );
     }
 
     let normalizer_ref = stage1_rollback.identity_refs.normalizer_id_ref.trim();
     let policy_ref = stage1_rollback.identity_refs.policy_digest_ref.trim();
     if normalizer_ref.is_empty() || policy_ref.is_empty() {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage1Rollback.identityRefs normalizer/policy refs must be non-empty"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE,
+            "evidenceStage1Rollback.identityRefs",
+            "evidenceStage1Rollback.identityRefs normalizer/policy refs must be non-empty",
         );
     } else {
         if normalizer_ref != "normalizerId" {
-            failures.push(GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceStage1Rollback.identityRefs.normalizerIdRef must be `normalizerId` (got `{normalizer_ref}`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE,
+                "evidenceStage1Rollback.identityRefs.normalizerIdRef",
+                format!(
+                    "evidenceStage1Rollback.identityRefs.normalizerIdRef must be `normalizerId` (got `{normalizer_ref}`)"
+                ),
+            );
         }
         if policy_ref != "policyDigest" {
-            failures.push(GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceStage1Rollback.identityRefs.policyDigestRef must be `policyDigest` (got `{policy_ref}`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE,
+                "evidenceStage1Rollback.identityRefs.policyDigestRef",
+                format!(
+                    "evidenceStage1Rollback.identityRefs.policyDigestRef must be `policyDigest` (got `{policy_ref}`)"
+                ),
+            );
         }
     }
 
@@ -2122,13 +4195,19 @@ fn evaluate_control_plane_stage1_rollback(
             != STAGE1_ROLLBACK_CLASS_IDENTITY_DRIFT
         || stage1_rollback.failure_classes.unbound.trim() != STAGE1_ROLLBACK_CLASS_UNBOUND
     {
-        failures.push(GATE_CHAIN_STAGE1_ROLLBACK_MISMATCH_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceStage1Rollback.failureClasses must map to canonical classes ({STAGE1_ROLLBACK_CLASS_PRECONDITION}, {STAGE1_ROLLBACK_CLASS_IDENTITY_DRIFT}, {STAGE1_ROLLBACK_CLASS_UNBOUND})"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE1_ROLLBACK_MISMATCH_FAILURE,
+            "evidenceStage1Rollback.failureClasses",
+            format!(
+                "evidenceStage1Rollback.failureClasses must map to canonical classes ({STAGE1_ROLLBACK_CLASS_PRECONDITION}, {STAGE1_ROLLBACK_CLASS_IDENTITY_DRIFT}, {STAGE1_ROLLBACK_CLASS_UNBOUND})"
+            ),
+        );
     }
 
-    details["reasons"] = json!(dedupe_sorted(reasons));
+    details["reasons"] = json!(flat_reasons(&reasons));
+    details["structuredReasons"] = json!(structured_reasons(&reasons));
 
     ObligationCheck {
         failure_classes: dedupe_sorted(failures),
@@ -2211,6 +4290,7 @@ fn evaluate_control_plane_stage2_authority(
         "canonicalKernelObligations": canonical_kernel_obligations,
         "kernelRegistryObligations": sorted_vec_from_set(&kernel_registry_obligations),
         "reasons": [],
+        "structuredReasons": [],
     });
 
     let Some(stage2) = &control_plane_contract.evidence_stage2_authority else {
@@ -2234,60 +4314,92 @@ fn evaluate_control_plane_stage2_authority(
     let mut reasons = Vec::new();
 
     if stage2.profile_kind.trim().is_empty() {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_INVALID_FAILURE.to_string());
-        reasons.push("evidenceStage2Authority.profileKind must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_INVALID_FAILURE,
+            "evidenceStage2Authority.profileKind",
+            "evidenceStage2Authority.profileKind must be non-empty",
+        );
     }
     if stage2.active_stage.trim() != "stage2" {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_INVALID_FAILURE.to_string());
-        reasons.push("evidenceStage2Authority.activeStage must be `stage2`".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_INVALID_FAILURE,
+            "evidenceStage2Authority.activeStage",
+            "evidenceStage2Authority.activeStage must be `stage2`",
+        );
     }
 
     if stage2.typed_authority.kind_ref.trim().is_empty()
         || stage2.typed_authority.digest_ref.trim().is_empty()
     {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage2Authority.typedAuthority kind/digest refs must be non-empty".to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE,
+            "evidenceStage2Authority.typedAuthority",
+            "evidenceStage2Authority.typedAuthority kind/digest refs must be non-empty",
         );
     }
 
     let typed_normalizer_ref = stage2.typed_authority.normalizer_id_ref.trim();
     let typed_policy_ref = stage2.typed_authority.policy_digest_ref.trim();
     if typed_normalizer_ref.is_empty() || typed_policy_ref.is_empty() {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage2Authority.typedAuthority normalizer/policy refs must be non-empty"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE,
+            "evidenceStage2Authority.typedAuthority",
+            "evidenceStage2Authority.typedAuthority normalizer/policy refs must be non-empty",
         );
     } else {
         if typed_normalizer_ref != "normalizerId" {
-            failures.push(GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceStage2Authority.typedAuthority.normalizerIdRef must be `normalizerId` (got `{typed_normalizer_ref}`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE,
+                "evidenceStage2Authority.typedAuthority.normalizerIdRef",
+                format!(
+                    "evidenceStage2Authority.typedAuthority.normalizerIdRef must be `normalizerId` (got `{typed_normalizer_ref}`)"
+                ),
+            );
         }
         if typed_policy_ref != "policyDigest" {
-            failures.push(GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceStage2Authority.typedAuthority.policyDigestRef must be `policyDigest` (got `{typed_policy_ref}`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE,
+                "evidenceStage2Authority.typedAuthority.policyDigestRef",
+                format!(
+                    "evidenceStage2Authority.typedAuthority.policyDigestRef must be `policyDigest` (got `{typed_policy_ref}`)"
+                ),
+            );
         }
     }
 
     if stage2.compatibility_alias.kind_ref.trim().is_empty()
         || stage2.compatibility_alias.digest_ref.trim().is_empty()
     {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_VIOLATION_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage2Authority.compatibilityAlias kind/digest refs must be non-empty"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_VIOLATION_FAILURE,
+            "evidenceStage2Authority.compatibilityAlias",
+            "evidenceStage2Authority.compatibilityAlias kind/digest refs must be non-empty",
         );
     }
     if stage2.compatibility_alias.role.trim() != STAGE2_AUTHORITY_ALIAS_ROLE {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_VIOLATION_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceStage2Authority.compatibilityAlias.role must be `{STAGE2_AUTHORITY_ALIAS_ROLE}`"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_VIOLATION_FAILURE,
+            "evidenceStage2Authority.compatibilityAlias.role",
+            format!(
+                "evidenceStage2Authority.compatibilityAlias.role must be `{STAGE2_AUTHORITY_ALIAS_ROLE}`"
+            ),
+        );
     }
     if stage2
         .compatibility_alias
@@ -2296,30 +4408,63 @@ fn evaluate_control_plane_stage2_authority(
         .is_empty()
         || !is_valid_epoch(stage2.compatibility_alias.support_until_epoch.trim())
     {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage2Authority.compatibilityAlias.supportUntilEpoch must be a valid YYYY-MM epoch"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE,
+            "evidenceStage2Authority.compatibilityAlias.supportUntilEpoch",
+            "evidenceStage2Authority.compatibilityAlias.supportUntilEpoch must be a valid YYYY-MM epoch",
         );
     }
     if stage2.typed_authority.digest_ref.trim() == stage2.compatibility_alias.digest_ref.trim() {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_VIOLATION_FAILURE.to_string());
-        reasons.push("evidenceStage2Authority typed/alias digest refs must differ".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_VIOLATION_FAILURE,
+            "evidenceStage2Authority.compatibilityAlias",
+            "evidenceStage2Authority typed/alias digest refs must differ",
+        );
+    }
+    // Both stages name the typed-core projection digest; empty refs are
+    // reported by each stage's own binding checks.
+    if let Some(stage1) = &control_plane_contract.evidence_stage1_parity {
+        let stage1_typed_ref = stage1.comparison_tuple.typed_core_digest_ref.trim();
+        let stage2_typed_ref = stage2.typed_authority.digest_ref.trim();
+        if !stage1_typed_ref.is_empty()
+            && !stage2_typed_ref.is_empty()
+            && stage1_typed_ref != stage2_typed_ref
+        {
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE_DIGEST_REF_DRIFT_FAILURE,
+                "evidenceStage2Authority.typedAuthority.digestRef",
+                format!(
+                    "evidenceStage2Authority.typedAuthority.digestRef `{stage2_typed_ref}` must match evidenceStage1Parity.comparisonTuple.typedCoreDigestRef `{stage1_typed_ref}`"
+                ),
+            );
+        }
     }
 
     let alias_support_epoch = stage2.compatibility_alias.support_until_epoch.trim();
     if let Some(rollover_epoch) = lifecycle_rollover_epoch.as_deref() {
         if alias_support_epoch != rollover_epoch {
-            failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE.to_string());
-            reasons.push(
-                "evidenceStage2Authority.compatibilityAlias.supportUntilEpoch must align with schemaLifecycle rolloverEpoch"
-                    .to_string(),
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE,
+                "evidenceStage2Authority.compatibilityAlias.supportUntilEpoch",
+                "evidenceStage2Authority.compatibilityAlias.supportUntilEpoch must align with schemaLifecycle rolloverEpoch",
             );
         }
     } else {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE.to_string());
-        reasons
-            .push("evidenceStage2Authority requires one schemaLifecycle rolloverEpoch".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE,
+            "evidenceStage2Authority",
+            "evidenceStage2Authority requires one schemaLifecycle rolloverEpoch",
+        );
     }
     if let Some(active_epoch_value) = active_epoch.as_deref() {
         match (
@@ -2327,23 +4472,35 @@ fn evaluate_control_plane_stage2_authority(
             epoch_to_month_index(alias_support_epoch),
         ) {
             (Some(active), Some(support)) if active > support => {
-                failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE.to_string());
-                reasons.push(format!(
-                    "evidenceStage2Authority compatibility alias expired (activeEpoch=`{active_epoch_value}`, supportUntilEpoch=`{alias_support_epoch}`)"
-                ));
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE,
+                    "evidenceStage2Authority.compatibilityAlias.supportUntilEpoch",
+                    format!(
+                        "evidenceStage2Authority compatibility alias expired (activeEpoch=`{active_epoch_value}`, supportUntilEpoch=`{alias_support_epoch}`)"
+                    ),
+                );
             }
             (Some(_), Some(_)) => {}
             _ => {
-                failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE.to_string());
-                reasons.push(
-                    "evidenceStage2Authority alias-window comparison could not be evaluated"
-                        .to_string(),
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE,
+                    "evidenceStage2Authority.compatibilityAlias.supportUntilEpoch",
+                    "evidenceStage2Authority alias-window comparison could not be evaluated",
                 );
             }
         }
     } else {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE.to_string());
-        reasons.push("evidenceStage2Authority requires schemaLifecycle.activeEpoch".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE,
+            "evidenceStage2Authority",
+            "evidenceStage2Authority requires schemaLifecycle.activeEpoch",
+        );
     }
 
     let declared_failure_classes = [
@@ -2371,24 +4528,39 @@ fn evaluate_control_plane_stage2_authority(
             != STAGE2_AUTHORITY_CLASS_ALIAS_WINDOW_VIOLATION
         || stage2.failure_classes.unbound.trim() != STAGE2_AUTHORITY_CLASS_UNBOUND
     {
-        failures.push(GATE_CHAIN_STAGE2_AUTHORITY_INVALID_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceStage2Authority.failureClasses must map to canonical classes ({STAGE2_AUTHORITY_CLASS_ALIAS_VIOLATION}, {STAGE2_AUTHORITY_CLASS_ALIAS_WINDOW_VIOLATION}, {STAGE2_AUTHORITY_CLASS_UNBOUND})"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_AUTHORITY_INVALID_FAILURE,
+            "evidenceStage2Authority.failureClasses",
+            format!(
+                "evidenceStage2Authority.failureClasses must map to canonical classes ({STAGE2_AUTHORITY_CLASS_ALIAS_VIOLATION}, {STAGE2_AUTHORITY_CLASS_ALIAS_WINDOW_VIOLATION}, {STAGE2_AUTHORITY_CLASS_UNBOUND})"
+            ),
+        );
     }
 
     if stage2.bidir_evidence_route.route_kind.trim() != STAGE2_BIDIR_ROUTE_KIND {
-        failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceStage2Authority.bidirEvidenceRoute.routeKind must be `{STAGE2_BIDIR_ROUTE_KIND}`"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+            "evidenceStage2Authority.bidirEvidenceRoute.routeKind",
+            format!(
+                "evidenceStage2Authority.bidirEvidenceRoute.routeKind must be `{STAGE2_BIDIR_ROUTE_KIND}`"
+            ),
+        );
     }
     if stage2.bidir_evidence_route.obligation_field_ref.trim() != STAGE2_BIDIR_OBLIGATION_FIELD_REF
     {
-        failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceStage2Authority.bidirEvidenceRoute.obligationFieldRef must be `{STAGE2_BIDIR_OBLIGATION_FIELD_REF}`"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+            "evidenceStage2Authority.bidirEvidenceRoute.obligationFieldRef",
+            format!(
+                "evidenceStage2Authority.bidirEvidenceRoute.obligationFieldRef must be `{STAGE2_BIDIR_OBLIGATION_FIELD_REF}`"
+            ),
+        );
     }
 
     let bidir_required = dedupe_sorted(
@@ -2402,41 +4574,57 @@ fn evaluate_control_plane_stage2_authority(
     );
     let bidir_required_set: BTreeSet<String> = bidir_required.iter().cloned().collect();
     if bidir_required.is_empty() {
-        failures.push(GATE_CHAIN_STAGE2_KERNEL_MISSING_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations must be non-empty"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_KERNEL_MISSING_FAILURE,
+            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations",
+            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations must be non-empty",
         );
     } else {
         for required in &required_bidir_obligations {
             if !bidir_required_set.contains(required) {
-                failures.push(GATE_CHAIN_STAGE2_KERNEL_MISSING_FAILURE.to_string());
-                reasons.push(format!(
-                    "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations missing required BIDIR obligation `{required}`"
-                ));
+                push_reason(
+                    &mut failures,
+                    &mut reasons,
+                    GATE_CHAIN_STAGE2_KERNEL_MISSING_FAILURE,
+                    "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations",
+                    format!(
+                        "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations missing required BIDIR obligation `{required}`"
+                    ),
+                );
             }
         }
     }
     if bidir_required_set != required_bidir_set {
-        failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations must match requiredBidirObligations"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations",
+            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations must match requiredBidirObligations",
         );
     }
     if bidir_required_set != canonical_kernel_set {
-        failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-        reasons.push(
-            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations must match canonical Stage 2 kernel obligations"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations",
+            "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations must match canonical Stage 2 kernel obligations",
         );
     }
     for obligation in &bidir_required {
         if !kernel_registry_obligations.contains(obligation) {
-            failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations contains unknown kernel obligation `{obligation}`"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+                "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations",
+                format!(
+                    "evidenceStage2Authority.bidirEvidenceRoute.requiredObligations contains unknown kernel obligation `{obligation}`"
+                ),
+            );
         }
     }
 
@@ -2445,10 +4633,15 @@ fn evaluate_control_plane_stage2_authority(
     if bidir_missing_class != STAGE2_KERNEL_CLASS_MISSING
         || bidir_drift_class != STAGE2_KERNEL_CLASS_DRIFT
     {
-        failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceStage2Authority.bidirEvidenceRoute.failureClasses must map to canonical classes ({STAGE2_KERNEL_CLASS_MISSING}, {STAGE2_KERNEL_CLASS_DRIFT})"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+            "evidenceStage2Authority.bidirEvidenceRoute.failureClasses",
+            format!(
+                "evidenceStage2Authority.bidirEvidenceRoute.failureClasses must map to canonical classes ({STAGE2_KERNEL_CLASS_MISSING}, {STAGE2_KERNEL_CLASS_DRIFT})"
+            ),
+        );
     }
 
     if let Some(sentinel) = &stage2.kernel_compliance_sentinel {
@@ -2467,10 +4660,12 @@ fn evaluate_control_plane_stage2_authority(
         if fallback_mode != STAGE2_BIDIR_FALLBACK_MODE
             || !fallback_profile_kinds.contains(stage2.profile_kind.trim())
         {
-            failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-            reasons.push(
-                "evidenceStage2Authority.kernelComplianceSentinel requires bidirEvidenceRoute.fallback.mode=`profile_gated_sentinel` with current profileKind included in fallback.profileKinds"
-                    .to_string(),
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+                "evidenceStage2Authority.kernelComplianceSentinel",
+                "evidenceStage2Authority.kernelComplianceSentinel requires bidirEvidenceRoute.fallback.mode=`profile_gated_sentinel` with current profileKind included in fallback.profileKinds",
             );
         }
         let sentinel_required = dedupe_sorted(
@@ -2483,10 +4678,12 @@ fn evaluate_control_plane_stage2_authority(
         );
         let sentinel_required_set: BTreeSet<String> = sentinel_required.iter().cloned().collect();
         if sentinel_required_set != bidir_required_set {
-            failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-            reasons.push(
-                "evidenceStage2Authority.kernelComplianceSentinel.requiredObligations must match evidenceStage2Authority.bidirEvidenceRoute.requiredObligations"
-                    .to_string(),
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+                "evidenceStage2Authority.kernelComplianceSentinel.requiredObligations",
+                "evidenceStage2Authority.kernelComplianceSentinel.requiredObligations must match evidenceStage2Authority.bidirEvidenceRoute.requiredObligations",
             );
         }
         let sentinel_missing_class = sentinel.failure_classes.missing.trim();
@@ -2494,15 +4691,18 @@ fn evaluate_control_plane_stage2_authority(
         if sentinel_missing_class != bidir_missing_class
             || sentinel_drift_class != bidir_drift_class
         {
-            failures.push(GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string());
-            reasons.push(
-                "evidenceStage2Authority.kernelComplianceSentinel.failureClasses must match evidenceStage2Authority.bidirEvidenceRoute.failureClasses"
-                    .to_string(),
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE,
+                "evidenceStage2Authority.kernelComplianceSentinel.failureClasses",
+                "evidenceStage2Authority.kernelComplianceSentinel.failureClasses must match evidenceStage2Authority.bidirEvidenceRoute.failureClasses",
             );
         }
     }
 
-    details["reasons"] = json!(dedupe_sorted(reasons));
+    details["reasons"] = json!(flat_reasons(&reasons));
+    details["structuredReasons"] = json!(structured_reasons(&reasons));
 
     ObligationCheck {
         failure_classes: dedupe_sorted(failures),
@@ -2531,6 +4731,7 @@ fn evaluate_control_plane_evidence_factorization(
         "requiredPullbackRoute": REQUIRED_PULLBACK_ROUTE,
         "requiredFailureClasses": required_failure_classes,
         "reasons": [],
+        "structuredReasons": [],
     });
 
     let Some(factorization) = &control_plane_contract.evidence_factorization else {
@@ -2551,14 +4752,24 @@ fn evaluate_control_plane_evidence_factorization(
     let mut reasons = Vec::new();
 
     if factorization.profile_kind.trim().is_empty() {
-        failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_INVALID_FAILURE.to_string());
-        reasons.push("evidenceFactorization.profileKind must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_EVIDENCE_FACTORIZATION_INVALID_FAILURE,
+            "evidenceFactorization.profileKind",
+            "evidenceFactorization.profileKind must be non-empty",
+        );
     }
     if factorization.route_kind.trim() != EVIDENCE_FACTORIZATION_ROUTE_KIND {
-        failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_INVALID_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceFactorization.routeKind must be `{EVIDENCE_FACTORIZATION_ROUTE_KIND}`"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_EVIDENCE_FACTORIZATION_INVALID_FAILURE,
+            "evidenceFactorization.routeKind",
+            format!(
+                "evidenceFactorization.routeKind must be `{EVIDENCE_FACTORIZATION_ROUTE_KIND}`"
+            ),
+        );
     }
 
     let mut route_count = 0usize;
@@ -2567,54 +4778,82 @@ fn evaluate_control_plane_evidence_factorization(
         route_count += 1;
         let trimmed = route.trim();
         if trimmed.is_empty() {
-            failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE.to_string());
-            reasons.push(
-                "evidenceFactorization.factorizationRoutes entries must be non-empty".to_string(),
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE,
+                "evidenceFactorization.factorizationRoutes",
+                "evidenceFactorization.factorizationRoutes entries must be non-empty",
             );
             continue;
         }
         unique_routes.insert(trimmed.to_string());
     }
     if route_count == 0 || unique_routes.is_empty() {
-        failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE.to_string());
-        reasons.push("evidenceFactorization.factorizationRoutes must be non-empty".to_string());
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE,
+            "evidenceFactorization.factorizationRoutes",
+            "evidenceFactorization.factorizationRoutes must be non-empty",
+        );
     } else if unique_routes.len() > 1 {
-        failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_AMBIGUOUS_FAILURE.to_string());
-        reasons.push(
-            "evidenceFactorization.factorizationRoutes must provide exactly one canonical route"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_EVIDENCE_FACTORIZATION_AMBIGUOUS_FAILURE,
+            "evidenceFactorization.factorizationRoutes",
+            "evidenceFactorization.factorizationRoutes must provide exactly one canonical route",
         );
     }
 
     let normalizer_ref = factorization.binding.normalizer_id_ref.trim();
     let policy_ref = factorization.binding.policy_digest_ref.trim();
     if normalizer_ref.is_empty() || policy_ref.is_empty() {
-        failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_UNBOUND_FAILURE.to_string());
-        reasons.push(
-            "evidenceFactorization.binding normalizer/policy refs must be non-empty".to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_EVIDENCE_FACTORIZATION_UNBOUND_FAILURE,
+            "evidenceFactorization.binding",
+            "evidenceFactorization.binding normalizer/policy refs must be non-empty",
         );
     } else {
         if normalizer_ref != "normalizerId" {
-            failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_UNBOUND_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceFactorization.binding.normalizerIdRef must be `normalizerId` (got `{normalizer_ref}`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_EVIDENCE_FACTORIZATION_UNBOUND_FAILURE,
+                "evidenceFactorization.binding.normalizerIdRef",
+                format!(
+                    "evidenceFactorization.binding.normalizerIdRef must be `normalizerId` (got `{normalizer_ref}`)"
+                ),
+            );
         }
         if policy_ref != "policyDigest" {
-            failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_UNBOUND_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceFactorization.binding.policyDigestRef must be `policyDigest` (got `{policy_ref}`)"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_EVIDENCE_FACTORIZATION_UNBOUND_FAILURE,
+                "evidenceFactorization.binding.policyDigestRef",
+                format!(
+                    "evidenceFactorization.binding.policyDigestRef must be `policyDigest` (got `{policy_ref}`)"
+                ),
+            );
         }
     }
 
     match &factorization.cross_lane_routes {
         Some(route) if route.pullback_base_change.trim() == REQUIRED_PULLBACK_ROUTE => {}
         _ => {
-            failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE.to_string());
-            reasons.push(format!(
-                "evidenceFactorization.crossLaneRoutes.pullbackBaseChange must be `{REQUIRED_PULLBACK_ROUTE}`"
-            ));
+            push_reason(
+                &mut failures,
+                &mut reasons,
+                GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE,
+                "evidenceFactorization.crossLaneRoutes.pullbackBaseChange",
+                format!(
+                    "evidenceFactorization.crossLaneRoutes.pullbackBaseChange must be `{REQUIRED_PULLBACK_ROUTE}`"
+                ),
+            );
         }
     }
 
@@ -2623,10 +4862,12 @@ fn evaluate_control_plane_evidence_factorization(
         && let Some(factorization_route) = &factorization.cross_lane_routes
         && route.pullback_base_change.trim() != factorization_route.pullback_base_change.trim()
     {
-        failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_AMBIGUOUS_FAILURE.to_string());
-        reasons.push(
-            "evidenceFactorization.crossLaneRoutes must match laneOwnership.requiredCrossLaneWitnessRoute"
-                .to_string(),
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_EVIDENCE_FACTORIZATION_AMBIGUOUS_FAILURE,
+            "evidenceFactorization.crossLaneRoutes",
+            "evidenceFactorization.crossLaneRoutes must match laneOwnership.requiredCrossLaneWitnessRoute",
         );
     }
 
@@ -2639,13 +4880,19 @@ fn evaluate_control_plane_evidence_factorization(
         || actual_failure_classes.1 != EVIDENCE_FACTORIZATION_CLASS_AMBIGUOUS
         || actual_failure_classes.2 != EVIDENCE_FACTORIZATION_CLASS_UNBOUND
     {
-        failures.push(GATE_CHAIN_EVIDENCE_FACTORIZATION_INVALID_FAILURE.to_string());
-        reasons.push(format!(
-            "evidenceFactorization.failureClasses must map to canonical classes ({EVIDENCE_FACTORIZATION_CLASS_MISSING}, {EVIDENCE_FACTORIZATION_CLASS_AMBIGUOUS}, {EVIDENCE_FACTORIZATION_CLASS_UNBOUND})"
-        ));
+        push_reason(
+            &mut failures,
+            &mut reasons,
+            GATE_CHAIN_EVIDENCE_FACTORIZATION_INVALID_FAILURE,
+            "evidenceFactorization.failureClasses",
+            format!(
+                "evidenceFactorization.failureClasses must map to canonical classes ({EVIDENCE_FACTORIZATION_CLASS_MISSING}, {EVIDENCE_FACTORIZATION_CLASS_AMBIGUOUS}, {EVIDENCE_FACTORIZATION_CLASS_UNBOUND})"
+            ),
+        );
     }
 
-    details["reasons"] = json!(dedupe_sorted(reasons));
+    details["reasons"] = json!(flat_reasons(&reasons));
+    details["structuredReasons"] = json!(structured_reasons(&reasons));
 
     ObligationCheck {
         failure_classes: dedupe_sorted(failures),
@@ -2661,18 +4908,16 @@ fn evaluate_gate_chain_lane_registry(
         || control_plane_contract.lane_ownership.is_some()
         || control_plane_contract.lane_failure_classes.is_some();
 
-    let expected_checker_core_only: Vec<String> = REQUIRED_OBLIGATION_IDS
-        .iter()
-        .filter(|id| id.starts_with("cwf_"))
-        .map(|id| (*id).to_string())
-        .collect();
+    let expected_checker_core = cwf_core_obligations();
     let mut lane_details = json!({
         "registryPresent": lane_registry_present,
         "evidenceLanes": null,
         "laneArtifactKinds": null,
+        "laneArtifactKindCrossLaneDuplicates": {},
+        "unknownLaneArtifactKinds": [],
         "laneOwnership": null,
         "laneFailureClasses": null,
-        "expectedCheckerCoreOnlyObligations": expected_checker_core_only,
+        "expectedCheckerCoreOnlyObligations": expected_checker_core,
         "requiredCrossLaneWitnessRoute": REQUIRED_PULLBACK_ROUTE,
         "requiredLaneFailureClasses": REQUIRED_LANE_FAILURE_CLASSES,
     });
@@ -2685,11 +4930,6 @@ fn evaluate_gate_chain_lane_registry(
     }
 
     let mut failures = Vec::new();
-    let expected_checker_core: BTreeSet<String> = REQUIRED_OBLIGATION_IDS
-        .iter()
-        .filter(|id| id.starts_with("cwf_"))
-        .map(|id| (*id).to_string())
-        .collect();
 
     let Some(evidence_lanes) = &control_plane_contract.evidence_lanes else {
         failures.push("coherence.gate_chain_parity.lane_unknown".to_string());
@@ -2745,6 +4985,38 @@ fn evaluate_gate_chain_lane_registry(
             failures.push("coherence.gate_chain_parity.lane_kind_unbound".to_string());
         }
     }
+    let mut kind_lanes: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (lane_id, kinds) in &lane_artifact_kinds {
+        let lane_kinds: BTreeSet<&str> = kinds
+            .iter()
+            .map(|kind| kind.trim())
+            .filter(|kind| !kind.is_empty())
+            .collect();
+        for kind in lane_kinds {
+            kind_lanes
+                .entry(kind.to_string())
+                .or_default()
+                .push(lane_id.clone());
+        }
+    }
+    kind_lanes.retain(|_, lanes| lanes.len() > 1);
+    if !kind_lanes.is_empty() {
+        failures.push(GATE_CHAIN_LANE_ARTIFACT_KIND_CROSS_LANE_DUPLICATE_FAILURE.to_string());
+    }
+    lane_details["laneArtifactKindCrossLaneDuplicates"] = json!(kind_lanes);
+
+    if let Some(allowed) = &control_plane_contract.allowed_lane_artifact_kinds {
+        let unknown_kinds: BTreeSet<&str> = lane_artifact_kinds
+            .values()
+            .flatten()
+            .map(|kind| kind.trim())
+            .filter(|kind| !kind.is_empty() && !allowed.contains(*kind))
+            .collect();
+        if !unknown_kinds.is_empty() {
+            failures.push(GATE_CHAIN_LANE_ARTIFACT_KIND_UNKNOWN_FAILURE.to_string());
+        }
+        lane_details["unknownLaneArtifactKinds"] = json!(unknown_kinds);
+    }
 
     let lane_ownership = control_plane_contract.lane_ownership.clone();
     lane_details["laneOwnership"] = json!(&lane_ownership);
@@ -2953,6 +5225,7 @@ fn evaluate_gate_chain_worker_lane_authority(
 fn evaluate_site_case_gate_chain_parity(
     artifacts_payload: &Value,
     case_path: &Path,
+    expected_contract_kind: &str,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -2977,17 +5250,20 @@ fn evaluate_site_case_gate_chain_parity(
         })?;
 
     if control_plane_contract.schema != 1 {
-        return Err(CoherenceError::Contract(format!(
-            "{}: artifacts.controlPlaneContract.schema must be 1",
-            display_path(case_path)
-        )));
+        return Err(CoherenceError::SchemaMismatch {
+            expected: 1,
+            got: control_plane_contract.schema,
+            path: display_path(case_path),
+            field: "artifacts.controlPlaneContract.schema".to_string(),
+        });
     }
-    if control_plane_contract.contract_kind != "premath.control_plane.contract.v1" {
-        return Err(CoherenceError::Contract(format!(
-            "{}: artifacts.controlPlaneContract.contractKind mismatch: {:?}",
-            display_path(case_path),
-            control_plane_contract.contract_kind
-        )));
+    if control_plane_contract.contract_kind != expected_contract_kind {
+        return Err(CoherenceError::ContractKindMismatch {
+            expected: expected_contract_kind.to_string(),
+            got: control_plane_contract.contract_kind,
+            path: display_path(case_path),
+            field: "artifacts.controlPlaneContract.contractKind".to_string(),
+        });
     }
 
     let stage1_parity_check = evaluate_control_plane_stage1_parity(&control_plane_contract);
@@ -3004,7 +5280,16 @@ fn evaluate_site_case_gate_chain_parity(
         evaluate_control_plane_evidence_factorization(&control_plane_contract);
     let lane_registry_check = evaluate_gate_chain_lane_registry(&control_plane_contract);
     let worker_lane_check = evaluate_gate_chain_worker_lane_authority(&control_plane_contract);
+    let case_context = artifacts.get("caseContext").cloned();
+    let case_context_bound = case_context
+        .as_ref()
+        .and_then(|context| context.get("obligationId"))
+        .and_then(Value::as_str)
+        == Some("gate_chain_parity");
     let mut failures = Vec::new();
+    if !case_context_bound {
+        failures.push(GATE_CHAIN_VECTOR_MISSING_CASE_CONTEXT_FAILURE.to_string());
+    }
     failures.extend(stage1_parity_check.failure_classes.clone());
     failures.extend(stage1_rollback_check.failure_classes.clone());
     failures.extend(stage2_authority_check.failure_classes.clone());
@@ -3021,6 +5306,7 @@ fn evaluate_site_case_gate_chain_parity(
         result: result.to_string(),
         failure_classes: failures,
         details: json!({
+            "caseContext": case_context,
             "stage1Parity": stage1_parity_check.details,
             "stage1Rollback": stage1_rollback_check.details,
             "stage2Authority": stage2_authority_check.details,
@@ -3035,12 +5321,7 @@ fn check_operation_reachability(
     repo_root: &Path,
     contract: &CoherenceContract,
 ) -> Result<ObligationCheck, CoherenceError> {
-    let doctrine_site_path = resolve_path(repo_root, contract.surfaces.doctrine_site_path.as_str());
-    let doctrine_site: DoctrineSite = serde_json::from_slice(&read_bytes(&doctrine_site_path)?)
-        .map_err(|source| CoherenceError::ParseJson {
-            path: display_path(&doctrine_site_path),
-            source,
-        })?;
+    let doctrine_site = load_doctrine_site(repo_root, contract)?;
 
     let mut operation_path_to_id: BTreeMap<String, String> = BTreeMap::new();
     for node in &doctrine_site.nodes {
@@ -3053,7 +5334,7 @@ fn check_operation_reachability(
     let mut operation_ids = Vec::new();
     for path in &contract.expected_operation_paths {
         let disk_path = resolve_path(repo_root, path.as_str());
-        if !disk_path.exists() {
+        if !probe_path_exists(&disk_path) {
             failures.push("coherence.operation_reachability.operation_path_missing".to_string());
         }
         match operation_path_to_id.get(path) {
@@ -3091,6 +5372,19 @@ fn check_operation_reachability(
     })
 }
 
+fn load_doctrine_site(
+    repo_root: &Path,
+    contract: &CoherenceContract,
+) -> Result<DoctrineSite, CoherenceError> {
+    let doctrine_site_path = resolve_path(repo_root, contract.surfaces.doctrine_site_path.as_str());
+    serde_json::from_slice(&read_bytes(&doctrine_site_path)?).map_err(|source| {
+        CoherenceError::ParseJson {
+            path: display_path(&doctrine_site_path),
+            source,
+        }
+    })
+}
+
 #[derive(Debug)]
 struct WorldRouteValidationOutcome {
     failure_classes: Vec<String>,
@@ -3143,6 +5437,7 @@ fn evaluate_world_route_validation(
     }))
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn check_overlay_traceability(
     repo_root: &Path,
     contract: &CoherenceContract,
@@ -3160,11 +5455,24 @@ fn check_overlay_traceability(
         contract.surfaces.profile_readme_path.as_str(),
     ))?;
 
+    let doctrine_reachability = if contract.enforce_overlay_doctrine_reachability {
+        let doctrine_site = load_doctrine_site(repo_root, contract)?;
+        let reachable = compute_doctrine_reachability(
+            &doctrine_site,
+            contract.surfaces.doctrine_root_node_id.as_str(),
+        );
+        Some((doctrine_site, reachable))
+    } else {
+        None
+    };
+
     let mut failures = Vec::new();
+    let mut unreachable_overlays = Vec::new();
     for overlay_ref in &contract.overlay_docs {
         let overlay_markdown = format!("{overlay_ref}.md");
-        let overlay_path = resolve_path(repo_root, format!("specs/premath/{overlay_markdown}"));
-        if !overlay_path.exists() {
+        let overlay_repo_path = format!("specs/premath/{overlay_markdown}");
+        let overlay_path = resolve_path(repo_root, overlay_repo_path.as_str());
+        if !probe_path_exists(&overlay_path) {
             failures.push("coherence.overlay_traceability.overlay_file_missing".to_string());
         }
         let overlay_token = format!("`{overlay_ref}`");
@@ -3181,6 +5489,18 @@ fn check_overlay_traceability(
                 "coherence.overlay_traceability.overlay_missing_in_profile_readme".to_string(),
             );
         }
+        if let Some((doctrine_site, reachable)) = &doctrine_reachability {
+            let reached = doctrine_site.nodes.iter().any(|node| {
+                (node.id == *overlay_ref || node.path == overlay_repo_path)
+                    && reachable.contains(&node.id)
+            });
+            if !reached {
+                failures.push(
+                    "coherence.overlay_traceability.overlay_unreachable_in_doctrine".to_string(),
+                );
+                unreachable_overlays.push(overlay_ref.clone());
+            }
+        }
     }
 
     Ok(ObligationCheck {
@@ -3188,6 +5508,10 @@ fn check_overlay_traceability(
         details: json!({
             "overlayDocs": contract.overlay_docs,
             "specIndexOverlaySectionFound": !section_56.is_empty(),
+            "doctrineReachability": doctrine_reachability.as_ref().map(|_| json!({
+                "rootNodeId": contract.surfaces.doctrine_root_node_id,
+                "unreachableOverlays": unreachable_overlays,
+            })),
         }),
     })
 }
@@ -3211,7 +5535,7 @@ fn check_transport_functoriality(
     if manifest.schema != 1 {
         failures.push("coherence.transport_functoriality.manifest_invalid_schema".to_string());
     }
-    if manifest.status != "executable" {
+    if FixtureStatus::parse(&manifest.status) != Some(FixtureStatus::Executable) {
         failures.push("coherence.transport_functoriality.manifest_invalid_status".to_string());
     }
     if manifest.vectors.is_empty() {
@@ -3222,11 +5546,22 @@ fn check_transport_functoriality(
     let mut vector_rows: Vec<Value> = Vec::new();
     let mut invariance_groups: InvarianceGroups = BTreeMap::new();
     let mut polarity = PolarityCoverage::default();
+    let mut non_executable = NonExecutableVectors::default();
+    let mut warnings = Vec::new();
 
     for vector_id in &manifest.vectors {
         if !seen_vectors.insert(vector_id.clone()) {
             failures.push("coherence.transport_functoriality.duplicate_vector_id".to_string());
         }
+        if non_executable.record_manifest_entry(
+            "coherence.transport_functoriality",
+            &manifest.vector_statuses,
+            vector_id,
+            &mut failures,
+            &mut vector_rows,
+        ) {
+            continue;
+        }
 
         let vector_root = fixture_root.join(vector_id);
         let case_path = vector_root.join("case.json");
@@ -3244,7 +5579,25 @@ fn check_transport_functoriality(
                 continue;
             }
         };
-        polarity.record_vector_id(vector_id);
+        let case_status = case_payload
+            .get("status")
+            .and_then(Value::as_str)
+            .unwrap_or("executable");
+        match FixtureStatus::parse(case_status) {
+            Some(status) => {
+                if non_executable.record(
+                    "coherence.transport_functoriality",
+                    vector_id,
+                    status,
+                    &mut vector_rows,
+                ) {
+                    continue;
+                }
+            }
+            None => failures
+                .push("coherence.transport_functoriality.vector_case_invalid_status".to_string()),
+        }
+        polarity.record_vector_id(vector_id, &contract.polarity_prefixes);
         let expect_bytes = match read_bytes(&expect_path) {
             Ok(bytes) => bytes,
             Err(err) => {
@@ -3293,34 +5646,37 @@ fn check_transport_functoriality(
         let expected_failure_classes =
             dedupe_sorted(expect_payload.expected_failure_classes.clone());
 
-        let evaluated = match evaluate_transport_case(&case_payload, &case_path) {
-            Ok(ok) => ok,
-            Err(err) => {
-                failures.push("coherence.transport_functoriality.vector_invalid_shape".to_string());
-                vector_rows.push(json!({
-                    "vectorId": vector_id,
-                    "result": "error",
-                    "error": err.to_string(),
-                }));
-                continue;
-            }
-        };
+        let evaluated =
+            match evaluate_transport_case(&case_payload, &case_path, contract.digest_scheme) {
+                Ok(ok) => ok,
+                Err(err) => {
+                    failures
+                        .push("coherence.transport_functoriality.vector_invalid_shape".to_string());
+                    vector_rows.push(json!({
+                        "vectorId": vector_id,
+                        "result": "error",
+                        "error": err.to_string(),
+                    }));
+                    continue;
+                }
+            };
 
+        let failure_class_diff = (!expected_failure_classes.is_empty())
+            .then(|| failure_class_set_diff(&expected_failure_classes, &evaluated.failure_classes));
         if expected_result == "accepted" || expected_result == "rejected" {
             if evaluated.result != expected_result {
                 failures.push("coherence.transport_functoriality.result_mismatch".to_string());
             }
-            if !expected_failure_classes.is_empty() {
-                let actual_failures = dedupe_sorted(evaluated.failure_classes.clone());
-                if expected_failure_classes != actual_failures {
-                    failures.push(
-                        "coherence.transport_functoriality.failure_class_mismatch".to_string(),
-                    );
-                }
+            if failure_class_diff
+                .as_ref()
+                .is_some_and(|diff| !diff.is_empty())
+            {
+                failures
+                    .push("coherence.transport_functoriality.failure_class_mismatch".to_string());
             }
         }
 
-        if vector_id.starts_with("invariance/") {
+        if contract.polarity_prefixes.is_invariance(vector_id) {
             record_invariance_row(
                 &mut failures,
                 "coherence.transport_functoriality",
@@ -3345,6 +5701,7 @@ fn check_transport_functoriality(
             "actualResult": evaluated.result,
             "expectedFailureClasses": expected_failure_classes,
             "actualFailureClasses": evaluated.failure_classes,
+            "failureClassDiff": failure_class_diff,
             "details": evaluated.details,
         }));
     }
@@ -3355,14 +5712,21 @@ fn check_transport_functoriality(
         &invariance_groups,
     );
     polarity.emit_missing_failures(&mut failures, "coherence.transport_functoriality", true);
+    polarity.emit_balance_warning(&mut warnings, "coherence.transport_functoriality");
+    warnings.extend(non_executable.warnings);
 
     Ok(ObligationCheck {
         failure_classes: dedupe_sorted(failures),
         details: json!({
             "fixtureRoot": to_repo_relative_or_absolute(repo_root, &fixture_root),
             "manifestVectors": manifest.vectors,
+            "skippedVectors": non_executable.skipped,
+            "pendingVectors": non_executable.pending,
+            "warnings": dedupe_sorted(warnings),
             "matchedVectorKinds": polarity.vector_kind_details(),
             "matchedExpectedResults": polarity.expected_result_details(),
+            "matchedVectorTotal": polarity.total_vectors(),
+            "polarityBalanceScore": polarity.balance_score(),
             "invariance": invariance_rows,
             "vectors": vector_rows,
         }),
@@ -3392,14 +5756,63 @@ fn check_span_square_commutation(
     repo_root: &Path,
     contract: &CoherenceContract,
 ) -> Result<ObligationCheck, CoherenceError> {
+    let span_kind_registry = load_span_kind_registry(repo_root, contract)?;
     check_site_obligation(
         repo_root,
         contract,
         "span_square_commutation",
-        evaluate_site_case_span_square_commutation,
+        |artifacts_payload: &Value, case_path: &Path, scheme: DigestScheme| {
+            evaluate_site_case_span_square_commutation(
+                artifacts_payload,
+                case_path,
+                span_kind_registry.as_ref(),
+                scheme,
+            )
+        },
     )
 }
 
+/// Registered span kinds from the control-plane contract, if it declares any.
+fn load_span_kind_registry(
+    repo_root: &Path,
+    contract: &CoherenceContract,
+) -> Result<Option<BTreeSet<String>>, CoherenceError> {
+    if contract
+        .surfaces
+        .control_plane_contract_path
+        .trim()
+        .is_empty()
+    {
+        return Ok(None);
+    }
+    let control_plane_contract_path = resolve_path(
+        repo_root,
+        contract.surfaces.control_plane_contract_path.as_str(),
+    );
+    // Only the registry subtree matters here; the rest of the control-plane
+    // contract is validated by `gate_chain_parity`.
+    let parse_error = |source| CoherenceError::ParseJson {
+        path: display_path(&control_plane_contract_path),
+        source,
+    };
+    let mut control_plane_contract: Value =
+        serde_json::from_slice(&read_bytes(&control_plane_contract_path)?).map_err(parse_error)?;
+    let span_kind_registry: Option<Vec<String>> = match control_plane_contract
+        .get_mut("spanKindRegistry")
+        .map(Value::take)
+    {
+        None | Some(Value::Null) => None,
+        Some(registry) => Some(serde_json::from_value(registry).map_err(parse_error)?),
+    };
+    Ok(span_kind_registry.map(|kinds| {
+        kinds
+            .iter()
+            .map(|kind| kind.trim().to_string())
+            .filter(|kind| !kind.is_empty())
+            .collect()
+    }))
+}
+
 fn check_coverage_transitivity(
     repo_root: &Path,
     contract: &CoherenceContract,
@@ -3476,7 +5889,7 @@ fn check_site_obligation(
     repo_root: &Path,
     contract: &CoherenceContract,
     obligation_id: &str,
-    evaluator: fn(&Value, &Path) -> Result<SiteEvaluation, CoherenceError>,
+    evaluator: impl Fn(&Value, &Path, DigestScheme) -> Result<SiteEvaluation, CoherenceError>,
 ) -> Result<ObligationCheck, CoherenceError> {
     let fixture_root = resolve_path(repo_root, contract.surfaces.site_fixture_root_path.as_str());
     let manifest_path = fixture_root.join("manifest.json");
@@ -3488,104 +5901,95 @@ fn check_site_obligation(
             }
         })?;
 
-    let mut failures = Vec::new();
-    if manifest.schema != 1 {
-        failures.push(format!("coherence.{obligation_id}.manifest_invalid_schema"));
-    }
-    if manifest.status != "executable" {
-        failures.push(format!("coherence.{obligation_id}.manifest_invalid_status"));
-    }
-    if manifest.vectors.is_empty() {
-        failures.push(format!("coherence.{obligation_id}.manifest_empty"));
-    }
-
-    let manifest_vector_set: BTreeSet<String> = manifest.vectors.iter().cloned().collect();
-    let scoped_vectors: Vec<String> = if manifest.obligation_vectors.is_empty() {
-        failures.push(format!(
-            "coherence.{obligation_id}.manifest_missing_obligation_vectors"
-        ));
-        manifest.vectors.clone()
-    } else {
-        manifest
-            .obligation_vectors
-            .get(obligation_id)
-            .cloned()
-            .unwrap_or_default()
-    };
-    for vector_id in &scoped_vectors {
-        if !manifest_vector_set.contains(vector_id) {
-            failures.push(format!(
-                "coherence.{obligation_id}.manifest_obligation_vector_not_declared"
-            ));
-        }
-    }
+    let SiteManifestLint {
+        mut failures,
+        scoped_vectors,
+        unclaimed_vectors,
+    } = manifest.lint(obligation_id);
 
-    let mut seen_vectors = BTreeSet::new();
     let mut vector_rows: Vec<Value> = Vec::new();
     let mut matched_count = 0usize;
     let mut polarity = PolarityCoverage::default();
     let mut invariance_groups: InvarianceGroups = BTreeMap::new();
     let invariance_failure_prefix = format!("coherence.{obligation_id}");
+    let mut non_executable = NonExecutableVectors::default();
+    let mut warnings = Vec::new();
 
     for vector_id in &scoped_vectors {
-        if !seen_vectors.insert(vector_id.clone()) {
-            failures.push(format!("coherence.{obligation_id}.duplicate_vector_id"));
+        if non_executable.record_manifest_entry(
+            &invariance_failure_prefix,
+            &manifest.vector_statuses,
+            vector_id,
+            &mut failures,
+            &mut vector_rows,
+        ) {
+            continue;
         }
 
-        let vector_root = fixture_root.join(vector_id);
-        let case_path = vector_root.join("case.json");
-        let expect_path = vector_root.join("expect.json");
-
-        let case_bytes = match read_bytes(&case_path) {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                failures.push(format!("coherence.{obligation_id}.vector_case_invalid"));
-                vector_rows.push(json!({
-                    "vectorId": vector_id,
-                    "result": "error",
-                    "error": err.to_string(),
-                }));
-                continue;
-            }
-        };
-        let case_payload: SiteCase = match serde_json::from_slice(&case_bytes) {
-            Ok(payload) => payload,
-            Err(source) => {
-                failures.push(format!("coherence.{obligation_id}.vector_case_invalid"));
-                vector_rows.push(json!({
-                    "vectorId": vector_id,
-                    "result": "error",
-                    "error": CoherenceError::ParseJson {
-                        path: display_path(&case_path),
-                        source,
-                    }.to_string(),
-                }));
-                continue;
-            }
+        let inline_vector = manifest.inline_vectors.get(vector_id);
+        let (case_path, expect_path) = if inline_vector.is_some() {
+            let inline_ref = format!("{}#inlineVectors/{vector_id}", display_path(&manifest_path));
+            (
+                PathBuf::from(format!("{inline_ref}/case")),
+                PathBuf::from(format!("{inline_ref}/expect")),
+            )
+        } else {
+            let vector_root = fixture_root.join(vector_id);
+            (
+                vector_root.join("case.json"),
+                vector_root.join("expect.json"),
+            )
         };
 
+        let case_payload: SiteCase =
+            match load_site_vector_payload(&case_path, inline_vector.map(|inline| &inline.case)) {
+                Ok(payload) => payload,
+                Err(err) => {
+                    failures.push(format!("coherence.{obligation_id}.vector_case_invalid"));
+                    vector_rows.push(json!({
+                        "vectorId": vector_id,
+                        "result": "error",
+                        "error": err.to_string(),
+                    }));
+                    continue;
+                }
+            };
+
         if case_payload.obligation_id != obligation_id {
             failures.push(format!(
                 "coherence.{obligation_id}.manifest_obligation_vector_mismatch"
             ));
             continue;
         }
+        match FixtureStatus::parse(&case_payload.status) {
+            Some(status) => {
+                if non_executable.record(
+                    &invariance_failure_prefix,
+                    vector_id,
+                    status,
+                    &mut vector_rows,
+                ) {
+                    continue;
+                }
+            }
+            None => failures.push(format!(
+                "coherence.{obligation_id}.vector_case_invalid_status"
+            )),
+        }
         matched_count += 1;
-        polarity.record_vector_id(vector_id);
+        polarity.record_vector_id(vector_id, &contract.polarity_prefixes);
 
         if case_payload.schema != 1 {
             failures.push(format!(
                 "coherence.{obligation_id}.vector_case_invalid_schema"
             ));
         }
-        if case_payload.status != "executable" {
-            failures.push(format!(
-                "coherence.{obligation_id}.vector_case_invalid_status"
-            ));
-        }
 
-        let expect_bytes = match read_bytes(&expect_path) {
-            Ok(bytes) => bytes,
+        let expect_payload: SiteExpect = match load_site_vector_payload(
+            &expect_path,
+            inline_vector.map(|inline| &inline.expect),
+        ) {
+            Ok(payload) => payload,
             Err(err) => {
                 failures.push(format!("coherence.{obligation_id}.vector_expect_invalid"));
                 vector_rows.push(json!({
@@ -3596,21 +6000,6 @@ fn check_site_obligation(
                 continue;
             }
         };
-        let expect_payload: SiteExpect = match serde_json::from_slice(&expect_bytes) {
-            Ok(payload) => payload,
-            Err(source) => {
-                failures.push(format!("coherence.{obligation_id}.vector_expect_invalid"));
-                vector_rows.push(json!({
-                    "vectorId": vector_id,
-                    "result": "error",
-                    "error": CoherenceError::ParseJson {
-                        path: display_path(&expect_path),
-                        source,
-                    }.to_string(),
-                }));
-                continue;
-            }
-        };
 
         let expected_result = expect_payload.result.as_str();
         if expect_payload.schema != 1 {
@@ -3631,8 +6020,16 @@ fn check_site_obligation(
             polarity.record_expected_result(expected_result);
         }
         let expected_failure_classes = dedupe_sorted(expect_payload.expected_failure_classes);
+        let unknown_expected_classes =
+            unknown_expected_failure_classes(obligation_id, &expected_failure_classes);
+        if !unknown_expected_classes.is_empty() {
+            failures.push(format!(
+                "coherence.{obligation_id}.unknown_expected_failure_class"
+            ));
+        }
 
-        let evaluated = match evaluator(&case_payload.artifacts, &case_path) {
+        let evaluated = match evaluator(&case_payload.artifacts, &case_path, contract.digest_scheme)
+        {
             Ok(ok) => ok,
             Err(err) => {
                 failures.push(format!("coherence.{obligation_id}.vector_invalid_shape"));
@@ -3645,19 +6042,21 @@ fn check_site_obligation(
             }
         };
 
+        let failure_class_diff = (!expected_failure_classes.is_empty())
+            .then(|| failure_class_set_diff(&expected_failure_classes, &evaluated.failure_classes));
         if expected_result == "accepted" || expected_result == "rejected" {
             if evaluated.result != expected_result {
                 failures.push(format!("coherence.{obligation_id}.result_mismatch"));
             }
-            if !expected_failure_classes.is_empty() {
-                let actual_failures = dedupe_sorted(evaluated.failure_classes.clone());
-                if expected_failure_classes != actual_failures {
-                    failures.push(format!("coherence.{obligation_id}.failure_class_mismatch"));
-                }
+            if failure_class_diff
+                .as_ref()
+                .is_some_and(|diff| !diff.is_empty())
+            {
+                failures.push(format!("coherence.{obligation_id}.failure_class_mismatch"));
             }
         }
 
-        if vector_id.starts_with("invariance/") {
+        if contract.polarity_prefixes.is_invariance(vector_id) {
             record_invariance_row(
                 &mut failures,
                 invariance_failure_prefix.as_str(),
@@ -3672,7 +6071,7 @@ fn check_site_obligation(
             );
         }
 
-        vector_rows.push(json!({
+        let mut row = json!({
             "vectorId": vector_id,
             "semanticScenarioId": case_payload.semantic_scenario_id,
             "profile": case_payload.profile,
@@ -3680,8 +6079,13 @@ fn check_site_obligation(
             "actualResult": evaluated.result,
             "expectedFailureClasses": expected_failure_classes,
             "actualFailureClasses": evaluated.failure_classes,
+            "failureClassDiff": failure_class_diff,
             "details": evaluated.details,
-        }));
+        });
+        if !unknown_expected_classes.is_empty() {
+            row["unknownExpectedFailureClasses"] = json!(unknown_expected_classes);
+        }
+        vector_rows.push(row);
     }
 
     let invariance_rows = validate_invariance_groups(
@@ -3700,6 +6104,10 @@ fn check_site_obligation(
         invariance_failure_prefix.as_str(),
         matched_count > 0,
     );
+    if matched_count > 0 {
+        polarity.emit_balance_warning(&mut warnings, invariance_failure_prefix.as_str());
+    }
+    warnings.extend(non_executable.warnings);
 
     Ok(ObligationCheck {
         failure_classes: dedupe_sorted(failures),
@@ -3707,19 +6115,43 @@ fn check_site_obligation(
             "fixtureRoot": to_repo_relative_or_absolute(repo_root, &fixture_root),
             "manifestVectors": manifest.vectors,
             "manifestObligationVectors": manifest.obligation_vectors,
+            "manifestInlineVectors": manifest.inline_vectors.keys().collect::<Vec<_>>(),
+            "manifestUnreferencedVectors": unclaimed_vectors,
             "scopedVectors": scoped_vectors,
             "matchedVectors": matched_count,
+            "skippedVectors": non_executable.skipped,
+            "pendingVectors": non_executable.pending,
+            "warnings": dedupe_sorted(warnings),
             "matchedVectorKinds": polarity.vector_kind_details(),
             "matchedExpectedResults": polarity.expected_result_details(),
+            "matchedVectorTotal": polarity.total_vectors(),
+            "polarityBalanceScore": polarity.balance_score(),
             "invariance": invariance_rows,
             "vectors": vector_rows,
         }),
     })
 }
 
+/// Parse a site vector payload either from its inline manifest value or from
+/// the on-disk file at `path`.
+fn load_site_vector_payload<T: DeserializeOwned>(
+    path: &Path,
+    inline: Option<&Value>,
+) -> Result<T, CoherenceError> {
+    let parsed = match inline {
+        Some(value) => T::deserialize(value),
+        None => serde_json::from_slice(&read_bytes(path)?),
+    };
+    parsed.map_err(|source| CoherenceError::ParseJson {
+        path: display_path(path),
+        source,
+    })
+}
+
 fn evaluate_site_case_coverage_base_change(
     artifacts_payload: &Value,
     case_path: &Path,
+    scheme: DigestScheme,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -3789,19 +6221,29 @@ fn evaluate_site_case_coverage_base_change(
         mapped_pullback_parts.push(pullback.to_string());
     }
 
+    // Multiset covers compare part multiplicities, so repeated parts are data
+    // rather than violations.
+    let multiset = optional_bool_field(coverage, "multiset", case_path, "artifacts.coverage")?
+        .unwrap_or(false);
+
     let base_set: BTreeSet<String> = base_parts.iter().cloned().collect();
     let source_set: BTreeSet<String> = source_parts.iter().cloned().collect();
     let pullback_set: BTreeSet<String> = pullback_parts.iter().cloned().collect();
     let mapped_pullback_set: BTreeSet<String> = mapped_pullback_parts.iter().cloned().collect();
 
     let mut failure_classes = Vec::new();
-    if has_duplicates(&base_parts)
-        || has_duplicates(&pullback_parts)
-        || has_duplicates(&source_parts)
-        || has_duplicates(&mapped_pullback_parts)
-        || base_set != source_set
-        || pullback_set != mapped_pullback_set
-    {
+    let violated = if multiset {
+        part_multiplicities(&base_parts) != part_multiplicities(&source_parts)
+            || part_multiplicities(&pullback_parts) != part_multiplicities(&mapped_pullback_parts)
+    } else {
+        has_duplicates(&base_parts)
+            || has_duplicates(&pullback_parts)
+            || has_duplicates(&source_parts)
+            || has_duplicates(&mapped_pullback_parts)
+            || base_set != source_set
+            || pullback_set != mapped_pullback_set
+    };
+    if violated {
         failure_classes.push("coherence.coverage_base_change.violation".to_string());
     }
 
@@ -3813,10 +6255,11 @@ fn evaluate_site_case_coverage_base_change(
         },
         failure_classes: dedupe_sorted(failure_classes),
         details: json!({
+            "multiset": multiset,
             "digests": {
-                "baseCoverParts": semantic_digest(&json!(base_parts)),
-                "pullbackCoverParts": semantic_digest(&json!(pullback_parts)),
-                "pullbackMapping": semantic_digest(&json!(pullback_of_parts)),
+                "baseCoverParts": semantic_digest(&json!(base_parts), scheme),
+                "pullbackCoverParts": semantic_digest(&json!(pullback_parts), scheme),
+                "pullbackMapping": semantic_digest(&json!(pullback_of_parts), scheme),
             },
             "sets": {
                 "baseCoverParts": sorted_vec_from_set(&base_set),
@@ -3831,6 +6274,7 @@ fn evaluate_site_case_coverage_base_change(
 fn evaluate_site_case_coverage_transitivity(
     artifacts_payload: &Value,
     case_path: &Path,
+    scheme: DigestScheme,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -3908,6 +6352,44 @@ fn evaluate_site_case_coverage_transitivity(
         failure_classes.push("coherence.coverage_transitivity.violation".to_string());
     }
 
+    // Optional: `(A ∘ B) ∘ C` and `A ∘ (B ∘ C)` must compose to the same cover.
+    let associativity = match coverage.get("compositionAssociativity") {
+        None => Value::Null,
+        Some(value) => {
+            let associativity = value.as_object().ok_or_else(|| {
+                CoherenceError::Contract(format!(
+                    "{}: artifacts.coverage.compositionAssociativity must be an object",
+                    display_path(case_path)
+                ))
+            })?;
+            let mut composed_sets = Vec::new();
+            for field in ["leftAssociated", "rightAssociated", "expectedCover"] {
+                let cover = require_object_field(associativity, field, case_path)?;
+                let parts = require_string_array_field(
+                    cover,
+                    "parts",
+                    case_path,
+                    &format!("artifacts.coverage.compositionAssociativity.{field}"),
+                )?;
+                if has_duplicates(&parts) {
+                    failure_classes.push(
+                        "coherence.coverage_transitivity.associativity_violation".to_string(),
+                    );
+                }
+                composed_sets.push(parts.into_iter().collect::<BTreeSet<String>>());
+            }
+            if composed_sets.windows(2).any(|pair| pair[0] != pair[1]) {
+                failure_classes
+                    .push("coherence.coverage_transitivity.associativity_violation".to_string());
+            }
+            json!({
+                "leftAssociated": sorted_vec_from_set(&composed_sets[0]),
+                "rightAssociated": sorted_vec_from_set(&composed_sets[1]),
+                "expectedCover": sorted_vec_from_set(&composed_sets[2]),
+            })
+        }
+    };
+
     Ok(SiteEvaluation {
         result: if failure_classes.is_empty() {
             "accepted".to_string()
@@ -3917,9 +6399,9 @@ fn evaluate_site_case_coverage_transitivity(
         failure_classes: dedupe_sorted(failure_classes),
         details: json!({
             "digests": {
-                "outerCoverParts": semantic_digest(&json!(outer_parts)),
-                "refinementCovers": semantic_digest(&json!(refinement_covers)),
-                "composedCoverParts": semantic_digest(&json!(composed_parts)),
+                "outerCoverParts": semantic_digest(&json!(outer_parts), scheme),
+                "refinementCovers": semantic_digest(&json!(refinement_covers), scheme),
+                "composedCoverParts": semantic_digest(&json!(composed_parts), scheme),
             },
             "sets": {
                 "outerCoverParts": sorted_vec_from_set(&outer_set),
@@ -3928,6 +6410,7 @@ fn evaluate_site_case_coverage_transitivity(
                 "composedCoverParts": sorted_vec_from_set(&composed_set),
             },
             "coverageMultiplicity": coverage_by_outer,
+            "compositionAssociativity": associativity,
         }),
     })
 }
@@ -3935,6 +6418,7 @@ fn evaluate_site_case_coverage_transitivity(
 fn evaluate_site_case_glue_or_witness_contractibility(
     artifacts_payload: &Value,
     case_path: &Path,
+    scheme: DigestScheme,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -3975,16 +6459,51 @@ fn evaluate_site_case_glue_or_witness_contractibility(
     if has_glue && compatibility_witnesses.is_empty() {
         failure_classes.push("coherence.glue_or_witness_contractibility.violation".to_string());
     }
+    let local_ids: BTreeSet<&str> = locals
+        .iter()
+        .filter_map(|local| local.get("id").and_then(Value::as_str))
+        .map(str::trim)
+        .collect();
+    if compatibility_witnesses.iter().any(|witness| {
+        witness
+            .get("overlap")
+            .and_then(Value::as_str)
+            .is_some_and(|overlap| !overlap_joins_declared_locals(overlap.trim(), &local_ids))
+    }) {
+        failure_classes.push(
+            "coherence.glue_or_witness_contractibility.witness_references_unknown_local"
+                .to_string(),
+        );
+    }
+    // Absent means any non-empty class is accepted.
+    let allowed_obstruction_classes = if descent.contains_key("allowedObstructionClasses") {
+        Some(optional_string_array_field(
+            descent,
+            "allowedObstructionClasses",
+            case_path,
+            "artifacts.descent",
+        )?)
+    } else {
+        None
+    };
     if has_obstruction {
-        let valid_obstruction_class = obstruction
+        let obstruction_class = obstruction
             .as_ref()
             .and_then(Value::as_object)
             .and_then(|row| row.get("class"))
             .and_then(Value::as_str)
             .map(str::trim)
-            .is_some_and(|v| !v.is_empty());
-        if !valid_obstruction_class {
-            failure_classes.push("coherence.glue_or_witness_contractibility.violation".to_string());
+            .filter(|v| !v.is_empty());
+        match (obstruction_class, &allowed_obstruction_classes) {
+            (None, _) => failure_classes
+                .push("coherence.glue_or_witness_contractibility.violation".to_string()),
+            (Some(class), Some(allowed)) if !allowed.iter().any(|known| known == class) => {
+                failure_classes.push(
+                    "coherence.glue_or_witness_contractibility.unknown_obstruction_class"
+                        .to_string(),
+                )
+            }
+            _ => {}
         }
     }
     if locals.is_empty() {
@@ -4000,10 +6519,10 @@ fn evaluate_site_case_glue_or_witness_contractibility(
         failure_classes: dedupe_sorted(failure_classes),
         details: json!({
             "digests": {
-                "locals": semantic_digest(&json!(locals)),
-                "compatibilityWitnesses": semantic_digest(&json!(compatibility_witnesses)),
-                "glue": semantic_digest(&glue.clone().unwrap_or(Value::Null)),
-                "obstruction": semantic_digest(&obstruction.clone().unwrap_or(Value::Null)),
+                "locals": semantic_digest(&json!(locals), scheme),
+                "compatibilityWitnesses": semantic_digest(&json!(compatibility_witnesses), scheme),
+                "glue": semantic_digest(&glue.clone().unwrap_or(Value::Null), scheme),
+                "obstruction": semantic_digest(&obstruction.clone().unwrap_or(Value::Null), scheme),
             },
             "shape": {
                 "localsCount": locals.len(),
@@ -4015,12 +6534,28 @@ fn evaluate_site_case_glue_or_witness_contractibility(
     })
 }
 
+/// Whether `overlap` is declared local ids joined by `_`. Local ids may
+/// themselves contain `_`, so every split point is tried.
+fn overlap_joins_declared_locals(overlap: &str, local_ids: &BTreeSet<&str>) -> bool {
+    let segments: Vec<&str> = overlap.split('_').collect();
+    // covered[i]: segments[..i] splits into declared local ids.
+    let mut covered = vec![false; segments.len() + 1];
+    covered[0] = true;
+    for end in 1..=segments.len() {
+        covered[end] = (0..end).any(|start| {
+            covered[start] && local_ids.contains(segments[start..end].join("_").as_str())
+        });
+    }
+    covered[segments.len()]
+}
+
 fn evaluate_cwf_row_equalities(
     rows: &[Value],
     case_path: &Path,
     field_prefix: &str,
     left_key: &str,
     right_key: &str,
+    scheme: DigestScheme,
 ) -> Result<(Vec<String>, Vec<Value>), CoherenceError> {
     let mut mismatch_labels = Vec::new();
     let mut digest_rows = Vec::new();
@@ -4038,15 +6573,25 @@ fn evaluate_cwf_row_equalities(
             .filter(|value| !value.is_empty())
             .map(ToString::to_string)
             .unwrap_or_else(|| format!("{field_prefix}[{index}]"));
+        // Rows marked `expectEqual: false` encode known-different pairs, so an
+        // unexpected match is the mismatch.
+        let expect_equal = optional_bool_field(
+            row_obj,
+            "expectEqual",
+            case_path,
+            &format!("{field_prefix}[{index}]"),
+        )?
+        .unwrap_or(true);
         let left_value = require_value_field(row_obj, left_key, case_path)?;
         let right_value = require_value_field(row_obj, right_key, case_path)?;
-        let left_digest = semantic_digest(left_value);
-        let right_digest = semantic_digest(right_value);
-        if left_digest != right_digest {
+        let left_digest = semantic_digest(left_value, scheme);
+        let right_digest = semantic_digest(right_value, scheme);
+        if (left_digest == right_digest) != expect_equal {
             mismatch_labels.push(label.clone());
         }
         digest_rows.push(json!({
             "label": label,
+            "expectEqual": expect_equal,
             "leftDigest": left_digest,
             "rightDigest": right_digest,
         }));
@@ -4057,6 +6602,7 @@ fn evaluate_cwf_row_equalities(
 fn evaluate_site_case_cwf_substitution_identity(
     artifacts_payload: &Value,
     case_path: &Path,
+    scheme: DigestScheme,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -4091,6 +6637,7 @@ fn evaluate_site_case_cwf_substitution_identity(
         "artifacts.cwf.substitution.types",
         "direct",
         "afterIdentity",
+        scheme,
     )?;
     let (term_mismatch, term_digests) = evaluate_cwf_row_equalities(
         term_rows,
@@ -4098,6 +6645,7 @@ fn evaluate_site_case_cwf_substitution_identity(
         "artifacts.cwf.substitution.terms",
         "direct",
         "afterIdentity",
+        scheme,
     )?;
 
     let mut failure_classes = Vec::new();
@@ -4134,6 +6682,7 @@ fn evaluate_site_case_cwf_substitution_identity(
 fn evaluate_site_case_cwf_substitution_composition(
     artifacts_payload: &Value,
     case_path: &Path,
+    scheme: DigestScheme,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -4168,6 +6717,7 @@ fn evaluate_site_case_cwf_substitution_composition(
         "artifacts.cwf.substitution.types",
         "afterCompose",
         "afterStepwise",
+        scheme,
     )?;
     let (term_mismatch, term_digests) = evaluate_cwf_row_equalities(
         term_rows,
@@ -4175,6 +6725,7 @@ fn evaluate_site_case_cwf_substitution_composition(
         "artifacts.cwf.substitution.terms",
         "afterCompose",
         "afterStepwise",
+        scheme,
     )?;
 
     let mut failure_classes = Vec::new();
@@ -4211,6 +6762,7 @@ fn evaluate_site_case_cwf_substitution_composition(
 fn evaluate_site_case_cwf_comprehension_beta(
     artifacts_payload: &Value,
     case_path: &Path,
+    scheme: DigestScheme,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -4236,6 +6788,7 @@ fn evaluate_site_case_cwf_comprehension_beta(
         "artifacts.cwf.comprehension.beta",
         "original",
         "afterBeta",
+        scheme,
     )?;
 
     let mut failure_classes = Vec::new();
@@ -4265,6 +6818,7 @@ fn evaluate_site_case_cwf_comprehension_beta(
 fn evaluate_site_case_cwf_comprehension_eta(
     artifacts_payload: &Value,
     case_path: &Path,
+    scheme: DigestScheme,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -4290,6 +6844,7 @@ fn evaluate_site_case_cwf_comprehension_eta(
         "artifacts.cwf.comprehension.eta",
         "original",
         "afterEta",
+        scheme,
     )?;
 
     let mut failure_classes = Vec::new();
@@ -4316,9 +6871,13 @@ fn evaluate_site_case_cwf_comprehension_eta(
     })
 }
 
+/// Span-square evaluation; when `span_kind_registry` is present every span
+/// `kind` must be registered, otherwise any non-empty kind is accepted.
 fn evaluate_site_case_span_square_commutation(
     artifacts_payload: &Value,
     case_path: &Path,
+    span_kind_registry: Option<&BTreeSet<String>>,
+    scheme: DigestScheme,
 ) -> Result<SiteEvaluation, CoherenceError> {
     let artifacts = artifacts_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -4346,6 +6905,14 @@ fn evaluate_site_case_span_square_commutation(
             ))
         })?;
 
+    let forbid_unreferenced_artifacts = optional_bool_field(
+        span_square,
+        "forbidUnreferencedArtifacts",
+        case_path,
+        "artifacts.spanSquare",
+    )?
+    .unwrap_or(false);
+
     let mut failures = Vec::new();
     if spans.is_empty() || squares.is_empty() {
         failures.push("coherence.span_square_commutation.violation".to_string());
@@ -4353,6 +6920,7 @@ fn evaluate_site_case_span_square_commutation(
 
     let mut span_digests: BTreeMap<String, String> = BTreeMap::new();
     let mut span_rows = Vec::new();
+    let mut span_inputs = Vec::with_capacity(spans.len());
     for (index, span) in spans.iter().enumerate() {
         let span_obj = span.as_object().ok_or_else(|| {
             CoherenceError::Contract(format!(
@@ -4362,15 +6930,26 @@ fn evaluate_site_case_span_square_commutation(
         })?;
         let span_id = require_non_empty_string_field(span_obj, "id", case_path)?;
         let span_kind = require_non_empty_string_field(span_obj, "kind", case_path)?;
+        if let Some(registry) = span_kind_registry
+            && !registry.contains(&span_kind)
+        {
+            failures.push("coherence.span_square_commutation.unregistered_span_kind".to_string());
+        }
         let left = require_value_field(span_obj, "left", case_path)?;
         let apex = require_value_field(span_obj, "apex", case_path)?;
         let right = require_value_field(span_obj, "right", case_path)?;
-        let span_digest = semantic_digest(&json!({
+        let payload = json!({
             "kind": span_kind,
             "left": left,
             "apex": apex,
             "right": right,
-        }));
+        });
+        span_inputs.push((span_id, span_kind, payload));
+    }
+    let span_digest_values = parallel_digests(&span_inputs, |(_, _, payload)| {
+        semantic_digest(payload, scheme)
+    });
+    for ((span_id, span_kind, _), span_digest) in span_inputs.into_iter().zip(span_digest_values) {
         if span_digests
             .insert(span_id.clone(), span_digest.clone())
             .is_some()
@@ -4387,6 +6966,7 @@ fn evaluate_site_case_span_square_commutation(
     let mut square_ids = BTreeSet::new();
     let mut square_digests: BTreeMap<String, String> = BTreeMap::new();
     let mut square_rows = Vec::new();
+    let mut square_inputs = Vec::with_capacity(squares.len());
     for (index, square) in squares.iter().enumerate() {
         let square_obj = square.as_object().ok_or_else(|| {
             CoherenceError::Contract(format!(
@@ -4410,14 +6990,25 @@ fn evaluate_site_case_span_square_commutation(
             "artifacts.spanSquare.squares[]",
         )?);
         let digest = require_non_empty_string_field(square_obj, "digest", case_path)?;
-        let expected_digest = square_witness_digest(
-            top.as_str(),
-            bottom.as_str(),
-            left.as_str(),
-            right.as_str(),
-            result.as_str(),
-            &square_failure_classes,
-        );
+        square_inputs.push((
+            square_id,
+            [top, bottom, left, right],
+            result,
+            square_failure_classes,
+            digest,
+        ));
+    }
+    let expected_square_digests = parallel_digests(
+        &square_inputs,
+        |(_, [top, bottom, left, right], result, failure_classes, _)| {
+            square_witness_digest(top, bottom, left, right, result, failure_classes, scheme)
+        },
+    );
+    for (
+        (square_id, [top, bottom, left, right], result, square_failure_classes, digest),
+        expected_digest,
+    ) in square_inputs.into_iter().zip(expected_square_digests)
+    {
         square_digests.insert(square_id.clone(), expected_digest.clone());
         if digest != expected_digest {
             failures.push("coherence.span_square_commutation.violation".to_string());
@@ -4464,6 +7055,8 @@ fn evaluate_site_case_span_square_commutation(
     let mut composition_summary = json!({
         "present": false
     });
+    let mut unreferenced_spans: Option<Vec<String>> = None;
+    let mut unreferenced_squares: Option<Vec<String>> = None;
     if let Some(composition_value) = span_square.get("compositionLaws") {
         let composition = composition_value.as_object().ok_or_else(|| {
             CoherenceError::Contract(format!(
@@ -4508,6 +7101,8 @@ fn evaluate_site_case_span_square_commutation(
         }
         let mut law_ids = BTreeSet::new();
         let mut accepted_laws = BTreeSet::new();
+        let mut referenced_spans = BTreeSet::new();
+        let mut referenced_squares = BTreeSet::new();
         let mut used_square_modes = SquareCompositionModes::default();
         let identity_span_set: BTreeSet<String> = identity_span_ids.iter().cloned().collect();
         let identity_square_set: BTreeSet<String> = identity_square_ids.iter().cloned().collect();
@@ -4534,6 +7129,10 @@ fn evaluate_site_case_span_square_commutation(
                 "artifacts.spanSquare.compositionLaws.laws[]",
             )?);
             let digest = require_non_empty_string_field(law_obj, "digest", case_path)?;
+            for expr in [left_expr, right_expr] {
+                collect_expression_refs(expr, "span", &mut referenced_spans);
+                collect_expression_refs(expr, "square", &mut referenced_squares);
+            }
             let expected_digest = composition_law_digest(
                 kind.as_str(),
                 law.as_str(),
@@ -4541,6 +7140,7 @@ fn evaluate_site_case_span_square_commutation(
                 right_expr,
                 result.as_str(),
                 &law_failure_classes,
+                scheme,
             );
             if digest != expected_digest {
                 failures.push("coherence.span_square_commutation.violation".to_string());
@@ -4687,6 +7287,30 @@ fn evaluate_site_case_span_square_commutation(
         if !used_square_modes.horizontal || !used_square_modes.vertical {
             failures.push("coherence.span_square_commutation.violation".to_string());
         }
+
+        // Spans bounding a square are in use even when no law names them.
+        for square_row in &square_rows {
+            for edge in ["top", "bottom", "left", "right"] {
+                if let Some(span_id) = square_row[edge]["id"].as_str() {
+                    referenced_spans.insert(span_id.to_string());
+                }
+            }
+        }
+        let dead_spans: Vec<String> = span_digests
+            .keys()
+            .filter(|span_id| !referenced_spans.contains(*span_id))
+            .cloned()
+            .collect();
+        let dead_squares: Vec<String> = square_digests
+            .keys()
+            .filter(|square_id| !referenced_squares.contains(*square_id))
+            .cloned()
+            .collect();
+        if forbid_unreferenced_artifacts && (!dead_spans.is_empty() || !dead_squares.is_empty()) {
+            failures.push("coherence.span_square_commutation.unreferenced_artifact".to_string());
+        }
+        unreferenced_spans = Some(dead_spans);
+        unreferenced_squares = Some(dead_squares);
         composition_summary = json!({
             "present": true,
             "lawCount": law_rows.len(),
@@ -4716,6 +7340,8 @@ fn evaluate_site_case_span_square_commutation(
             "squares": square_rows,
             "compositionLaws": composition_rows,
             "compositionSummary": composition_summary,
+            "unreferencedSpans": unreferenced_spans,
+            "unreferencedSquares": unreferenced_squares,
         }),
     })
 }
@@ -4730,6 +7356,7 @@ struct TransportEvaluation {
 fn evaluate_transport_case(
     case_payload: &Value,
     case_path: &Path,
+    scheme: DigestScheme,
 ) -> Result<TransportEvaluation, CoherenceError> {
     let root = case_payload.as_object().ok_or_else(|| {
         CoherenceError::Contract(format!(
@@ -4761,20 +7388,22 @@ fn evaluate_transport_case(
     let naturality_left = require_value_field(naturality, "left", case_path)?;
     let naturality_right = require_value_field(naturality, "right", case_path)?;
 
-    let base_identity_digest = semantic_digest(base_identity);
-    let base_f_digest = semantic_digest(base_f);
-    let base_g_digest = semantic_digest(base_g);
-    let base_g_after_f_digest = semantic_digest(base_g_after_f);
+    let base_identity_digest = semantic_digest(base_identity, scheme);
+    let base_f_digest = semantic_digest(base_f, scheme);
+    let base_g_digest = semantic_digest(base_g, scheme);
+    let base_g_after_f_digest = semantic_digest(base_g_after_f, scheme);
 
-    let fibre_identity_digest = semantic_digest(fibre_identity);
-    let fibre_f_identity_digest = semantic_digest(fibre_f_identity);
-    let fibre_f_f_digest = semantic_digest(fibre_f_f);
-    let fibre_f_g_digest = semantic_digest(fibre_f_g);
-    let fibre_f_g_after_f_digest = semantic_digest(fibre_f_g_after_f);
-    let fibre_f_g_after_f_f_digest = semantic_digest(fibre_f_g_after_f_f);
+    let fibre_identity_digest = semantic_digest(fibre_identity, scheme);
+    let fibre_f_identity_digest = semantic_digest(fibre_f_identity, scheme);
+    let fibre_f_f_digest = semantic_digest(fibre_f_f, scheme);
+    let fibre_f_g_digest = semantic_digest(fibre_f_g, scheme);
+    let fibre_f_g_after_f_digest = semantic_digest(fibre_f_g_after_f, scheme);
+    let fibre_f_g_after_f_f_digest = semantic_digest(fibre_f_g_after_f_f, scheme);
 
-    let naturality_left_digest = semantic_digest(naturality_left);
-    let naturality_right_digest = semantic_digest(naturality_right);
+    let naturality_left_digest = semantic_digest(naturality_left, scheme);
+    let naturality_right_digest = semantic_digest(naturality_right, scheme);
+    let naturality_left_complete = is_complete_naturality_side(naturality_left);
+    let naturality_right_complete = is_complete_naturality_side(naturality_right);
 
     let mut failure_classes = Vec::new();
     if fibre_identity_digest != fibre_f_identity_digest {
@@ -4786,6 +7415,10 @@ fn evaluate_transport_case(
     if naturality_left_digest != naturality_right_digest {
         failure_classes.push("coherence.transport_functoriality.naturality_violation".to_string());
     }
+    if !naturality_left_complete || !naturality_right_complete {
+        failure_classes
+            .push("coherence.transport_functoriality.naturality_square_incomplete".to_string());
+    }
 
     Ok(TransportEvaluation {
         result: if failure_classes.is_empty() {
@@ -4817,13 +7450,115 @@ fn evaluate_transport_case(
                 "naturality": {
                     "left": naturality_left_digest,
                     "right": naturality_right_digest,
+                    "leftIsComplete": naturality_left_complete,
+                    "rightIsComplete": naturality_right_complete,
                 }
             }
         }),
     })
 }
 
+/// A naturality side is structurally complete when it is an object carrying at
+/// least one field; `null` or `{}` cannot witness a square.
+fn is_complete_naturality_side(value: &Value) -> bool {
+    value.as_object().is_some_and(|map| !map.is_empty())
+}
+
+/// Recursively sort object keys so witness bytes do not depend on whether
+/// `serde_json` preserves insertion order. Unlike `normalize_semantics`, arrays
+/// keep their order and duplicates: vector rows and reason lists are
+/// order-significant in obligation details.
+fn sort_object_keys(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_unstable_by(|left, right| left.0.cmp(right.0));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, item)| (key.clone(), sort_object_keys(item)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.iter().map(sort_object_keys).collect()),
+        _ => value.clone(),
+    }
+}
+
+/// Nesting depth beyond which semantic normalization leaves values untouched.
+const SEMANTIC_NORMALIZE_MAX_DEPTH: usize = 64;
+/// Digest family for values past `SEMANTIC_NORMALIZE_MAX_DEPTH`, hashed raw
+/// (neither sanitized nor normalized).
+const SEMANTIC_DIGEST_DEPTH_EXCEEDED_FAMILY: &str = "semantic_digest_depth_exceeded";
+/// Replaces any string (value or object key) containing a NUL byte before hashing.
+const DIGEST_NULL_BYTE_SENTINEL: &str = "<null-byte-sanitized>";
+
+/// Copy of `value` with every NUL-bearing string swapped for
+/// `DIGEST_NULL_BYTE_SENTINEL`, so digest pre-images never carry `\u0000`.
+/// Values nested past `SEMANTIC_NORMALIZE_MAX_DEPTH` are returned unchanged,
+/// like `normalize_semantics`, before the recursive pass starts.
+fn sanitize_value_for_digest(value: &Value) -> Value {
+    if exceeds_nesting_depth(value, SEMANTIC_NORMALIZE_MAX_DEPTH) {
+        note_semantic_depth_exceeded();
+        return value.clone();
+    }
+    sanitize_value_within_limit(value)
+}
+
+fn sanitize_value_within_limit(value: &Value) -> Value {
+    let sanitize_str = |text: &str| {
+        if text.contains('\0') {
+            DIGEST_NULL_BYTE_SENTINEL.to_string()
+        } else {
+            text.to_string()
+        }
+    };
+    match value {
+        Value::String(text) => Value::String(sanitize_str(text)),
+        Value::Array(items) => {
+            Value::Array(items.iter().map(sanitize_value_within_limit).collect())
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| (sanitize_str(key), sanitize_value_within_limit(item)))
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
 fn normalize_semantics(value: &Value) -> Value {
+    normalize_semantics_with_depth_limit(value, SEMANTIC_NORMALIZE_MAX_DEPTH)
+}
+
+/// Normalize `value`, or return it unchanged when it nests deeper than
+/// `max_depth` containers. The depth is measured iteratively, so hostile
+/// shapes are rejected before the recursive pass can overflow the stack.
+fn normalize_semantics_with_depth_limit(value: &Value, max_depth: usize) -> Value {
+    if exceeds_nesting_depth(value, max_depth) {
+        note_semantic_depth_exceeded();
+        return value.clone();
+    }
+    normalize_semantics_within_limit(value)
+}
+
+fn exceeds_nesting_depth(value: &Value, max_depth: usize) -> bool {
+    let mut pending = vec![(value, 0usize)];
+    while let Some((item, depth)) = pending.pop() {
+        let children: Box<dyn Iterator<Item = &Value>> = match item {
+            Value::Object(map) => Box::new(map.values()),
+            Value::Array(items) => Box::new(items.iter()),
+            _ => continue,
+        };
+        if depth + 1 > max_depth {
+            return true;
+        }
+        pending.extend(children.map(|child| (child, depth + 1)));
+    }
+    false
+}
+
+fn normalize_semantics_within_limit(value: &Value) -> Value {
     match value {
         Value::Object(map) => {
             let mut keys: Vec<&String> = map.keys().collect();
@@ -4831,7 +7566,7 @@ fn normalize_semantics(value: &Value) -> Value {
             let mut sorted = Map::new();
             for key in keys {
                 if let Some(item) = map.get(key) {
-                    sorted.insert(key.clone(), normalize_semantics(item));
+                    sorted.insert(key.clone(), normalize_semantics_within_limit(item));
                 }
             }
             Value::Object(sorted)
@@ -4839,7 +7574,7 @@ fn normalize_semantics(value: &Value) -> Value {
         Value::Array(items) => {
             let mut by_key: BTreeMap<String, Value> = BTreeMap::new();
             for item in items {
-                let normalized = normalize_semantics(item);
+                let normalized = normalize_semantics_within_limit(item);
                 let key = serde_json::to_string(&normalized).expect("normalize semantics");
                 by_key.insert(key, normalized);
             }
@@ -4849,12 +7584,23 @@ fn normalize_semantics(value: &Value) -> Value {
     }
 }
 
-fn semantic_digest(value: &Value) -> String {
-    let normalized = normalize_semantics(value);
+fn semantic_digest(value: &Value, scheme: DigestScheme) -> String {
+    if exceeds_nesting_depth(value, SEMANTIC_NORMALIZE_MAX_DEPTH) {
+        note_semantic_depth_exceeded();
+        let raw = serde_json::to_string(value).expect("semantic digest serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(raw.as_bytes());
+        return format!(
+            "{}{:x}",
+            scheme.prefix(SEMANTIC_DIGEST_DEPTH_EXCEEDED_FAMILY),
+            hasher.finalize()
+        );
+    }
+    let normalized = normalize_semantics_within_limit(&sanitize_value_within_limit(value));
     let canonical = serde_json::to_string(&normalized).expect("semantic digest serialization");
     let mut hasher = Sha256::new();
     hasher.update(canonical.as_bytes());
-    format!("sem1_{:x}", hasher.finalize())
+    format!("{}{:x}", scheme.prefix("sem"), hasher.finalize())
 }
 
 fn square_witness_digest(
@@ -4864,6 +7610,7 @@ fn square_witness_digest(
     right: &str,
     result: &str,
     failure_classes: &[String],
+    scheme: DigestScheme,
 ) -> String {
     let core = json!({
         "top": top,
@@ -4876,7 +7623,55 @@ fn square_witness_digest(
     let canonical = serde_json::to_string(&core).expect("square witness digest serialization");
     let mut hasher = Sha256::new();
     hasher.update(canonical.as_bytes());
-    format!("sqw1_{:x}", hasher.finalize())
+    format!("{}{:x}", scheme.prefix("sqw"), hasher.finalize())
+}
+
+/// Below this many items, digests are computed on the calling thread.
+const PARALLEL_DIGEST_MIN_ITEMS: usize = 256;
+
+/// Compute one digest per item across scoped worker threads, preserving input
+/// order so callers can collect deterministically.
+fn parallel_digests<T: Sync>(items: &[T], digest: impl Fn(&T) -> String + Sync) -> Vec<String> {
+    let workers = std::thread::available_parallelism().map_or(1, |count| count.get());
+    if items.len() < PARALLEL_DIGEST_MIN_ITEMS {
+        return items.iter().map(digest).collect();
+    }
+    parallel_digests_with_workers(items, workers, digest)
+}
+
+fn parallel_digests_with_workers<T: Sync>(
+    items: &[T],
+    workers: usize,
+    digest: impl Fn(&T) -> String + Sync,
+) -> Vec<String> {
+    if workers < 2 || items.len() < 2 {
+        return items.iter().map(digest).collect();
+    }
+    let chunk_size = items.len().div_ceil(workers);
+    let digest = &digest;
+    let (digests, depth_exceeded) = std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    with_semantic_depth_tracking(|| chunk.iter().map(digest).collect::<Vec<_>>())
+                })
+            })
+            .collect();
+        let mut digests = Vec::with_capacity(items.len());
+        let mut depth_exceeded = false;
+        for handle in handles {
+            let (chunk_digests, exceeded) = handle.join().expect("digest worker panicked");
+            digests.extend(chunk_digests);
+            depth_exceeded |= exceeded;
+        }
+        (digests, depth_exceeded)
+    });
+    // Worker threads carry their own depth flag; fold it into the caller's.
+    if depth_exceeded {
+        note_semantic_depth_exceeded();
+    }
+    digests
 }
 
 fn composition_law_digest(
@@ -4886,19 +7681,20 @@ fn composition_law_digest(
     right: &Value,
     result: &str,
     failure_classes: &[String],
+    scheme: DigestScheme,
 ) -> String {
     let core = normalize_semantics(&json!({
         "kind": kind,
         "law": law,
-        "left": left,
-        "right": right,
+        "left": sanitize_value_for_digest(left),
+        "right": sanitize_value_for_digest(right),
         "result": result,
         "failureClasses": failure_classes,
     }));
     let canonical = serde_json::to_string(&core).expect("composition law digest serialization");
     let mut hasher = Sha256::new();
     hasher.update(canonical.as_bytes());
-    format!("sqlw1_{:x}", hasher.finalize())
+    format!("{}{:x}", scheme.prefix("sqlw"), hasher.finalize())
 }
 
 #[derive(Debug, Default)]
@@ -4913,6 +7709,28 @@ enum SquareExpressionValue {
     Grid(Vec<Vec<String>>),
 }
 
+/// Collect every `{"<key>": "<id>"}` leaf of a span/square law expression.
+fn collect_expression_refs(expression: &Value, key: &str, out: &mut BTreeSet<String>) {
+    match expression {
+        Value::Object(map) => {
+            for (field, value) in map {
+                match value.as_str() {
+                    Some(id) if field == key => {
+                        out.insert(id.trim().to_string());
+                    }
+                    _ => collect_expression_refs(value, key, out),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_expression_refs(item, key, out);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn evaluate_span_expression(
     expression: &Value,
     span_digests: &BTreeMap<String, String>,
@@ -5127,22 +7945,50 @@ fn require_non_empty_string_field(
     Ok(value.to_string())
 }
 
-fn require_string_array_field(
+fn require_bool_field(
     parent: &Map<String, Value>,
     key: &str,
     path: &Path,
     field_prefix: &str,
-) -> Result<Vec<String>, CoherenceError> {
-    let values = parent.get(key).and_then(Value::as_array).ok_or_else(|| {
+) -> Result<bool, CoherenceError> {
+    parent.get(key).and_then(Value::as_bool).ok_or_else(|| {
         CoherenceError::Contract(format!(
-            "{}: {field_prefix}.{key} must be an array of non-empty strings",
+            "{}: {field_prefix}.{key} must be a boolean",
             display_path(path)
         ))
-    })?;
-    let mut out = Vec::new();
-    for item in values {
-        let value = item
-            .as_str()
+    })
+}
+
+/// `None` when `key` is absent; a present non-boolean is an error.
+fn optional_bool_field(
+    parent: &Map<String, Value>,
+    key: &str,
+    path: &Path,
+    field_prefix: &str,
+) -> Result<Option<bool>, CoherenceError> {
+    if parent.contains_key(key) {
+        require_bool_field(parent, key, path, field_prefix).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn require_string_array_field(
+    parent: &Map<String, Value>,
+    key: &str,
+    path: &Path,
+    field_prefix: &str,
+) -> Result<Vec<String>, CoherenceError> {
+    let values = parent.get(key).and_then(Value::as_array).ok_or_else(|| {
+        CoherenceError::Contract(format!(
+            "{}: {field_prefix}.{key} must be an array of non-empty strings",
+            display_path(path)
+        ))
+    })?;
+    let mut out = Vec::new();
+    for item in values {
+        let value = item
+            .as_str()
             .map(str::trim)
             .filter(|v| !v.is_empty())
             .ok_or_else(|| {
@@ -5188,30 +8034,80 @@ fn optional_string_array_field(
     Ok(out)
 }
 
-fn validate_contract_obligation_set(contract_ids: &[String]) -> Vec<String> {
-    let mut failures = Vec::new();
-    let allowed: BTreeSet<String> = REQUIRED_OBLIGATION_IDS
-        .iter()
-        .map(|v| (*v).to_string())
-        .collect();
-    let mut seen = BTreeSet::new();
+/// Policy for [`validate_contract_obligation_set`]; the default is an exact match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValidateObligationSetOptions {
+    /// Do not report required obligations the contract leaves out.
+    pub allow_subset: bool,
+    /// Do not report ids outside `REQUIRED_OBLIGATION_IDS`.
+    pub allow_superset: bool,
+}
+
+/// Compare contract obligation ids against `REQUIRED_OBLIGATION_IDS` in one
+/// pass. Duplicates are reported for every id, known or not.
+pub fn validate_contract_obligation_set(
+    contract_ids: &[String],
+    options: ValidateObligationSetOptions,
+) -> Vec<String> {
+    let required: BTreeSet<&str> = REQUIRED_OBLIGATION_IDS.iter().copied().collect();
+    let mut seen: BTreeSet<&str> = BTreeSet::new();
+    let mut seen_required = 0usize;
+    let mut failures = BTreeSet::new();
     for obligation_id in contract_ids {
-        if !allowed.contains(obligation_id) {
-            failures.push("coherence.contract.unknown_obligation".to_string());
-            continue;
-        }
-        if !seen.insert(obligation_id.clone()) {
-            failures.push("coherence.contract.duplicate_obligation".to_string());
+        let obligation_id = obligation_id.as_str();
+        if !seen.insert(obligation_id) {
+            failures.insert("coherence.contract.duplicate_obligation");
+        } else if required.contains(obligation_id) {
+            seen_required += 1;
+        } else if !options.allow_superset {
+            failures.insert("coherence.contract.unknown_obligation");
         }
     }
-    for required in REQUIRED_OBLIGATION_IDS {
-        if !seen.contains(*required) {
-            failures.push("coherence.contract.missing_required_obligation".to_string());
+    if seen_required < required.len() && !options.allow_subset {
+        failures.insert("coherence.contract.missing_required_obligation");
+    }
+    failures.into_iter().map(str::to_string).collect()
+}
+
+/// Flag ids listed more than once with differing non-empty descriptions; the
+/// plain duplicate is already reported by `validate_contract_obligation_set`.
+fn validate_contract_obligation_descriptions(contract: &CoherenceContract) -> Vec<String> {
+    let mut descriptions: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for item in &contract.obligations {
+        let description = item.description.trim();
+        if !description.is_empty() {
+            descriptions
+                .entry(item.id.trim())
+                .or_default()
+                .insert(description);
         }
     }
-    dedupe_sorted(failures)
+    if descriptions.values().any(|distinct| distinct.len() > 1) {
+        vec!["coherence.contract.duplicate_obligation_description_conflict".to_string()]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Check that known ids in `obligations` follow `REQUIRED_OBLIGATION_IDS`
+/// order; unknown ids are left to `validate_contract_obligation_set`.
+fn validate_contract_obligation_order(obligations: &[CoherenceObligationSpec]) -> Vec<String> {
+    let ranks: Vec<usize> = obligations
+        .iter()
+        .filter_map(|item| {
+            REQUIRED_OBLIGATION_IDS
+                .iter()
+                .position(|required| *required == item.id.trim())
+        })
+        .collect();
+    if ranks.windows(2).any(|pair| pair[0] > pair[1]) {
+        vec!["coherence.contract.obligation_order".to_string()]
+    } else {
+        Vec::new()
+    }
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn validate_required_obligation_parity(
     declared: &BTreeSet<String>,
     required: &BTreeSet<String>,
@@ -5234,7 +8130,8 @@ fn validate_required_obligation_parity(
     dedupe_sorted(failures)
 }
 
-fn compute_doctrine_reachability(site: &DoctrineSite, root: &str) -> BTreeSet<String> {
+/// Nodes reachable from `root` (inclusive) along `edges` and `covers` alike.
+pub fn compute_doctrine_reachability(site: &DoctrineSite, root: &str) -> BTreeSet<String> {
     let mut adjacency: BTreeMap<String, Vec<String>> = BTreeMap::new();
     for edge in &site.edges {
         adjacency
@@ -5242,6 +8139,26 @@ fn compute_doctrine_reachability(site: &DoctrineSite, root: &str) -> BTreeSet<St
             .or_default()
             .push(edge.to.clone());
     }
+    add_cover_adjacency(site, &mut adjacency);
+    breadth_first_closure(&adjacency, root)
+}
+
+/// Parts `node` covers transitively, following `covers` only.
+///
+/// `node` itself is included only if some cover chain leads back to it.
+pub fn cover_closure(site: &DoctrineSite, node: &str) -> BTreeSet<String> {
+    let mut adjacency: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    add_cover_adjacency(site, &mut adjacency);
+    let mut closure = BTreeSet::new();
+    for part in adjacency.get(node).into_iter().flatten() {
+        if !closure.contains(part) {
+            closure.extend(breadth_first_closure(&adjacency, part));
+        }
+    }
+    closure
+}
+
+fn add_cover_adjacency(site: &DoctrineSite, adjacency: &mut BTreeMap<String, Vec<String>>) {
     for cover in &site.covers {
         for part in &cover.parts {
             adjacency
@@ -5250,7 +8167,12 @@ fn compute_doctrine_reachability(site: &DoctrineSite, root: &str) -> BTreeSet<St
                 .push(part.clone());
         }
     }
+}
 
+fn breadth_first_closure(
+    adjacency: &BTreeMap<String, Vec<String>>,
+    root: &str,
+) -> BTreeSet<String> {
     let mut visited: BTreeSet<String> = BTreeSet::new();
     let mut queue: VecDeque<String> = VecDeque::new();
     queue.push_back(root.to_string());
@@ -5268,11 +8190,18 @@ fn compute_doctrine_reachability(site: &DoctrineSite, root: &str) -> BTreeSet<St
     visited
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn parse_manifest_capabilities(root: &Path) -> Result<BTreeSet<String>, CoherenceError> {
     let mut out = BTreeSet::new();
-    let entries = fs::read_dir(root).map_err(|source| CoherenceError::ReadFile {
-        path: display_path(root),
-        source,
+    let mut listed = Vec::new();
+    let entries = fs::read_dir(root).map_err(|source| {
+        if source.kind() == std::io::ErrorKind::NotFound {
+            record_consulted_probe(root, CONSULTED_INPUT_ABSENT);
+        }
+        CoherenceError::ReadFile {
+            path: display_path(root),
+            source,
+        }
     })?;
     for entry in entries {
         let entry = entry.map_err(|source| CoherenceError::ReadFile {
@@ -5292,6 +8221,7 @@ fn parse_manifest_capabilities(root: &Path) -> Result<BTreeSet<String>, Coherenc
         if !name.starts_with("capabilities.") {
             continue;
         }
+        listed.push(name);
         let manifest_path = entry.path().join("manifest.json");
         let payload: Value =
             serde_json::from_slice(&read_bytes(&manifest_path)?).map_err(|source| {
@@ -5317,6 +8247,10 @@ fn parse_manifest_capabilities(root: &Path) -> Result<BTreeSet<String>, Coherenc
         }
         out.insert(capability.to_string());
     }
+    // The directory listing is an input too: adding or removing a capability
+    // directory must change `inputsDigest`.
+    listed.sort();
+    record_consulted_input(root, listed.join("\n").as_bytes());
     if out.is_empty() {
         return Err(CoherenceError::Contract(format!(
             "no capability manifests found under {}",
@@ -5326,6 +8260,36 @@ fn parse_manifest_capabilities(root: &Path) -> Result<BTreeSet<String>, Coherenc
     Ok(out)
 }
 
+/// README text between the capability section markers, or the whole README
+/// when no markers are configured. `None` when the start marker is configured
+/// but does not occur in `readme_text`.
+#[cfg(feature = "markdown-surfaces")]
+fn extract_capability_section(
+    readme_text: &str,
+    surfaces: &CoherenceSurfaces,
+) -> Result<Option<String>, CoherenceError> {
+    let start_marker = surfaces.capability_readme_section_start.as_str();
+    if start_marker.is_empty() {
+        return Ok(Some(readme_text.to_string()));
+    }
+    if !readme_text.contains(start_marker) {
+        return Ok(None);
+    }
+    if surfaces.capability_readme_section_end.is_empty() {
+        return Err(CoherenceError::Contract(
+            "surfaces.capabilityReadmeSectionEnd must be set with capabilityReadmeSectionStart"
+                .to_string(),
+        ));
+    }
+    extract_section_between(
+        readme_text,
+        start_marker,
+        surfaces.capability_readme_section_end.as_str(),
+    )
+    .map(|section| Some(section.to_string()))
+}
+
+#[cfg(feature = "markdown-surfaces")]
 fn parse_backticked_capabilities(text: &str) -> Result<BTreeSet<String>, CoherenceError> {
     let re = compile_regex(r"`(capabilities\.[a-z0-9_]+)`")?;
     Ok(re
@@ -5334,22 +8298,87 @@ fn parse_backticked_capabilities(text: &str) -> Result<BTreeSet<String>, Coheren
         .collect())
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn parse_backticked_profile_overlay_claims(text: &str) -> Result<BTreeSet<String>, CoherenceError> {
-    let re = compile_regex(r"`(profile\.[a-z0-9_.]+)`")?;
+    // Capture loosely so malformed claims surface as format failures instead of vanishing.
+    let re = compile_regex(r"`(profile\.[A-Za-z0-9_.]*)`")?;
     Ok(re
         .captures_iter(text)
         .filter_map(|caps| caps.get(1).map(|m| m.as_str().to_string()))
         .collect())
 }
 
-fn parse_backticked_tasks(text: &str) -> Result<BTreeSet<String>, CoherenceError> {
-    let re = compile_regex(r"`([a-z][a-z0-9-]*)`")?;
-    Ok(re
-        .captures_iter(text)
-        .filter_map(|caps| caps.get(1).map(|m| m.as_str().to_string()))
-        .collect())
+#[cfg(feature = "markdown-surfaces")]
+/// Profile claim ids are `profile.` followed by one or more dot-separated
+/// segments, each matching `[a-z][a-z0-9_]*`.
+fn validate_profile_claim_format(claim: &str) -> bool {
+    let Some(rest) = claim.strip_prefix("profile.") else {
+        return false;
+    };
+    rest.split('.').all(|segment| {
+        let mut chars = segment.chars();
+        matches!(chars.next(), Some(first) if first.is_ascii_lowercase())
+            && chars.all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_')
+    })
+}
+
+#[cfg(feature = "markdown-surfaces")]
+/// Overlay doc refs are `/`-separated segments, each starting with a letter
+/// followed by letters, digits, `_`, `.`, or `-`. Uppercase is accepted because
+/// existing overlay docs use uppercase file stems (`profile/ADJOINTS-AND-SITES`).
+fn validate_overlay_doc_ref(overlay_ref: &str) -> bool {
+    overlay_ref.split('/').all(|segment| {
+        let mut chars = segment.chars();
+        matches!(chars.next(), Some(first) if first.is_ascii_alphabetic())
+            && chars.all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '.' | '-'))
+    })
+}
+
+#[cfg(feature = "markdown-surfaces")]
+/// Overlay refs grouped by resolved filename (last segment + `.md`), keeping
+/// only filenames claimed by more than one ref.
+fn overlay_doc_filename_collisions(overlay_docs: &[String]) -> BTreeMap<String, Vec<String>> {
+    let mut by_filename: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for overlay_ref in overlay_docs {
+        let stem = overlay_ref.rsplit('/').next().unwrap_or_default();
+        by_filename
+            .entry(format!("{stem}.md"))
+            .or_default()
+            .push(overlay_ref.clone());
+    }
+    by_filename.retain(|_, refs| refs.len() > 1);
+    by_filename
+}
+
+/// Task ids match `[a-z][a-z0-9-]*`.
+fn is_task_id(token: &str) -> bool {
+    let mut chars = token.chars();
+    matches!(chars.next(), Some(first) if first.is_ascii_lowercase())
+        && chars.all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-')
+}
+
+/// Collect every backticked task id; hand-scanned so gate-chain parity does
+/// not depend on the `markdown-surfaces` feature.
+fn parse_backticked_tasks(text: &str) -> BTreeSet<String> {
+    let mut out = BTreeSet::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('`') {
+        let after = &rest[open + 1..];
+        let len = after
+            .find(|ch: char| !(ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-'))
+            .unwrap_or(after.len());
+        let token = &after[..len];
+        if is_task_id(token) && after[len..].starts_with('`') {
+            out.insert(token.to_string());
+            rest = &after[len + 1..];
+        } else {
+            rest = after;
+        }
+    }
+    out
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn parse_backtick_obligation_tokens(text: &str) -> Result<BTreeSet<String>, CoherenceError> {
     let re = compile_regex(r"`([a-z_]+)`")?;
     Ok(re
@@ -5385,25 +8414,25 @@ fn parse_baseline_task_ids_from_toml(
                 "[tasks.{task_name}] must have run = [\"...\"] list"
             ))
         })?;
-    let command_re = compile_regex(r"^mise run ([a-z][a-z0-9-]*)$")?;
     let mut out = Vec::new();
     for item in run {
         let command = item.as_str().ok_or_else(|| {
             CoherenceError::Contract(format!("[tasks.{task_name}] run entries must be strings"))
         })?;
-        let captured = command_re
-            .captures(command)
-            .and_then(|caps| caps.get(1))
+        let captured = command
+            .strip_prefix("mise run ")
+            .filter(|task_id| is_task_id(task_id))
             .ok_or_else(|| {
                 CoherenceError::Contract(format!(
                     "[tasks.{task_name}] unsupported command shape: {command:?}"
                 ))
             })?;
-        out.push(captured.as_str().to_string());
+        out.push(captured.to_string());
     }
     Ok(out)
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn parse_spec_index_capability_doc_map(
     section_54: &str,
 ) -> Result<BTreeMap<String, String>, CoherenceError> {
@@ -5425,12 +8454,13 @@ fn parse_spec_index_capability_doc_map(
     Ok(out)
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn contains_conditional_normative_clause(
     section_55: &str,
     doc_ref: &str,
     capability_id: &str,
 ) -> Result<bool, CoherenceError> {
-    let pattern = compile_regex(&format!(
+    let pattern = compile_regex_bounded(&format!(
         r#"(?is)`{}`.*?normative\s+only\s+when\s+`{}`\s+is\s+claimed"#,
         regex::escape(doc_ref),
         regex::escape(capability_id)
@@ -5454,8 +8484,10 @@ fn extract_section_between<'a>(
     Ok(&text[start..end])
 }
 
+#[cfg(feature = "markdown-surfaces")]
 fn extract_heading_section(text: &str, heading_prefix: &str) -> Result<String, CoherenceError> {
-    let heading_re = compile_regex(&format!(r"(?m)^### {}\b.*$", regex::escape(heading_prefix)))?;
+    let heading_re =
+        compile_regex_bounded(&format!(r"(?m)^### {}\b.*$", regex::escape(heading_prefix)))?;
     let heading_match = heading_re
         .find(text)
         .ok_or_else(|| CoherenceError::Contract(format!("missing heading: {heading_prefix:?}")))?;
@@ -5469,18 +8501,219 @@ fn extract_heading_section(text: &str, heading_prefix: &str) -> Result<String, C
     }
 }
 
-fn read_text(path: &Path) -> Result<String, CoherenceError> {
-    fs::read_to_string(path).map_err(|source| CoherenceError::ReadFile {
-        path: display_path(path),
-        source,
+thread_local! {
+    /// Surface files read while an obligation runs, keyed by path.
+    static CONSULTED_INPUTS: RefCell<Option<BTreeMap<PathBuf, String>>> =
+        const { RefCell::new(None) };
+}
+
+/// Run `check` while recording the content digest of every file it reads.
+fn with_consulted_inputs<T>(check: impl FnOnce() -> T) -> (T, BTreeMap<PathBuf, String>) {
+    let previous = CONSULTED_INPUTS.with(|inputs| inputs.replace(Some(BTreeMap::new())));
+    let out = check();
+    let recorded = CONSULTED_INPUTS.with(|inputs| inputs.replace(previous));
+    (out, recorded.unwrap_or_default())
+}
+
+thread_local! {
+    /// Whether semantic normalization hit `SEMANTIC_NORMALIZE_MAX_DEPTH`
+    /// while an obligation runs.
+    static SEMANTIC_DEPTH_EXCEEDED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Run `check`, reporting whether any value it normalized or digested
+/// exceeded `SEMANTIC_NORMALIZE_MAX_DEPTH`.
+fn with_semantic_depth_tracking<T>(check: impl FnOnce() -> T) -> (T, bool) {
+    let previous = SEMANTIC_DEPTH_EXCEEDED.with(|flag| flag.replace(false));
+    let out = check();
+    let exceeded = SEMANTIC_DEPTH_EXCEEDED.with(|flag| flag.replace(previous));
+    (out, exceeded)
+}
+
+fn note_semantic_depth_exceeded() {
+    SEMANTIC_DEPTH_EXCEEDED.with(|flag| flag.set(true));
+}
+
+/// Flag `details` and add a `semantic_depth_exceeded` warning for `obligation_id`.
+fn mark_semantic_depth_exceeded(details: &mut Value, obligation_id: &str) {
+    let warning = json!(format!("coherence.{obligation_id}.semantic_depth_exceeded"));
+    match details.get_mut("warnings").and_then(Value::as_array_mut) {
+        Some(warnings) if !warnings.contains(&warning) => warnings.push(warning),
+        Some(_) => {}
+        None => details["warnings"] = json!([warning]),
+    }
+    details["semanticDepthExceeded"] = json!(true);
+}
+
+thread_local! {
+    /// Extra attempts granted to surface reads that fail transiently.
+    static SURFACE_READ_RETRIES: Cell<u32> = const { Cell::new(DEFAULT_SURFACE_READ_RETRIES) };
+}
+
+/// Run `check` with `retries` (at most `MAX_SURFACE_READ_RETRIES`) extra
+/// attempts for transient surface reads, restoring the previous budget
+/// afterwards (also when `check` panics).
+fn with_surface_read_retries<T>(retries: u32, check: impl FnOnce() -> T) -> T {
+    struct Restore(u32);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SURFACE_READ_RETRIES.with(|active| active.set(self.0));
+        }
+    }
+    let _restore = Restore(
+        SURFACE_READ_RETRIES.with(|active| active.replace(retries.min(MAX_SURFACE_READ_RETRIES))),
+    );
+    check()
+}
+
+#[cfg(test)]
+type SurfaceReader = Box<dyn FnMut(&Path) -> std::io::Result<Vec<u8>>>;
+
+#[cfg(test)]
+thread_local! {
+    /// Test reader behind surface reads; `None` reads from the filesystem.
+    static SURFACE_READER: RefCell<Option<SurfaceReader>> = const { RefCell::new(None) };
+}
+
+/// Run `check` with surface reads served by `reader` instead of the
+/// filesystem, restoring the previous reader afterwards (also when `check`
+/// panics).
+#[cfg(test)]
+fn with_surface_reader<T>(
+    reader: impl FnMut(&Path) -> std::io::Result<Vec<u8>> + 'static,
+    check: impl FnOnce() -> T,
+) -> T {
+    struct Restore(Option<SurfaceReader>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SURFACE_READER.with(|active| *active.borrow_mut() = self.0.take());
+        }
+    }
+    let _restore = Restore(SURFACE_READER.with(|active| active.replace(Some(Box::new(reader)))));
+    check()
+}
+
+#[cfg(test)]
+fn read_surface(path: &Path) -> std::io::Result<Vec<u8>> {
+    SURFACE_READER.with(|active| match active.borrow_mut().as_mut() {
+        Some(reader) => reader(path),
+        None => fs::read(path),
     })
 }
 
+#[cfg(not(test))]
+fn read_surface(path: &Path) -> std::io::Result<Vec<u8>> {
+    fs::read(path)
+}
+
+/// Whether a read failure may clear up on its own, e.g. on a network mount.
+fn is_transient_read_error(kind: std::io::ErrorKind) -> bool {
+    matches!(
+        kind,
+        std::io::ErrorKind::Interrupted
+            | std::io::ErrorKind::WouldBlock
+            | std::io::ErrorKind::TimedOut
+    )
+}
+
+/// Call `read`, retrying transient failures up to the active retry budget
+/// with a linear backoff. Any other error, such as `NotFound`, is returned
+/// from the first attempt.
+fn read_with_retry<T>(
+    path: &Path,
+    mut read: impl FnMut(&Path) -> std::io::Result<T>,
+) -> Result<T, CoherenceError> {
+    let budget = SURFACE_READ_RETRIES.with(Cell::get);
+    let mut attempt = 0;
+    loop {
+        match read(path) {
+            Ok(out) => return Ok(out),
+            Err(source) if attempt < budget && is_transient_read_error(source.kind()) => {
+                attempt += 1;
+                std::thread::sleep(SURFACE_READ_RETRY_BACKOFF * attempt);
+            }
+            Err(source) => {
+                if source.kind() == std::io::ErrorKind::NotFound {
+                    record_consulted_probe(path, CONSULTED_INPUT_ABSENT);
+                }
+                return Err(CoherenceError::ReadFile {
+                    path: display_path(path),
+                    source,
+                });
+            }
+        }
+    }
+}
+
+fn record_consulted_input(path: &Path, bytes: &[u8]) {
+    CONSULTED_INPUTS.with(|inputs| {
+        if let Some(recorded) = inputs.borrow_mut().as_mut() {
+            recorded.insert(path.to_path_buf(), format!("{:x}", Sha256::digest(bytes)));
+        }
+    });
+}
+
+/// Recorded for a probed path that does not exist.
+const CONSULTED_INPUT_ABSENT: &str = "absent";
+/// Recorded for a path whose existence was probed but whose bytes were not read.
+const CONSULTED_INPUT_PRESENT: &str = "present";
+
+/// Record the outcome of an existence probe; a content digest already
+/// recorded for `path` wins.
+fn record_consulted_probe(path: &Path, marker: &str) {
+    CONSULTED_INPUTS.with(|inputs| {
+        if let Some(recorded) = inputs.borrow_mut().as_mut() {
+            recorded
+                .entry(path.to_path_buf())
+                .or_insert_with(|| marker.to_string());
+        }
+    });
+}
+
+/// `path.exists()` for obligation checks, recorded as a consulted input so a
+/// file appearing or disappearing changes `inputsDigest`.
+fn probe_path_exists(path: &Path) -> bool {
+    let exists = path.exists();
+    record_consulted_probe(
+        path,
+        if exists {
+            CONSULTED_INPUT_PRESENT
+        } else {
+            CONSULTED_INPUT_ABSENT
+        },
+    );
+    exists
+}
+
+fn inputs_digest(
+    repo_root: &Path,
+    consulted_inputs: &BTreeMap<PathBuf, String>,
+    scheme: DigestScheme,
+) -> String {
+    let pairs: BTreeMap<String, &String> = consulted_inputs
+        .iter()
+        .map(|(path, digest)| (to_repo_relative_or_absolute(repo_root, path), digest))
+        .collect();
+    let pairs: Vec<(String, &String)> = pairs.into_iter().collect();
+    let canonical = serde_json::to_string(&pairs).expect("inputs digest serialization");
+    let mut hasher = Sha256::new();
+    hasher.update(canonical.as_bytes());
+    format!("{}{:x}", scheme.prefix("inputs"), hasher.finalize())
+}
+
+fn read_text(path: &Path) -> Result<String, CoherenceError> {
+    let text = read_with_retry(path, |path| {
+        String::from_utf8(read_surface(path)?)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    })?;
+    record_consulted_input(path, text.as_bytes());
+    Ok(text)
+}
+
 fn read_bytes(path: &Path) -> Result<Vec<u8>, CoherenceError> {
-    fs::read(path).map_err(|source| CoherenceError::ReadFile {
-        path: display_path(path),
-        source,
-    })
+    let bytes = read_with_retry(path, read_surface)?;
+    record_consulted_input(path, &bytes);
+    Ok(bytes)
 }
 
 fn read_json_value(path: &Path) -> Result<Value, CoherenceError> {
@@ -5490,12 +8723,42 @@ fn read_json_value(path: &Path) -> Result<Value, CoherenceError> {
     })
 }
 
+#[cfg(feature = "markdown-surfaces")]
+fn contains_informative_clause(
+    section: &str,
+    surfaces: &CoherenceSurfaces,
+) -> Result<bool, CoherenceError> {
+    match surfaces.informative_clause_regex.as_deref() {
+        Some(pattern) => Ok(compile_regex_bounded(pattern)?.is_match(section)),
+        None => Ok(section.contains(surfaces.informative_clause_needle.as_str())),
+    }
+}
+
+#[cfg(feature = "markdown-surfaces")]
 fn compile_regex(pattern: &str) -> Result<Regex, CoherenceError> {
     Regex::new(pattern).map_err(|source| {
         CoherenceError::Contract(format!("invalid regex pattern {pattern:?}: {source}"))
     })
 }
 
+/// Compiled-program and lazy-DFA budget for patterns built from contract
+/// input, well under the `regex` defaults.
+#[cfg(feature = "markdown-surfaces")]
+const CONTRACT_REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+/// Like [`compile_regex`], for patterns built from contract input: rejects
+/// patterns whose compiled form exceeds [`CONTRACT_REGEX_SIZE_LIMIT`].
+#[cfg(feature = "markdown-surfaces")]
+fn compile_regex_bounded(pattern: &str) -> Result<Regex, CoherenceError> {
+    RegexBuilder::new(pattern)
+        .size_limit(CONTRACT_REGEX_SIZE_LIMIT)
+        .dfa_size_limit(CONTRACT_REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|source| {
+            CoherenceError::Contract(format!("invalid regex pattern {pattern:?}: {source}"))
+        })
+}
+
 fn resolve_path(root: &Path, path: impl AsRef<Path>) -> PathBuf {
     let path = path.as_ref();
     if path.is_absolute() {
@@ -5599,13 +8862,47 @@ fn validate_invariance_groups(
                 .collect::<Vec<Value>>(),
         }));
     }
+    for (prefix_id, extended_id) in detect_scenario_id_namespace_collisions(invariance_groups) {
+        failures.push(format!(
+            "{failure_prefix}.invariance_scenario_id_namespace_collision"
+        ));
+        invariance_rows.push(json!({
+            "semanticScenarioId": extended_id,
+            "namespaceCollisionWith": prefix_id,
+        }));
+    }
     invariance_rows
 }
 
+/// Pairs `(prefix, extended)` of scenario IDs where `prefix` is a proper
+/// dot-segment prefix of `extended` (`foo.bar` / `foo.bar.baz`).
+fn detect_scenario_id_namespace_collisions(groups: &InvarianceGroups) -> Vec<(String, String)> {
+    let mut collisions = Vec::new();
+    for scenario_id in groups.keys() {
+        let namespace = format!("{scenario_id}.");
+        for extended_id in groups
+            .range::<String, _>(&namespace..)
+            .map(|(id, _)| id)
+            .take_while(|id| id.starts_with(&namespace))
+        {
+            collisions.push((scenario_id.clone(), extended_id.clone()));
+        }
+    }
+    collisions
+}
+
 fn sorted_vec_from_set(values: &BTreeSet<String>) -> Vec<String> {
     values.iter().cloned().collect()
 }
 
+fn part_multiplicities(values: &[String]) -> BTreeMap<&str, usize> {
+    let mut counts = BTreeMap::new();
+    for value in values {
+        *counts.entry(value.as_str()).or_insert(0) += 1;
+    }
+    counts
+}
+
 fn has_duplicates(values: &[String]) -> bool {
     let set: BTreeSet<String> = values.iter().cloned().collect();
     set.len() != values.len()
@@ -5625,12 +8922,53 @@ mod tests {
     use std::path::{Path, PathBuf};
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    struct TempDirGuard {
+    fn execute_obligation(
+        obligation_id: &str,
+        repo_root: &Path,
+        contract: &CoherenceContract,
+    ) -> ObligationCheck {
+        run_obligation(obligation_id, repo_root, contract)
+            .unwrap_or_else(|err| surface_error_check(obligation_id, &err))
+    }
+
+    pub(crate) fn repo_root() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .and_then(|path| path.parent())
+            .expect("repo root should resolve")
+            .to_path_buf()
+    }
+
+    fn real_contract_payload() -> Value {
+        serde_json::from_slice(
+            &fs::read(repo_root().join("specs/premath/draft/COHERENCE-CONTRACT.json"))
+                .expect("contract should be readable"),
+        )
+        .expect("contract should parse")
+    }
+
+    /// The repo's own coherence contract.
+    pub(crate) fn real_contract() -> CoherenceContract {
+        serde_json::from_value(real_contract_payload()).expect("contract should deserialize")
+    }
+
+    /// Write the repo's coherence contract, after `edit`, into `temp`; returns
+    /// the copy's path and the payload written.
+    #[cfg(feature = "markdown-surfaces")]
+    fn copy_real_contract(temp: &TempDirGuard, edit: impl FnOnce(&mut Value)) -> (PathBuf, Value) {
+        let mut payload = real_contract_payload();
+        edit(&mut payload);
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        write_json_file(&contract_path, &payload);
+        (contract_path, payload)
+    }
+
+    pub(crate) struct TempDirGuard {
         path: PathBuf,
     }
 
     impl TempDirGuard {
-        fn new(prefix: &str) -> Self {
+        pub(crate) fn new(prefix: &str) -> Self {
             let mut path = std::env::temp_dir();
             let nonce = SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -5644,7 +8982,7 @@ mod tests {
             Self { path }
         }
 
-        fn path(&self) -> &Path {
+        pub(crate) fn path(&self) -> &Path {
             &self.path
         }
     }
@@ -5655,7 +8993,7 @@ mod tests {
         }
     }
 
-    fn write_json_file(path: &Path, payload: &Value) {
+    pub(crate) fn write_json_file(path: &Path, payload: &Value) {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent).expect("parent directories should be creatable");
         }
@@ -5980,14 +9318,23 @@ Current deterministic projected check IDs include:
         contract
     }
 
-    fn write_operation_reachability_surfaces(
-        root: &Path,
-        operation_morphisms: &[&str],
-        required_morphisms: &[&str],
-    ) {
+    #[cfg(feature = "markdown-surfaces")]
+    fn write_overlay_traceability_surfaces(root: &Path, overlays: &[&str]) {
+        let mut spec_index = String::from("### 5.6 Overlays\n\n");
+        let mut profile_readme = String::from("# Profiles\n\n");
+        for overlay in overlays {
+            write_text_file(
+                &root.join(format!("specs/premath/{overlay}.md")),
+                "# Overlay\n",
+            );
+            spec_index.push_str(&format!("- `{overlay}`\n"));
+            let file = overlay.rsplit('/').next().expect("overlay file name");
+            profile_readme.push_str(&format!("- {file}.md\n"));
+        }
+        write_text_file(&root.join("specs/premath/draft/SPEC-INDEX.md"), &spec_index);
         write_text_file(
-            &root.join("tools/ci/run_gate.sh"),
-            "#!/usr/bin/env sh\nexit 0\n",
+            &root.join("specs/premath/profile/README.md"),
+            &profile_readme,
         );
         write_json_file(
             &root.join("specs/premath/draft/DOCTRINE-SITE.json"),
@@ -5999,57 +9346,326 @@ Current deterministic projected check IDs include:
                         "kind": "doctrine"
                     },
                     {
-                        "id": "op/ci.run_gate",
-                        "path": "tools/ci/run_gate.sh",
-                        "kind": "operation"
+                        "id": "profile/LINKED",
+                        "path": "specs/premath/profile/LINKED.md",
+                        "kind": "profile"
+                    },
+                    {
+                        "id": "profile/ORPHANED",
+                        "path": "specs/premath/profile/ORPHANED.md",
+                        "kind": "profile"
                     }
                 ],
                 "covers": [],
                 "edges": [
                     {
                         "from": "draft/DOCTRINE-INF",
-                        "to": "op/ci.run_gate"
-                    }
-                ]
-            }),
-        );
-        write_json_file(
-            &root.join("specs/premath/draft/DOCTRINE-SITE-INPUT.json"),
-            &json!({
-                "schema": 1,
-                "inputKind": "premath.doctrine_operation_site.input.v1",
-                "worldRouteBindings": {
-                    "schema": 1,
-                    "bindingKind": "premath.world_route_bindings.v1",
-                    "rows": [
-                        {
-                            "routeFamilyId": "route.gate_execution",
-                            "operationIds": ["op/ci.run_gate"],
-                            "worldId": "world.kernel.semantic.v1",
-                            "morphismRowId": "wm.kernel.semantic.runtime_gate",
-                            "requiredMorphisms": required_morphisms,
-                            "failureClassUnbound": "world_route_unbound"
-                        }
-                    ]
-                }
-            }),
-        );
-        write_json_file(
-            &root.join("specs/premath/draft/DOCTRINE-OP-REGISTRY.json"),
-            &json!({
-                "schema": 1,
-                "registryKind": "premath.doctrine_operation_registry.v1",
-                "operations": [
-                    {
-                        "id": "op/ci.run_gate",
-                        "morphisms": operation_morphisms
+                        "to": "profile/LINKED"
                     }
                 ]
             }),
         );
     }
 
-    fn write_transport_manifest(fixture_root: &Path, vectors: &[&str]) {
+    #[cfg(feature = "markdown-surfaces")]
+    fn test_contract_for_overlay_traceability(overlays: &[&str]) -> CoherenceContract {
+        let mut contract = test_contract_with_fixture_roots("", "");
+        contract.surfaces.spec_index_path = "specs/premath/draft/SPEC-INDEX.md".to_string();
+        contract.surfaces.spec_index_overlay_heading = "5.6".to_string();
+        contract.surfaces.profile_readme_path = "specs/premath/profile/README.md".to_string();
+        contract.surfaces.doctrine_site_path = "specs/premath/draft/DOCTRINE-SITE.json".to_string();
+        contract.surfaces.doctrine_root_node_id = "draft/DOCTRINE-INF".to_string();
+        contract.overlay_docs = overlays.iter().map(|overlay| overlay.to_string()).collect();
+        contract
+    }
+
+    #[test]
+    fn read_with_retry_recovers_from_transient_errors_only() {
+        let path = Path::new("surfaces/flaky.json");
+        let flaky_reader = |kind: std::io::ErrorKind| {
+            let mut attempts = 0;
+            move |_: &Path| {
+                attempts += 1;
+                if attempts == 1 {
+                    Err(std::io::Error::from(kind))
+                } else {
+                    Ok(attempts)
+                }
+            }
+        };
+
+        let attempts = read_with_retry(path, flaky_reader(std::io::ErrorKind::Interrupted))
+            .expect("interrupted read should be retried");
+        assert_eq!(attempts, 2);
+
+        let err = with_surface_read_retries(0, || {
+            read_with_retry(path, flaky_reader(std::io::ErrorKind::Interrupted))
+        })
+        .expect_err("zero retries should surface the interrupted read");
+        assert!(matches!(
+            err,
+            CoherenceError::ReadFile { ref source, .. }
+                if source.kind() == std::io::ErrorKind::Interrupted
+        ));
+
+        let err = read_with_retry(path, flaky_reader(std::io::ErrorKind::NotFound))
+            .expect_err("missing files should not be retried");
+        assert!(matches!(
+            err,
+            CoherenceError::ReadFile { ref source, .. }
+                if source.kind() == std::io::ErrorKind::NotFound
+        ));
+
+        let budget = with_surface_read_retries(u32::MAX, || SURFACE_READ_RETRIES.with(Cell::get));
+        assert_eq!(budget, MAX_SURFACE_READ_RETRIES);
+        let panicked = std::panic::catch_unwind(|| {
+            with_surface_read_retries(0, || panic!("check panicked"));
+        });
+        assert!(panicked.is_err());
+        assert_eq!(
+            SURFACE_READ_RETRIES.with(Cell::get),
+            DEFAULT_SURFACE_READ_RETRIES
+        );
+    }
+
+    /// Reader stub that fails the first `failures` reads of `flaky` with
+    /// `Interrupted`, counting its attempts, and reads every other path from disk.
+    fn interrupted_reader(
+        flaky: PathBuf,
+        failures: u32,
+    ) -> (
+        impl FnMut(&Path) -> std::io::Result<Vec<u8>>,
+        std::rc::Rc<Cell<u32>>,
+    ) {
+        let attempts = std::rc::Rc::new(Cell::new(0));
+        let counted = attempts.clone();
+        let reader = move |path: &Path| {
+            if path == flaky {
+                counted.set(counted.get() + 1);
+                if counted.get() <= failures {
+                    return Err(std::io::Error::from(std::io::ErrorKind::Interrupted));
+                }
+            }
+            fs::read(path)
+        };
+        (reader, attempts)
+    }
+
+    #[test]
+    fn obligation_passes_after_a_transient_surface_read_error() {
+        let temp = TempDirGuard::new("operation-reachability-interrupted-read");
+        write_operation_reachability_surfaces(temp.path(), &["dm.identity"], &["dm.identity"]);
+        let contract = test_contract_for_operation_reachability();
+        let site_path = temp.path().join(&contract.surfaces.doctrine_site_path);
+
+        let (reader, attempts) = interrupted_reader(site_path.clone(), 1);
+        let checked = with_surface_reader(reader, || {
+            run_obligation("operation_reachability", temp.path(), &contract)
+        })
+        .expect("interrupted read should be retried");
+        assert!(checked.failure_classes.is_empty(), "{:?}", checked.details);
+        assert_eq!(attempts.get(), 2);
+
+        let (reader, _) = interrupted_reader(site_path, 1);
+        let err = with_surface_reader(reader, || {
+            with_surface_read_retries(0, || {
+                run_obligation("operation_reachability", temp.path(), &contract)
+            })
+        })
+        .expect_err("zero retries should surface the interrupted read");
+        assert!(matches!(
+            err,
+            CoherenceError::ReadFile { ref source, .. }
+                if source.kind() == std::io::ErrorKind::Interrupted
+        ));
+    }
+
+    #[test]
+    fn contract_surface_read_retries_reach_surface_reads() {
+        let temp = TempDirGuard::new("surface-read-retries");
+        write_operation_reachability_surfaces(temp.path(), &["dm.identity"], &["dm.identity"]);
+        let contract = test_contract_for_operation_reachability();
+        let site_path = temp.path().join(&contract.surfaces.doctrine_site_path);
+        let mut payload = serde_json::to_value(&contract).expect("contract should serialize");
+        assert!(payload.get("surfaceReadRetries").is_none());
+        payload["obligations"] = json!([]);
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        let attempts = |payload: &Value| {
+            write_json_file(&contract_path, payload);
+            let (reader, attempts) = interrupted_reader(site_path.clone(), u32::MAX);
+            let witness = with_surface_reader(reader, || {
+                run_coherence_check(temp.path(), &contract_path)
+                    .expect("coherence check should run")
+            });
+            assert!(
+                witness
+                    .failure_classes
+                    .contains(&"coherence.operation_reachability.surface_error".to_string())
+            );
+            attempts.get()
+        };
+
+        assert_eq!(attempts(&payload), DEFAULT_SURFACE_READ_RETRIES + 1);
+        payload["surfaceReadRetries"] = json!(5);
+        assert_eq!(attempts(&payload), 6);
+        payload["surfaceReadRetries"] = json!(1000);
+        assert_eq!(attempts(&payload), MAX_SURFACE_READ_RETRIES + 1);
+        assert_eq!(
+            SURFACE_READ_RETRIES.with(Cell::get),
+            DEFAULT_SURFACE_READ_RETRIES
+        );
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn inputs_digest_tracks_only_the_obligation_that_read_an_edited_file() {
+        let temp = TempDirGuard::new("obligation-inputs-digest");
+        let overlays = ["profile/LINKED"];
+        write_overlay_traceability_surfaces(temp.path(), &overlays);
+        write_operation_reachability_surfaces(temp.path(), &["dm.identity"], &["dm.identity"]);
+        let mut contract = test_contract_for_operation_reachability();
+        contract.surfaces.spec_index_path = "specs/premath/draft/SPEC-INDEX.md".to_string();
+        contract.surfaces.spec_index_overlay_heading = "5.6".to_string();
+        contract.surfaces.profile_readme_path = "specs/premath/profile/README.md".to_string();
+        contract.overlay_docs = overlays.iter().map(|overlay| overlay.to_string()).collect();
+
+        let digests = |contract: &CoherenceContract| {
+            ["overlay_traceability", "operation_reachability"].map(|obligation_id| {
+                let (_, consulted) = with_consulted_inputs(|| {
+                    execute_obligation(obligation_id, temp.path(), contract)
+                });
+                inputs_digest(temp.path(), &consulted, DigestScheme::default())
+            })
+        };
+        let [overlay_before, operation_before] = digests(&contract);
+        assert!(overlay_before.starts_with("inputs1_"));
+        assert_eq!(
+            digests(&contract),
+            [overlay_before.clone(), operation_before.clone()]
+        );
+
+        write_text_file(
+            &temp.path().join("specs/premath/profile/README.md"),
+            "# Profiles\n\n- LINKED.md (edited)\n",
+        );
+        let [overlay_after, operation_after] = digests(&contract);
+        assert_ne!(overlay_after, overlay_before);
+        assert_eq!(operation_after, operation_before);
+
+        // Existence probes count as inputs: removing a probed overlay file
+        // changes the digest even though its bytes were never read.
+        fs::remove_file(temp.path().join("specs/premath/profile/LINKED.md"))
+            .expect("overlay file should be removable");
+        let (_, consulted) = with_consulted_inputs(|| {
+            execute_obligation("overlay_traceability", temp.path(), &contract)
+        });
+        assert_eq!(
+            consulted
+                .get(&temp.path().join("specs/premath/profile/LINKED.md"))
+                .map(String::as_str),
+            Some(CONSULTED_INPUT_ABSENT)
+        );
+        let [overlay_removed, operation_removed] = digests(&contract);
+        assert_ne!(overlay_removed, overlay_after);
+        assert_eq!(operation_removed, operation_after);
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn check_overlay_traceability_flags_overlay_unreachable_in_doctrine() {
+        let temp = TempDirGuard::new("overlay-doctrine-reachability");
+        let overlays = ["profile/LINKED", "profile/ORPHANED", "profile/ABSENT"];
+        write_overlay_traceability_surfaces(temp.path(), &overlays);
+        let mut contract = test_contract_for_overlay_traceability(&overlays);
+
+        let unchecked = check_overlay_traceability(temp.path(), &contract)
+            .expect("overlay traceability should evaluate");
+        assert!(unchecked.failure_classes.is_empty());
+        assert_eq!(unchecked.details["doctrineReachability"], Value::Null);
+
+        contract.enforce_overlay_doctrine_reachability = true;
+        let checked = check_overlay_traceability(temp.path(), &contract)
+            .expect("overlay traceability should evaluate");
+        assert_eq!(
+            checked.failure_classes,
+            vec!["coherence.overlay_traceability.overlay_unreachable_in_doctrine".to_string()]
+        );
+        assert_eq!(
+            checked.details["doctrineReachability"]["unreachableOverlays"],
+            json!(["profile/ORPHANED", "profile/ABSENT"])
+        );
+    }
+
+    fn write_operation_reachability_surfaces(
+        root: &Path,
+        operation_morphisms: &[&str],
+        required_morphisms: &[&str],
+    ) {
+        write_text_file(
+            &root.join("tools/ci/run_gate.sh"),
+            "#!/usr/bin/env sh\nexit 0\n",
+        );
+        write_json_file(
+            &root.join("specs/premath/draft/DOCTRINE-SITE.json"),
+            &json!({
+                "nodes": [
+                    {
+                        "id": "draft/DOCTRINE-INF",
+                        "path": "specs/premath/draft/DOCTRINE-INF.md",
+                        "kind": "doctrine"
+                    },
+                    {
+                        "id": "op/ci.run_gate",
+                        "path": "tools/ci/run_gate.sh",
+                        "kind": "operation"
+                    }
+                ],
+                "covers": [],
+                "edges": [
+                    {
+                        "from": "draft/DOCTRINE-INF",
+                        "to": "op/ci.run_gate"
+                    }
+                ]
+            }),
+        );
+        write_json_file(
+            &root.join("specs/premath/draft/DOCTRINE-SITE-INPUT.json"),
+            &json!({
+                "schema": 1,
+                "inputKind": "premath.doctrine_operation_site.input.v1",
+                "worldRouteBindings": {
+                    "schema": 1,
+                    "bindingKind": "premath.world_route_bindings.v1",
+                    "rows": [
+                        {
+                            "routeFamilyId": "route.gate_execution",
+                            "operationIds": ["op/ci.run_gate"],
+                            "worldId": "world.kernel.semantic.v1",
+                            "morphismRowId": "wm.kernel.semantic.runtime_gate",
+                            "requiredMorphisms": required_morphisms,
+                            "failureClassUnbound": "world_route_unbound"
+                        }
+                    ]
+                }
+            }),
+        );
+        write_json_file(
+            &root.join("specs/premath/draft/DOCTRINE-OP-REGISTRY.json"),
+            &json!({
+                "schema": 1,
+                "registryKind": "premath.doctrine_operation_registry.v1",
+                "operations": [
+                    {
+                        "id": "op/ci.run_gate",
+                        "morphisms": operation_morphisms
+                    }
+                ]
+            }),
+        );
+    }
+
+    fn write_transport_manifest(fixture_root: &Path, vectors: &[&str]) {
         write_json_file(
             &fixture_root.join("manifest.json"),
             &json!({
@@ -6191,7 +9807,15 @@ Current deterministic projected check IDs include:
             (
                 "accepted",
                 failures.clone(),
-                square_witness_digest("top", "bottom", "left", "right", "accepted", &failures),
+                square_witness_digest(
+                    "top",
+                    "bottom",
+                    "left",
+                    "right",
+                    "accepted",
+                    &failures,
+                    DigestScheme::default(),
+                ),
             )
         };
         json!({
@@ -6213,6 +9837,14 @@ Current deterministic projected check IDs include:
         })
     }
 
+    fn evaluate_span_square_without_registry(
+        artifacts_payload: &Value,
+        case_path: &Path,
+        scheme: DigestScheme,
+    ) -> Result<SiteEvaluation, CoherenceError> {
+        evaluate_site_case_span_square_commutation(artifacts_payload, case_path, None, scheme)
+    }
+
     fn write_site_vector_with_metadata(
         fixture_root: &Path,
         vector_id: &str,
@@ -6291,9 +9923,12 @@ Current deterministic projected check IDs include:
                 capability_registry_path: String::new(),
                 capability_registry_kind: String::new(),
                 conformance_path: String::new(),
+                conformance_capability_heading: String::new(),
                 capability_manifest_root: String::new(),
                 readme_path: String::new(),
                 conformance_readme_path: String::new(),
+                capability_readme_section_start: String::new(),
+                capability_readme_section_end: String::new(),
                 spec_index_path: String::new(),
                 spec_index_capability_heading: String::new(),
                 spec_index_informative_heading: String::new(),
@@ -6319,6 +9954,7 @@ Current deterministic projected check IDs include:
                 coherence_spec_obligation_end: String::new(),
                 obligation_registry_kind: String::new(),
                 informative_clause_needle: String::new(),
+                informative_clause_regex: None,
                 transport_fixture_root_path: transport_fixture_root_path.to_string(),
                 site_fixture_root_path: site_fixture_root_path.to_string(),
             },
@@ -6334,6 +9970,16 @@ Current deterministic projected check IDs include:
                 "ext_gap".to_string(),
                 "ext_ambiguous".to_string(),
             ],
+            enforce_obligation_order: false,
+            enforce_overlay_doctrine_reachability: false,
+            require_sorted_profile_claims: false,
+            verbose_reasons: false,
+            tolerate_surface_errors: Vec::new(),
+            polarity_prefixes: PolarityPrefixes::default(),
+            control_plane_contract_kind: DEFAULT_CONTROL_PLANE_CONTRACT_KIND.to_string(),
+            surface_read_retries: DEFAULT_SURFACE_READ_RETRIES,
+            inline_capability_registry: None,
+            digest_scheme: DigestScheme::default(),
         }
     }
 
@@ -6347,6 +9993,206 @@ Current deterministic projected check IDs include:
         test_contract_with_fixture_roots("", site_fixture_root_path)
     }
 
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn validate_profile_claim_format_accepts_well_formed_claims() {
+        assert!(validate_profile_claim_format("profile.doctrine_inf"));
+        assert!(validate_profile_claim_format("profile.overlay.interop_v2"));
+        assert!(validate_profile_claim_format("profile.a1"));
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn validate_profile_claim_format_rejects_malformed_claims() {
+        for claim in [
+            "profile.",
+            "profile..overlay",
+            "profile.overlay.",
+            "profile.overlay..core",
+            "profile.Overlay",
+            "profile.overlay.Core",
+            "profile._overlay",
+            "profile.1overlay",
+            "profiles.overlay",
+            "overlay",
+        ] {
+            assert!(
+                !validate_profile_claim_format(claim),
+                "claim should be rejected: {claim}"
+            );
+        }
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn validate_overlay_doc_ref_checks_segment_format() {
+        for overlay_ref in [
+            "profile/ADJOINTS-AND-SITES",
+            "draft/overlay.foo.bar",
+            "raw/span_square-v2",
+            "overlay",
+        ] {
+            assert!(
+                validate_overlay_doc_ref(overlay_ref),
+                "ref should be accepted: {overlay_ref}"
+            );
+        }
+        for overlay_ref in [
+            "",
+            "/draft/overlay",
+            "draft//overlay",
+            "draft/overlay/",
+            "draft/1overlay",
+            "draft/_overlay",
+            "draft/overlay foo",
+            "../draft/overlay",
+        ] {
+            assert!(
+                !validate_overlay_doc_ref(overlay_ref),
+                "ref should be rejected: {overlay_ref}"
+            );
+        }
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn overlay_doc_filename_collisions_groups_refs_by_last_segment() {
+        let overlay_docs = vec![
+            "draft/overlay.foo".to_string(),
+            "profile/ADJOINTS-AND-SITES".to_string(),
+            "raw/overlay.foo".to_string(),
+        ];
+        assert_eq!(
+            overlay_doc_filename_collisions(&overlay_docs),
+            BTreeMap::from([(
+                "overlay.foo.md".to_string(),
+                vec![
+                    "draft/overlay.foo".to_string(),
+                    "raw/overlay.foo".to_string()
+                ]
+            )])
+        );
+        assert!(overlay_doc_filename_collisions(&overlay_docs[..2]).is_empty());
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn parse_backticked_profile_overlay_claims_keeps_malformed_claims() {
+        let claims = parse_backticked_profile_overlay_claims(
+            "- `profile.overlay.core`\n- `profile.Overlay`\n- `profile..core`\n",
+        )
+        .expect("claims should parse");
+        let invalid: Vec<&String> = claims
+            .iter()
+            .filter(|claim| !validate_profile_claim_format(claim))
+            .collect();
+        assert_eq!(invalid, vec!["profile..core", "profile.Overlay"]);
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn contains_informative_clause_regex_tolerates_line_breaks() {
+        let section = "Overlays are informative unless they are\n  explicitly claimed.";
+        let mut contract = test_contract_with_fixture_roots("", "");
+        contract.surfaces.informative_clause_needle =
+            "unless they are explicitly claimed".to_string();
+        assert!(
+            !contains_informative_clause(section, &contract.surfaces)
+                .expect("substring check should evaluate")
+        );
+
+        contract.surfaces.informative_clause_regex =
+            Some(r"unless\s+they\s+are\s+explicitly\s+claimed".to_string());
+        assert!(
+            contains_informative_clause(section, &contract.surfaces)
+                .expect("regex check should evaluate")
+        );
+
+        contract.surfaces.informative_clause_regex = Some("(".to_string());
+        assert!(contains_informative_clause(section, &contract.surfaces).is_err());
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn compile_regex_bounded_rejects_oversized_patterns() {
+        assert!(compile_regex_bounded(r"unless\s+they\s+are\s+explicitly\s+claimed").is_ok());
+        let pathological = r"(?:\w{100}){100}";
+        match compile_regex_bounded(pathological) {
+            Err(CoherenceError::Contract(message)) => assert!(message.contains("size limit")),
+            other => panic!("expected Contract error, got {other:?}"),
+        }
+
+        let mut contract = test_contract_with_fixture_roots("", "");
+        contract.surfaces.informative_clause_regex = Some(pathological.to_string());
+        assert!(contains_informative_clause("informative", &contract.surfaces).is_err());
+    }
+
+    #[test]
+    fn obligation_witness_assert_classes_reports_missing_and_extra() {
+        let witness = ObligationWitness {
+            obligation_id: "gate_chain_parity".to_string(),
+            result: "rejected".to_string(),
+            failure_classes: vec![
+                "coherence.gate_chain_parity.lane_unknown".to_string(),
+                "coherence.gate_chain_parity.lane_kind_unbound".to_string(),
+            ],
+            details: json!({}),
+            details_digest: semantic_digest(&json!({}), DigestScheme::default()),
+            inputs_digest: None,
+        };
+        assert_eq!(
+            witness.assert_classes(&[
+                "coherence.gate_chain_parity.lane_kind_unbound",
+                "coherence.gate_chain_parity.lane_unknown",
+            ]),
+            Ok(())
+        );
+        assert_eq!(
+            witness.assert_classes(&[
+                "coherence.gate_chain_parity.lane_unknown",
+                "coherence.gate_chain_parity.lane_route_missing",
+            ]),
+            Err("gate_chain_parity: failure classes mismatch \
+                 (missing=[\"coherence.gate_chain_parity.lane_route_missing\"], \
+                 extra=[\"coherence.gate_chain_parity.lane_kind_unbound\"])"
+                .to_string())
+        );
+    }
+
+    #[test]
+    fn bool_field_helpers_report_present_wrong_type_and_missing() {
+        let path = Path::new("fixtures/case.json");
+        let fields = json!({"flag": true, "count": 7});
+        let fields = fields.as_object().expect("object");
+
+        assert!(require_bool_field(fields, "flag", path, "artifacts").expect("bool present"));
+        assert_eq!(
+            optional_bool_field(fields, "flag", path, "artifacts").expect("bool present"),
+            Some(true)
+        );
+        assert_eq!(
+            optional_bool_field(fields, "absent", path, "artifacts").expect("absent is fine"),
+            None
+        );
+
+        let err = require_bool_field(fields, "count", path, "artifacts").expect_err("wrong type");
+        assert!(
+            err.to_string()
+                .contains("artifacts.count must be a boolean")
+        );
+        let err =
+            optional_bool_field(fields, "count", path, "artifacts.row").expect_err("wrong type");
+        assert!(
+            err.to_string()
+                .contains("artifacts.row.count must be a boolean")
+        );
+        let err = require_bool_field(fields, "absent", path, "artifacts").expect_err("missing");
+        assert!(
+            err.to_string()
+                .contains("artifacts.absent must be a boolean")
+        );
+    }
+
     #[test]
     fn extract_section_between_returns_body() {
         let text = "prefix START body END suffix";
@@ -6355,6 +10201,80 @@ Current deterministic projected check IDs include:
         assert_eq!(section.trim(), "body");
     }
 
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn capability_section_markers_scope_readme_capabilities() {
+        let readme = "\
+# Premath
+
+`capabilities.preamble`
+
+<!-- capabilities:start -->
+- `capabilities.alpha`
+- `capabilities.beta`
+<!-- capabilities:end -->
+
+## Deprecated
+
+- `capabilities.legacy`
+";
+        let mut surfaces = test_contract_with_fixture_roots("", "").surfaces;
+        let all = parse_backticked_capabilities(
+            &extract_capability_section(readme, &surfaces)
+                .expect("full text")
+                .expect("unset markers keep the whole text"),
+        )
+        .expect("capabilities should parse");
+        assert_eq!(all.len(), 4);
+
+        surfaces.capability_readme_section_start = "<!-- capabilities:start -->".to_string();
+        surfaces.capability_readme_section_end = "<!-- capabilities:end -->".to_string();
+        let scoped = parse_backticked_capabilities(
+            &extract_capability_section(readme, &surfaces)
+                .expect("section should extract")
+                .expect("start marker should be found"),
+        )
+        .expect("capabilities should parse");
+        assert_eq!(
+            sorted_vec_from_set(&scoped),
+            vec![
+                "capabilities.alpha".to_string(),
+                "capabilities.beta".to_string()
+            ]
+        );
+
+        let unmarked = "- `capabilities.alpha`\n- `capabilities.legacy`\n";
+        assert_eq!(
+            extract_capability_section(unmarked, &surfaces)
+                .expect("missing marker is not an error"),
+            None
+        );
+
+        let unterminated = "<!-- capabilities:start -->\n- `capabilities.alpha`\n";
+        assert!(extract_capability_section(unterminated, &surfaces).is_err());
+        surfaces.capability_readme_section_end.clear();
+        assert!(extract_capability_section(readme, &surfaces).is_err());
+    }
+
+    #[test]
+    fn coherence_surfaces_round_trip_through_contract_fragment() {
+        let repo_root = repo_root();
+        let contract_path = repo_root.join("specs/premath/draft/COHERENCE-CONTRACT.json");
+        let raw = read_json_value(&contract_path).expect("contract should parse as JSON");
+        let contract: CoherenceContract =
+            serde_json::from_value(raw.clone()).expect("contract should deserialize");
+
+        let fragment = contract.surfaces.to_contract_json_fragment();
+        let reparsed: CoherenceSurfaces =
+            serde_json::from_value(fragment.clone()).expect("fragment should deserialize");
+        assert_eq!(reparsed, contract.surfaces);
+
+        let original = raw["surfaces"].as_object().expect("surfaces object");
+        for (key, value) in original {
+            assert_eq!(fragment.get(key), Some(value), "surface field `{key}`");
+        }
+    }
+
     #[test]
     fn compile_coherence_constructor_projects_required_obligations() {
         let contract = test_contract_with_fixture_roots("", "");
@@ -6387,493 +10307,1325 @@ Current deterministic projected check IDs include:
     }
 
     #[test]
-    fn check_gate_chain_parity_accepts_valid_lane_registry() {
-        let temp = TempDirGuard::new("gate-chain-lane-registry-valid");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &base_control_plane_contract_payload(),
-        );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+    fn digest_schemes_produce_distinct_consistent_prefixes() {
+        let value = json!({"b": [2, 1], "a": "x"});
+        let classes = vec!["coherence.test.failure".to_string()];
+        let digests_under = |scheme: DigestScheme| {
+            let mut contract = test_contract_with_fixture_roots("", "");
+            contract.digest_scheme = scheme;
+            let constructor = compile_coherence_constructor(
+                Path::new("."),
+                Path::new("specs/premath/draft/COHERENCE-CONTRACT.json"),
+                b"{}",
+                &contract,
+            );
+            let mut parallel = parallel_digests_with_workers(&[&value, &value], 2, |item| {
+                semantic_digest(item, scheme)
+            });
+            vec![
+                ("cohctr", constructor.contract_digest),
+                ("sem", semantic_digest(&value, scheme)),
+                ("sem", parallel.remove(1)),
+                (
+                    "sqw",
+                    square_witness_digest("t", "b", "l", "r", "rejected", &classes, scheme),
+                ),
+                (
+                    "sqlw",
+                    composition_law_digest("span", "law", &value, &value, "accepted", &[], scheme),
+                ),
+                (
+                    "inputs",
+                    inputs_digest(Path::new("."), &BTreeMap::new(), scheme),
+                ),
+            ]
+        };
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(evaluated.failure_classes.is_empty());
+        let v1 = digests_under(DigestScheme::default());
+        let v2 = digests_under(DigestScheme::new(2));
+        for ((family, digest_v1), (_, digest_v2)) in v1.iter().zip(&v2) {
+            let body_v1 = digest_v1
+                .strip_prefix(&format!("{family}1_"))
+                .expect("v1 digest should carry the v1 prefix");
+            let body_v2 = digest_v2
+                .strip_prefix(&format!("{family}2_"))
+                .expect("v2 digest should carry the v2 prefix");
+            assert_ne!(digest_v1, digest_v2);
+            assert_eq!(body_v1, body_v2);
+        }
     }
 
-    #[test]
-    fn check_gate_chain_parity_rejects_missing_schema_lifecycle() {
-        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-missing");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload
-            .as_object_mut()
-            .expect("payload should be object")
-            .remove("schemaLifecycle");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
-        );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+    fn empty_surfaces_payload() -> Value {
+        json!({
+            "capabilityRegistryPath": "",
+            "capabilityRegistryKind": "",
+            "conformancePath": "",
+            "capabilityManifestRoot": "",
+            "readmePath": "",
+            "conformanceReadmePath": "",
+            "specIndexPath": "",
+            "specIndexCapabilityHeading": "",
+            "specIndexInformativeHeading": "",
+            "specIndexOverlayHeading": "",
+            "ciClosurePath": "",
+            "ciClosureBaselineStart": "",
+            "ciClosureBaselineEnd": "",
+            "ciClosureProjectionStart": "",
+            "ciClosureProjectionEnd": "",
+            "misePath": "",
+            "miseBaselineTask": "",
+            "controlPlaneContractPath": "",
+            "doctrineSitePath": "",
+            "doctrineSiteInputPath": "",
+            "doctrineOperationRegistryPath": "",
+            "doctrineRootNodeId": "",
+            "profileReadmePath": "",
+            "bidirSpecPath": "",
+            "bidirSpecSectionStart": "",
+            "bidirSpecSectionEnd": "",
+            "coherenceSpecPath": "",
+            "coherenceSpecObligationStart": "",
+            "coherenceSpecObligationEnd": "",
+            "obligationRegistryKind": "",
+            "informativeClauseNeedle": "",
+            "transportFixtureRootPath": "",
+            "siteFixtureRootPath": "",
+        })
+    }
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string())
-        );
+    fn obligation_specs(ids: &[&str]) -> Vec<CoherenceObligationSpec> {
+        ids.iter()
+            .map(|id| CoherenceObligationSpec {
+                id: (*id).to_string(),
+                description: String::new(),
+            })
+            .collect()
     }
 
-    #[test]
-    fn check_operation_reachability_accepts_matching_world_route_bindings() {
-        let temp = TempDirGuard::new("operation-reachability-world-routes-valid");
-        write_operation_reachability_surfaces(
-            temp.path(),
-            &["dm.identity", "dm.profile.execution"],
-            &["dm.identity", "dm.profile.execution"],
-        );
-        let contract = test_contract_for_operation_reachability();
-        let evaluated = check_operation_reachability(temp.path(), &contract)
-            .expect("operation reachability should evaluate");
-        assert!(evaluated.failure_classes.is_empty());
-        assert_eq!(
-            evaluated.details["worldRouteValidation"]["result"],
-            json!("accepted")
-        );
+    fn contract_with_obligations(ids: &[&str]) -> CoherenceContract {
+        let mut contract = test_contract_with_fixture_roots("", "");
+        contract.obligations = ids
+            .iter()
+            .map(|id| CoherenceObligationSpec {
+                id: (*id).to_string(),
+                description: String::new(),
+            })
+            .collect();
+        contract
     }
 
     #[test]
-    fn check_operation_reachability_rejects_world_route_morphism_drift() {
-        let temp = TempDirGuard::new("operation-reachability-world-routes-drift");
-        write_operation_reachability_surfaces(
-            temp.path(),
-            &["dm.identity"],
-            &["dm.identity", "dm.transport.world"],
-        );
-        let contract = test_contract_for_operation_reachability();
-        let evaluated = check_operation_reachability(temp.path(), &contract)
-            .expect("operation reachability should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&"world_route_morphism_drift".to_string())
-        );
+    fn validate_contract_obligation_descriptions_flags_conflicting_duplicates() {
+        let mut contract = contract_with_obligations(&[
+            "scope_noncontradiction",
+            "gate_chain_parity",
+            "gate_chain_parity",
+        ]);
+        assert!(validate_contract_obligation_descriptions(&contract).is_empty());
+
+        contract.obligations[1].description = "Gate chain parity".to_string();
+        assert!(validate_contract_obligation_descriptions(&contract).is_empty());
+        contract.obligations[2].description = "Gate chain parity".to_string();
+        assert!(validate_contract_obligation_descriptions(&contract).is_empty());
+
+        contract.obligations[2].description = "Scope noncontradiction".to_string();
         assert_eq!(
-            evaluated.details["worldRouteValidation"]["result"],
-            json!("rejected")
+            validate_contract_obligation_descriptions(&contract),
+            vec!["coherence.contract.duplicate_obligation_description_conflict".to_string()]
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_expired_schema_alias() {
-        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-expired-alias");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["requiredWitness"]["witnessKind"] = json!("ci.required.v0");
-        payload["schemaLifecycle"]["activeEpoch"] = json!("2026-07");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
-        );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+    fn failure_class_set_diff_reports_missing_and_unexpected() {
+        let classes = |items: &[&str]| -> Vec<String> {
+            items.iter().map(|item| (*item).to_string()).collect()
+        };
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string())
+        let overlap = failure_class_set_diff(
+            &classes(&["coherence.a.x", "coherence.a.y"]),
+            &classes(&["coherence.a.z", "coherence.a.y", "coherence.a.y"]),
         );
-    }
+        assert_eq!(overlap.missing, classes(&["coherence.a.x"]));
+        assert_eq!(overlap.unexpected, classes(&["coherence.a.z"]));
 
-    #[test]
-    fn check_gate_chain_parity_rejects_freeze_with_active_aliases() {
-        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-freeze-with-aliases");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["schemaLifecycle"]["governance"] = json!({
-            "mode": "freeze",
-            "decisionRef": "decision-0105",
-            "owner": "premath-core",
-            "freezeReason": "release-freeze"
-        });
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+        let disjoint =
+            failure_class_set_diff(&classes(&["coherence.a.x"]), &classes(&["coherence.b.x"]));
+        assert_eq!(disjoint.missing, classes(&["coherence.a.x"]));
+        assert_eq!(disjoint.unexpected, classes(&["coherence.b.x"]));
+
+        let subset = failure_class_set_diff(
+            &classes(&["coherence.a.x", "coherence.a.y"]),
+            &classes(&["coherence.a.y"]),
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        assert_eq!(subset.missing, classes(&["coherence.a.x"]));
+        assert!(subset.unexpected.is_empty());
+        assert!(!subset.is_empty());
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string())
+        let same = failure_class_set_diff(
+            &classes(&["coherence.a.y", "coherence.a.x"]),
+            &classes(&["coherence.a.x", "coherence.a.y"]),
+        );
+        assert!(same.is_empty());
+        assert_eq!(
+            serde_json::to_value(&overlap).expect("diff should serialize"),
+            json!({"missing": ["coherence.a.x"], "unexpected": ["coherence.a.z"]})
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_accepts_freeze_without_aliases() {
-        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-freeze-no-aliases");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["schemaLifecycle"]["governance"] = json!({
-            "mode": "freeze",
-            "decisionRef": "decision-0105",
-            "owner": "premath-core",
-            "freezeReason": "release-freeze"
-        });
-        if let Some(kind_families) = payload["schemaLifecycle"]["kindFamilies"].as_object_mut() {
-            for family in kind_families.values_mut() {
-                family["compatibilityAliases"] = json!([]);
+    fn obligation_surface_fields_cover_required_obligations_and_known_fields() {
+        // Fill the fields that are omitted from JSON when unset.
+        let mut contract = test_contract_with_fixture_roots("", "");
+        contract.surfaces.conformance_capability_heading = "2.3".to_string();
+        contract.surfaces.capability_readme_section_start = "start".to_string();
+        contract.surfaces.capability_readme_section_end = "end".to_string();
+        contract.surfaces.informative_clause_regex = Some(String::new());
+        let surfaces = serde_json::to_value(&contract.surfaces).expect("surfaces serialize");
+        for id in REQUIRED_OBLIGATION_IDS {
+            assert!(
+                OBLIGATION_SURFACE_FIELDS
+                    .iter()
+                    .any(|(obligation_id, _)| obligation_id == id),
+                "{id} has no surface dependencies"
+            );
+        }
+        for (obligation_id, fields) in OBLIGATION_SURFACE_FIELDS {
+            for field in *fields {
+                assert!(
+                    surfaces.get(field).is_some(),
+                    "{obligation_id}: unknown surface field {field}"
+                );
             }
         }
-        payload
-            .as_object_mut()
-            .expect("payload should be object")
-            .remove("evidenceStage2Authority");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
-        );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+    }
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(evaluated.failure_classes.is_empty());
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn obligation_surface_fields_match_consulted_surface_paths() {
+        let root = repo_root();
+        let mut contract = real_contract();
+        // Take the conditional reads too, so every declared path is exercised.
+        contract.enforce_overlay_doctrine_reachability = true;
+        let path_fields = contract_surface_paths(&contract.surfaces);
+        for (obligation_id, fields) in OBLIGATION_SURFACE_FIELDS {
+            let (_, consulted_inputs) =
+                with_consulted_inputs(|| execute_obligation(obligation_id, &root, &contract));
+            let consulted: BTreeSet<&str> =
+                consulted_surface_paths(&root, &contract.surfaces, &consulted_inputs)
+                    .into_keys()
+                    .map(|field| {
+                        path_fields
+                            .get_key_value(&field)
+                            .map(|(field, _)| field.as_str())
+                            .expect("consulted field should be a declared path")
+                    })
+                    .collect();
+            let declared: BTreeSet<&str> = fields
+                .iter()
+                .copied()
+                .filter(|field| path_fields.contains_key(*field))
+                .collect();
+            assert_eq!(
+                consulted, declared,
+                "{obligation_id}: surface path fields read differ from OBLIGATION_SURFACE_FIELDS"
+            );
+        }
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_duplicate_lane_ids() {
-        let temp = TempDirGuard::new("gate-chain-lane-registry-duplicate-ids");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["evidenceLanes"]["runtimeTransport"] = json!("strict_checker");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+    fn affected_obligations_follows_changed_surface_fields() {
+        let mut old = test_contract_with_fixture_roots("", "");
+        old.obligations = REQUIRED_OBLIGATION_IDS
+            .iter()
+            .map(|id| CoherenceObligationSpec {
+                id: (*id).to_string(),
+                description: String::new(),
+            })
+            .collect();
+        assert!(affected_obligations(&old, &old).is_empty());
+
+        let mut new = old.clone();
+        new.surfaces.mise_path = ".mise.local.toml".to_string();
+        assert_eq!(
+            affected_obligations(&old, &new),
+            BTreeSet::from(["gate_chain_parity".to_string()])
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&"coherence.gate_chain_parity.lane_unknown".to_string())
+        new.overlay_docs.push("profile/NEW".to_string());
+        assert_eq!(
+            affected_obligations(&old, &new).len(),
+            REQUIRED_OBLIGATION_IDS.len()
         );
     }
 
+    #[cfg(feature = "markdown-surfaces")]
     #[test]
-    fn check_gate_chain_parity_rejects_unknown_lane_artifact_kind_mapping() {
-        let temp = TempDirGuard::new("gate-chain-lane-registry-unknown-lane-kind");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["laneArtifactKinds"]["unknown_lane"] = json!(["opaque_kind"]);
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+    fn surface_drift_reports_added_removed_and_repointed_fields() {
+        let temp = TempDirGuard::new("surface-drift");
+        write_overlay_traceability_surfaces(temp.path(), &[]);
+        let mut surfaces = empty_surfaces_payload();
+        surfaces["specIndexPath"] = json!("./specs/premath/draft/SPEC-INDEX.md");
+        surfaces["specIndexOverlayHeading"] = json!("5.6");
+        surfaces["profileReadmePath"] = json!("specs/premath/profile/README.md");
+        let payload = json!({
+            "schema": 1,
+            "contractKind": "premath.coherence.contract.v1",
+            "contractId": "coherence.test.v1",
+            "binding": {
+                "normalizerId": "normalizer.coherence.v1",
+                "policyDigest": "policy.coherence.v1"
+            },
+            "obligations": [],
+            "surfaces": surfaces,
+        });
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        write_json_file(&contract_path, &payload);
+        let witness =
+            run_coherence_check(temp.path(), &contract_path).expect("coherence check should run");
+        assert_eq!(
+            witness.consulted_surfaces,
+            BTreeMap::from([
+                (
+                    "profileReadmePath".to_string(),
+                    "specs/premath/profile/README.md".to_string()
+                ),
+                (
+                    "specIndexPath".to_string(),
+                    "specs/premath/draft/SPEC-INDEX.md".to_string()
+                ),
+            ])
+        );
+
+        let mut unread_payload = payload.clone();
+        unread_payload["surfaces"]["misePath"] = json!(".mise.toml");
+        write_json_file(&contract_path, &unread_payload);
+        let unread_witness =
+            run_coherence_check(temp.path(), &contract_path).expect("coherence check should run");
+        assert!(!unread_witness.consulted_surfaces.contains_key("misePath"));
+        assert_eq!(
+            unread_witness.declared_surfaces.get("misePath"),
+            Some(&".mise.toml".to_string())
+        );
+        let unread_contract: CoherenceContract =
+            serde_json::from_value(unread_payload).expect("contract should parse");
+        assert!(surface_drift(&unread_witness, &unread_contract).is_empty());
+
+        let unchanged: CoherenceContract =
+            serde_json::from_value(payload.clone()).expect("contract should parse");
+        assert!(surface_drift(&witness, &unchanged).is_empty());
+
+        let mut changed_payload = payload;
+        changed_payload["surfaces"]["specIndexPath"] = json!("specs/premath/SPEC-INDEX.md");
+        changed_payload["surfaces"]["profileReadmePath"] = json!("");
+        changed_payload["surfaces"]["doctrineSitePath"] =
+            json!("specs/premath/draft/DOCTRINE-SITE.json");
+        let changed: CoherenceContract =
+            serde_json::from_value(changed_payload).expect("contract should parse");
+        let drift = surface_drift(&witness, &changed);
+        assert_eq!(drift.added, vec!["doctrineSitePath".to_string()]);
+        assert_eq!(drift.removed, vec!["profileReadmePath".to_string()]);
+        assert_eq!(
+            drift.repointed,
+            vec![SurfaceRepoint {
+                field: "specIndexPath".to_string(),
+                old_path: "specs/premath/draft/SPEC-INDEX.md".to_string(),
+                new_path: "specs/premath/SPEC-INDEX.md".to_string(),
+            }]
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+    }
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn run_coherence_check_embeds_contract_snapshot_on_request() {
+        let temp = TempDirGuard::new("contract-snapshot");
+        let mut surfaces = empty_surfaces_payload();
+        surfaces["specIndexPath"] = json!("specs/premath/draft/SPEC-INDEX.md");
+        let payload = json!({
+            "schema": 1,
+            "contractKind": "premath.coherence.contract.v1",
+            "contractId": "coherence.test.v1",
+            "binding": {
+                "normalizerId": "normalizer.coherence.v1",
+                "policyDigest": "policy.coherence.v1"
+            },
+            "obligations": [{"id": "scope_noncontradiction", "description": "scope"}],
+            "surfaces": surfaces,
+            "overlayDocs": ["raw/SQUEAK-SITE"],
+        });
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        write_json_file(&contract_path, &payload);
+        let original: CoherenceContract =
+            serde_json::from_value(payload).expect("contract should parse");
+
+        let plain =
+            run_coherence_check(temp.path(), &contract_path).expect("coherence check should run");
+        assert!(plain.contract_snapshot.is_none());
         assert!(
-            evaluated
-                .failure_classes
-                .contains(&"coherence.gate_chain_parity.lane_kind_unbound".to_string())
+            serde_json::to_value(&plain)
+                .expect("witness should serialize")
+                .get("contractSnapshot")
+                .is_none()
         );
-    }
 
+        let embedded = run_coherence_check_with_options(
+            temp.path(),
+            &contract_path,
+            CoherenceCheckOptions {
+                embed_contract: true,
+                ..CoherenceCheckOptions::default()
+            },
+        )
+        .expect("coherence check should run");
+        assert_eq!(embedded.contract_digest, plain.contract_digest);
+        let snapshot = embedded
+            .contract_snapshot
+            .clone()
+            .expect("snapshot should be embedded");
+        let restored: CoherenceContract =
+            serde_json::from_value(snapshot).expect("snapshot should deserialize");
+        assert_eq!(restored, original);
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
     #[test]
-    fn check_gate_chain_parity_rejects_missing_cross_lane_route() {
-        let temp = TempDirGuard::new("gate-chain-lane-registry-missing-route");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["laneOwnership"]["requiredCrossLaneWitnessRoute"] = Value::Null;
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+    fn details_digests_track_detail_changes_without_class_changes() {
+        let root = repo_root();
+        let contract_path = root.join("specs/premath/draft/COHERENCE-CONTRACT.json");
+        let plain = run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        let verbose = run_coherence_check_with_options(
+            &root,
+            &contract_path,
+            CoherenceCheckOptions {
+                verbose_reasons: true,
+                ..CoherenceCheckOptions::default()
+            },
+        )
+        .expect("coherence check should run");
+
+        let gate_chain = |witness: &CoherenceWitness| {
+            witness
+                .obligations
+                .iter()
+                .find(|obligation| obligation.obligation_id == "gate_chain_parity")
+                .cloned()
+                .expect("gate chain obligation should be present")
+        };
+        let (plain_gate, verbose_gate) = (gate_chain(&plain), gate_chain(&verbose));
+        assert_eq!(plain_gate.failure_classes, verbose_gate.failure_classes);
+        assert_ne!(plain_gate.details, verbose_gate.details);
+        assert_eq!(
+            plain_gate.details_digest,
+            semantic_digest(&plain_gate.details, DigestScheme::default())
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        assert_ne!(plain_gate.details_digest, verbose_gate.details_digest);
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&"coherence.gate_chain_parity.lane_route_missing".to_string())
+        assert_eq!(plain.failure_classes, verbose.failure_classes);
+        assert_eq!(plain.contract_digest, verbose.contract_digest);
+        assert_ne!(
+            plain.obligations_details_digest,
+            verbose.obligations_details_digest
+        );
+        let rerun = run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(
+            rerun.obligations_details_digest,
+            plain.obligations_details_digest
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_checker_core_ownership_violation() {
-        let temp = TempDirGuard::new("gate-chain-lane-registry-ownership-violation");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["laneOwnership"]["checkerCoreOnlyObligations"] =
-            json!(["cwf_substitution_identity", "span_square_commutation"]);
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
-        );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+    fn validate_contract_obligation_order_accepts_canonical_order() {
+        let obligations = obligation_specs(REQUIRED_OBLIGATION_IDS);
+        assert!(validate_contract_obligation_order(&obligations).is_empty());
+    }
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&"coherence.gate_chain_parity.lane_ownership_violation".to_string())
+    #[test]
+    fn validate_contract_obligation_order_skips_unknown_ids_and_flags_adjacent_swaps() {
+        let subset = obligation_specs(&[
+            "gate_chain_parity",
+            "unknown_obligation",
+            "cwf_comprehension_eta",
+        ]);
+        assert!(validate_contract_obligation_order(&subset).is_empty());
+
+        let adjacent_swap = obligation_specs(&[
+            "gate_chain_parity",
+            "transport_functoriality",
+            "operation_reachability",
+        ]);
+        assert_eq!(
+            validate_contract_obligation_order(&adjacent_swap),
+            vec!["coherence.contract.obligation_order".to_string()]
         );
     }
 
+    #[cfg(not(feature = "markdown-surfaces"))]
     #[test]
-    fn check_gate_chain_parity_rejects_worker_lane_default_mode_drift() {
-        let temp = TempDirGuard::new("gate-chain-worker-lane-default-mode-drift");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["workerLaneAuthority"]["mutationPolicy"]["defaultMode"] = json!("human-override");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+    fn markdown_free_build_runs_remaining_obligations_and_rejects_markdown_ones() {
+        let repo_root = repo_root();
+        let contract_path = repo_root.join("specs/premath/draft/COHERENCE-CONTRACT.json");
+        let contract = real_contract();
+        assert_eq!(REQUIRED_OBLIGATION_IDS.len(), 11);
+        for obligation_id in REQUIRED_OBLIGATION_IDS {
+            let check = execute_obligation(obligation_id, &repo_root, &contract);
+            assert!(
+                check.failure_classes.is_empty(),
+                "{obligation_id}: {:?}",
+                check.failure_classes
+            );
+        }
+
+        let err = run_coherence_check(&repo_root, &contract_path)
+            .expect_err("contract naming markdown obligations should be rejected");
+        assert!(err.to_string().contains("markdown-surfaces"), "{err}");
+    }
+
+    #[test]
+    fn cwf_core_obligations_match_control_plane_lane_ownership() {
+        let expected: BTreeSet<String> = [
+            "cwf_comprehension_beta",
+            "cwf_comprehension_eta",
+            "cwf_substitution_composition",
+            "cwf_substitution_identity",
+        ]
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+        assert_eq!(cwf_core_obligations(), expected);
+
+        let root = repo_root();
+        let contract: Value = serde_json::from_slice(
+            &fs::read(root.join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"))
+                .expect("control-plane contract should be readable"),
+        )
+        .expect("control-plane contract should parse");
+        let listed: BTreeSet<String> =
+            serde_json::from_value(contract["laneOwnership"]["checkerCoreOnlyObligations"].clone())
+                .expect("checkerCoreOnlyObligations should be a string list");
+        assert_eq!(listed, cwf_core_obligations());
+    }
+
+    #[test]
+    fn validate_contract_obligation_set_honors_subset_and_superset_options() {
+        let ids = |items: &[&str]| -> Vec<String> {
+            items.iter().map(|item| (*item).to_string()).collect()
+        };
+        let strict = ValidateObligationSetOptions::default();
+        let subset = ValidateObligationSetOptions {
+            allow_subset: true,
+            ..strict
+        };
+        let superset = ValidateObligationSetOptions {
+            allow_superset: true,
+            ..strict
+        };
+
+        let complete = ids(REQUIRED_OBLIGATION_IDS);
+        assert!(validate_contract_obligation_set(&complete, strict).is_empty());
+
+        let partial = ids(&REQUIRED_OBLIGATION_IDS[1..]);
+        assert_eq!(
+            validate_contract_obligation_set(&partial, strict),
+            vec!["coherence.contract.missing_required_obligation".to_string()]
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        assert!(validate_contract_obligation_set(&partial, subset).is_empty());
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        let mut extended = complete.clone();
+        extended.push("future_obligation".to_string());
+        assert_eq!(
+            validate_contract_obligation_set(&extended, strict),
+            vec!["coherence.contract.unknown_obligation".to_string()]
+        );
+        assert!(validate_contract_obligation_set(&extended, superset).is_empty());
+        assert_eq!(
+            validate_contract_obligation_set(&extended, subset),
+            vec!["coherence.contract.unknown_obligation".to_string()]
+        );
+
+        extended.push("future_obligation".to_string());
+        extended.push(REQUIRED_OBLIGATION_IDS[0].to_string());
+        assert_eq!(
+            validate_contract_obligation_set(&extended, superset),
+            vec!["coherence.contract.duplicate_obligation".to_string()]
+        );
+        let permissive = ValidateObligationSetOptions {
+            allow_subset: true,
+            allow_superset: true,
+        };
         assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_WORKER_MUTATION_MODE_DRIFT_FAILURE.to_string())
+            validate_contract_obligation_set(&ids(&["future_obligation"]), permissive).is_empty()
         );
     }
 
+    #[cfg(feature = "markdown-surfaces")]
     #[test]
-    fn check_gate_chain_parity_rejects_worker_lane_route_drift() {
-        let temp = TempDirGuard::new("gate-chain-worker-lane-route-drift");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["workerLaneAuthority"]["mutationRoutes"]["issueDiscover"] = json!("issue_discover");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+    fn validate_contract_obligation_order_rejects_scrambled_complete_set() {
+        let mut ids = REQUIRED_OBLIGATION_IDS.to_vec();
+        ids.swap(0, 5);
+        ids.reverse();
+        let obligations = obligation_specs(&ids);
+        let mut declared: Vec<String> = ids.iter().map(|id| (*id).to_string()).collect();
+        declared.sort();
+        assert!(
+            validate_contract_obligation_set(&declared, ValidateObligationSetOptions::default())
+                .is_empty()
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        assert_eq!(
+            validate_contract_obligation_order(&obligations),
+            vec!["coherence.contract.obligation_order".to_string()]
+        );
+    }
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn witness_id_is_stable_and_tracks_surface_changes() {
+        let root = repo_root();
+        let conformance_text = fs::read_to_string(root.join("specs/premath/draft/CONFORMANCE.md"))
+            .expect("conformance doc should be readable");
+        let temp = TempDirGuard::new("witness-id");
+        let conformance_path = temp.path().join("CONFORMANCE.md");
+        write_text_file(&conformance_path, &conformance_text);
+        let (contract_path, _) = copy_real_contract(&temp, |payload| {
+            payload["surfaces"]["conformancePath"] = json!(conformance_path.display().to_string());
+        });
+
+        let first = run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        let second =
+            run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(first.result, "accepted");
+        assert!(first.witness_id.starts_with("wit1_"));
+        assert_eq!(first.witness_id, second.witness_id);
+
+        let strict = run_coherence_check_with_options(
+            &root,
+            &contract_path,
+            CoherenceCheckOptions {
+                warnings_as_errors: true,
+                ..CoherenceCheckOptions::default()
+            },
+        )
+        .expect("coherence check should run");
+        assert_ne!(strict.witness_id, first.witness_id);
+        let ordered = run_coherence_check_with_options(
+            &root,
+            &contract_path,
+            CoherenceCheckOptions {
+                enforce_obligation_order: true,
+                ..CoherenceCheckOptions::default()
+            },
+        )
+        .expect("coherence check should run");
+        assert_ne!(ordered.witness_id, first.witness_id);
+        assert_ne!(ordered.witness_id, strict.witness_id);
+
+        write_text_file(
+            &conformance_path,
+            &format!("{conformance_text}\n<!-- edited -->\n"),
+        );
+        let edited =
+            run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(edited.result, "accepted");
+        assert_eq!(edited.contract_digest, first.contract_digest);
+        assert_ne!(edited.witness_id, first.witness_id);
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn coherence_witness_round_trips_through_json() {
+        let root = repo_root();
+        let witness = run_coherence_check(
+            &root,
+            root.join("specs/premath/draft/COHERENCE-CONTRACT.json"),
+        )
+        .expect("coherence check should run");
+        let payload = serde_json::to_value(&witness).expect("witness should serialize");
+        let reparsed: CoherenceWitness =
+            serde_json::from_value(payload.clone()).expect("witness should deserialize");
+        assert_eq!(reparsed, witness);
+
+        let mut legacy = payload;
+        for field in [
+            "witnessId",
+            "obligationsDetailsDigest",
+            "consultedSurfaces",
+            "warnings",
+            "contractSnapshot",
+            "digestScheme",
+        ] {
+            legacy
+                .as_object_mut()
+                .expect("witness object")
+                .remove(field);
+        }
+        for obligation in legacy["obligations"]
+            .as_array_mut()
+            .expect("obligations array")
+        {
+            let obligation = obligation.as_object_mut().expect("obligation object");
+            obligation.remove("detailsDigest");
+            obligation.remove("inputsDigest");
+        }
+        let legacy: CoherenceWitness =
+            serde_json::from_value(legacy).expect("pre-digest witness should deserialize");
+        assert_eq!(legacy.witness_id, "");
+        assert_eq!(legacy.digest_scheme, DigestScheme::default());
+        assert_eq!(legacy.obligations.len(), witness.obligations.len());
         assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_WORKER_ROUTE_UNBOUND_FAILURE.to_string())
+            legacy
+                .obligations
+                .iter()
+                .all(|row| row.details_digest.is_empty())
         );
+        assert_eq!(legacy.failure_classes, witness.failure_classes);
     }
 
+    #[cfg(feature = "markdown-surfaces")]
     #[test]
-    fn check_gate_chain_parity_rejects_worker_lane_policy_drift() {
-        let temp = TempDirGuard::new("gate-chain-worker-lane-policy-drift");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["workerLaneAuthority"]["mutationPolicy"]["compatibilityOverrides"][0]["supportUntilEpoch"] =
-            json!("2026-01");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+    fn run_coherence_check_promotes_warnings_when_requested() {
+        let root = repo_root();
+        let temp = TempDirGuard::new("warnings-as-errors");
+        let (contract_path, _) = copy_real_contract(&temp, |payload| {
+            payload["obligations"]
+                .as_array_mut()
+                .expect("obligations should be an array")
+                .swap(0, 1);
+        });
+        let order_class = "coherence.contract.obligation_order".to_string();
+
+        let witness =
+            run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(witness.result, "accepted");
+        assert_eq!(witness.warnings, vec![order_class.clone()]);
+
+        let witness = run_coherence_check_with_options(
+            &root,
+            &contract_path,
+            CoherenceCheckOptions {
+                warnings_as_errors: true,
+                ..CoherenceCheckOptions::default()
+            },
+        )
+        .expect("coherence check should run");
+        assert_eq!(witness.result, "rejected");
+        // Obligation-level warnings (the repo's transport fixtures are
+        // polarity-imbalanced) are promoted alongside the witness-level one.
+        assert_eq!(
+            witness.failure_classes,
+            vec![
+                order_class.clone(),
+                "coherence.transport_functoriality.polarity_imbalance".to_string(),
+            ]
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        assert_eq!(witness.warnings, vec![order_class]);
+    }
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn run_coherence_check_downgrades_tolerated_surface_errors() {
+        let root = repo_root();
+        let temp = TempDirGuard::new("tolerate-surface-errors");
+        let (contract_path, mut payload) = copy_real_contract(&temp, |payload| {
+            payload["surfaces"]["profileReadmePath"] = json!("specs/premath/profile/MISSING.md");
+        });
+
+        let surface_error = "coherence.overlay_traceability.surface_error".to_string();
+        let witness =
+            run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(witness.result, "rejected");
+        assert_eq!(witness.failure_classes, vec![surface_error.clone()]);
+
+        payload["tolerateSurfaceErrors"] = json!(["overlay_traceability"]);
+        write_json_file(&contract_path, &payload);
+        let witness =
+            run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(witness.result, "accepted");
+        assert!(witness.failure_classes.is_empty());
+        assert!(witness.warnings.contains(&surface_error));
+        let overlay = witness
+            .obligations
+            .iter()
+            .find(|obligation| obligation.obligation_id == "overlay_traceability")
+            .expect("overlay obligation should be present");
+        assert_eq!(overlay.result, "accepted");
+        assert_eq!(overlay.details["surfaceErrorTolerated"], json!(true));
+
+        // A surface that exists but cannot be read is not tolerated.
+        payload["surfaces"]["profileReadmePath"] = json!("specs/premath/profile");
+        write_json_file(&contract_path, &payload);
+        let witness =
+            run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(witness.failure_classes, vec![surface_error.clone()]);
+        assert!(!witness.warnings.contains(&surface_error));
+
+        payload["tolerateSurfaceErrors"] = json!(["overlay_traceability", "not_an_obligation"]);
+        write_json_file(&contract_path, &payload);
+        let err = run_coherence_check(&root, &contract_path)
+            .expect_err("unknown tolerated obligation should be rejected");
+        assert!(err.to_string().contains("\"not_an_obligation\""), "{err}");
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn run_coherence_check_tolerates_missing_overlay_file() {
+        let root = repo_root();
+        let temp = TempDirGuard::new("tolerate-missing-overlay");
+        let pending = "profile/PENDING-OVERLAY";
+        let spec_index_path = temp.path().join("SPEC-INDEX.md");
+        let spec_index = fs::read_to_string(root.join("specs/premath/draft/SPEC-INDEX.md"))
+            .expect("spec index should be readable");
+        let overlay_heading = "### 5.6 Normative for profile overlays (only if claimed)\n";
+        assert!(spec_index.contains(overlay_heading));
+        write_text_file(
+            &spec_index_path,
+            &spec_index.replacen(
+                overlay_heading,
+                &format!("{overlay_heading}\n- `{pending}` — not yet written.\n"),
+                1,
+            ),
+        );
+        let profile_readme_path = temp.path().join("README.md");
+        let profile_readme = fs::read_to_string(root.join("specs/premath/profile/README.md"))
+            .expect("profile README should be readable");
+        write_text_file(
+            &profile_readme_path,
+            &format!("{profile_readme}\n- PENDING-OVERLAY.md\n"),
+        );
+        let (contract_path, mut payload) = copy_real_contract(&temp, |payload| {
+            payload["surfaces"]["specIndexPath"] = json!(display_path(&spec_index_path));
+            payload["surfaces"]["profileReadmePath"] = json!(display_path(&profile_readme_path));
+            payload["overlayDocs"]
+                .as_array_mut()
+                .expect("overlay docs array")
+                .push(json!(pending));
+        });
+
+        let missing_class = "coherence.overlay_traceability.overlay_file_missing".to_string();
+        let witness =
+            run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(witness.result, "rejected");
+        assert_eq!(witness.failure_classes, vec![missing_class.clone()]);
+
+        payload["tolerateSurfaceErrors"] = json!(["overlay_traceability"]);
+        write_json_file(&contract_path, &payload);
+        let witness =
+            run_coherence_check(&root, &contract_path).expect("coherence check should run");
+        assert_eq!(witness.result, "accepted", "{:?}", witness.failure_classes);
+        assert!(witness.warnings.contains(&missing_class));
+        let overlay = witness
+            .obligations
+            .iter()
+            .find(|obligation| obligation.obligation_id == "overlay_traceability")
+            .expect("overlay obligation should be present");
+        assert_eq!(overlay.result, "accepted");
+        assert_eq!(overlay.details["surfaceErrorTolerated"], json!(true));
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn check_capability_parity_flags_conformance_capability_drift() {
+        let root = repo_root();
+        let mut contract = real_contract();
+        assert_eq!(contract.surfaces.conformance_capability_heading, "2.3");
+        let checked = check_capability_parity(&root, &contract, None)
+            .expect("capability parity should evaluate");
+        assert!(checked.failure_classes.is_empty());
+
+        let conformance_text = fs::read_to_string(root.join(&contract.surfaces.conformance_path))
+            .expect("conformance doc should be readable");
+        let temp = TempDirGuard::new("conformance-capability-parity");
+        let drifted_path = temp.path().join("CONFORMANCE.md");
+        write_text_file(
+            &drifted_path,
+            &conformance_text.replace("- `capabilities.squeak_site`\n", ""),
+        );
+        contract.surfaces.conformance_path = drifted_path.display().to_string();
+
+        let checked = check_capability_parity(&root, &contract, None)
+            .expect("capability parity should evaluate");
+        assert_eq!(
+            checked.failure_classes,
+            vec!["coherence.capability_parity.conformance_capability_mismatch".to_string()]
+        );
         assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_WORKER_POLICY_DRIFT_FAILURE.to_string())
+            !checked.details["conformance"]
+                .as_array()
+                .expect("conformance capabilities should be listed")
+                .contains(&json!("capabilities.squeak_site"))
+        );
+
+        contract.surfaces.conformance_capability_heading.clear();
+        let checked = check_capability_parity(&root, &contract, None)
+            .expect("capability parity should evaluate");
+        assert!(checked.failure_classes.is_empty());
+        assert_eq!(checked.details["conformance"], Value::Null);
+
+        contract.surfaces.capability_readme_section_start = "<!-- capabilities:start -->".into();
+        contract.surfaces.capability_readme_section_end = "<!-- capabilities:end -->".into();
+        let checked = check_capability_parity(&root, &contract, None)
+            .expect("capability parity should evaluate");
+        assert_eq!(
+            checked.failure_classes,
+            vec![
+                "coherence.capability_parity.conformance_readme_section_missing".to_string(),
+                "coherence.capability_parity.readme_section_missing".to_string(),
+            ]
         );
     }
 
+    #[cfg(feature = "markdown-surfaces")]
     #[test]
-    fn check_gate_chain_parity_rejects_evidence_factorization_missing_route() {
-        let temp = TempDirGuard::new("gate-chain-evidence-factorization-missing-route");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["evidenceFactorization"]["factorizationRoutes"] = json!([]);
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+    fn capability_checks_accept_inline_registry() {
+        let root = repo_root();
+        let mut contract = real_contract();
+        let mut registry: Value = serde_json::from_slice(
+            &fs::read(root.join(&contract.surfaces.capability_registry_path))
+                .expect("capability registry should be readable"),
+        )
+        .expect("capability registry should parse");
+        contract.surfaces.capability_registry_path = "specs/premath/draft/MISSING.json".to_string();
+
+        let checked = check_capability_parity(&root, &contract, Some(&registry))
+            .expect("capability parity should evaluate");
+        assert!(checked.failure_classes.is_empty());
+        assert_eq!(
+            checked.details["capabilityRegistryPath"],
+            json!(INLINE_CAPABILITY_REGISTRY_SOURCE)
+        );
+        let checked = check_scope_noncontradiction(&root, &contract, Some(&registry))
+            .expect("scope noncontradiction should evaluate");
+        assert!(checked.failure_classes.is_empty());
+
+        registry["registryKind"] = json!("premath.other_registry.v1");
+        let err = check_capability_parity(&root, &contract, Some(&registry))
+            .expect_err("registry kind mismatch should fail");
+        match err {
+            CoherenceError::ContractKindMismatch {
+                expected,
+                got,
+                path,
+                field,
+            } => {
+                assert_eq!(expected, contract.surfaces.capability_registry_kind);
+                assert_eq!(got, "premath.other_registry.v1");
+                assert_eq!(path, INLINE_CAPABILITY_REGISTRY_SOURCE);
+                assert_eq!(field, "registryKind");
+            }
+            other => panic!("expected ContractKindMismatch, got {other:?}"),
+        }
+
+        registry["schema"] = json!(2);
+        let err = check_capability_parity(&root, &contract, Some(&registry))
+            .expect_err("unsupported schema should fail");
+        assert!(matches!(
+            err,
+            CoherenceError::SchemaMismatch {
+                expected: 1,
+                got: 2,
+                ..
+            }
+        ));
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn check_scope_noncontradiction_flags_unsorted_profile_claims_when_required() {
+        let root = repo_root();
+        let mut contract = real_contract();
+        let mut registry: Value = serde_json::from_slice(
+            &fs::read(root.join(&contract.surfaces.capability_registry_path))
+                .expect("capability registry should be readable"),
+        )
+        .expect("capability registry should parse");
+        registry["profileOverlayClaims"] = json!([
+            "profile.doctrine_inf_governance.v0",
+            "profile.alpha_overlay.v0"
+        ]);
+
+        let conformance_text = fs::read_to_string(root.join(&contract.surfaces.conformance_path))
+            .expect("conformance doc should be readable");
+        let temp = TempDirGuard::new("scope-unsorted-profile-claims");
+        let conformance_path = temp.path().join("CONFORMANCE.md");
+        write_text_file(
+            &conformance_path,
+            &conformance_text.replace(
+                "- `profile.doctrine_inf_governance.v0`\n",
+                "- `profile.doctrine_inf_governance.v0`\n- `profile.alpha_overlay.v0`\n",
+            ),
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        contract.surfaces.conformance_path = conformance_path.display().to_string();
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        let checked = check_scope_noncontradiction(&root, &contract, Some(&registry))
+            .expect("scope noncontradiction should evaluate");
+        assert!(checked.failure_classes.is_empty());
+
+        contract.require_sorted_profile_claims = true;
+        let checked = check_scope_noncontradiction(&root, &contract, Some(&registry))
+            .expect("scope noncontradiction should evaluate");
+        assert_eq!(
+            checked.failure_classes,
+            vec!["coherence.scope_noncontradiction.profile_overlay_unsorted".to_string()]
+        );
+    }
+
+    #[test]
+    fn cover_closure_follows_covers_but_not_edges() {
+        let site: DoctrineSite = serde_json::from_value(json!({
+            "covers": [
+                {"over": "root", "parts": ["a", "b"]},
+                {"over": "a", "parts": ["a1"]}
+            ],
+            "edges": [
+                {"from": "root", "to": "e"},
+                {"from": "a1", "to": "e1"}
+            ]
+        }))
+        .expect("doctrine site should parse");
+        let set = |items: &[&str]| -> BTreeSet<String> {
+            items.iter().map(|item| (*item).to_string()).collect()
+        };
+
+        assert_eq!(cover_closure(&site, "root"), set(&["a", "a1", "b"]));
+        assert_eq!(cover_closure(&site, "a"), set(&["a1"]));
+        assert!(cover_closure(&site, "e").is_empty());
+        assert_eq!(
+            compute_doctrine_reachability(&site, "root"),
+            set(&["a", "a1", "b", "e", "e1", "root"])
+        );
+    }
+
+    #[test]
+    fn run_coherence_check_enforces_obligation_order_only_when_enabled() {
+        let temp = TempDirGuard::new("contract-obligation-order");
+        let mut ids = REQUIRED_OBLIGATION_IDS.to_vec();
+        ids.reverse();
+        let obligations: Vec<Value> = ids
+            .iter()
+            .map(|id| json!({"id": id, "description": id}))
+            .collect();
+        let mut payload = json!({
+            "schema": 1,
+            "contractKind": "premath.coherence.contract.v1",
+            "contractId": "coherence.test.v1",
+            "binding": {
+                "normalizerId": "normalizer.coherence.v1",
+                "policyDigest": "policy.coherence.v1"
+            },
+            "obligations": obligations,
+            "surfaces": empty_surfaces_payload(),
+        });
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        write_json_file(&contract_path, &payload);
+        let witness =
+            run_coherence_check(temp.path(), &contract_path).expect("coherence check should run");
         assert!(
-            evaluated
+            !witness
                 .failure_classes
-                .contains(&GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE.to_string())
+                .contains(&"coherence.contract.obligation_order".to_string())
+        );
+
+        payload["enforceObligationOrder"] = json!(true);
+        write_json_file(&contract_path, &payload);
+        let witness =
+            run_coherence_check(temp.path(), &contract_path).expect("coherence check should run");
+        assert!(
+            witness
+                .failure_classes
+                .contains(&"coherence.contract.obligation_order".to_string())
+        );
+        assert_eq!(
+            witness.obligations[0].obligation_id,
+            "contract_obligation_set"
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_evidence_factorization_ambiguous_routes() {
-        let temp = TempDirGuard::new("gate-chain-evidence-factorization-ambiguous-routes");
+    fn run_coherence_check_warns_on_obligation_order_unless_enforced() {
+        let temp = TempDirGuard::new("contract-obligation-order");
+        let mut ids = REQUIRED_OBLIGATION_IDS.to_vec();
+        ids.reverse();
+        let obligations: Vec<Value> = ids
+            .iter()
+            .map(|id| json!({"id": id, "description": id}))
+            .collect();
+        let payload = json!({
+            "schema": 1,
+            "contractKind": "premath.coherence.contract.v1",
+            "contractId": "coherence.test.v1",
+            "binding": {
+                "normalizerId": "normalizer.coherence.v1",
+                "policyDigest": "policy.coherence.v1"
+            },
+            "obligations": obligations,
+            "surfaces": empty_surfaces_payload(),
+        });
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        write_json_file(&contract_path, &payload);
+        let order_class = "coherence.contract.obligation_order".to_string();
+        let witness =
+            run_coherence_check(temp.path(), &contract_path).expect("coherence check should run");
+        assert!(!witness.failure_classes.contains(&order_class));
+        assert_eq!(witness.warnings, vec![order_class.clone()]);
+
+        let witness = run_coherence_check_with_options(
+            temp.path(),
+            &contract_path,
+            CoherenceCheckOptions {
+                enforce_obligation_order: true,
+                ..CoherenceCheckOptions::default()
+            },
+        )
+        .expect("coherence check should run");
+        assert!(witness.failure_classes.contains(&order_class));
+        assert!(witness.warnings.is_empty());
+    }
+
+    #[test]
+    fn run_coherence_check_with_baseline_summarizes_regressions() {
+        let temp = TempDirGuard::new("contract-regression-baseline");
+        let mut ids = REQUIRED_OBLIGATION_IDS.to_vec();
+        ids.reverse();
+        let obligations: Vec<Value> = ids
+            .iter()
+            .map(|id| json!({"id": id, "description": id}))
+            .collect();
+        let mut payload = json!({
+            "schema": 1,
+            "contractKind": "premath.coherence.contract.v1",
+            "contractId": "coherence.test.v1",
+            "binding": {
+                "normalizerId": "normalizer.coherence.v1",
+                "policyDigest": "policy.coherence.v1"
+            },
+            "obligations": obligations,
+            "surfaces": empty_surfaces_payload(),
+        });
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        write_json_file(&contract_path, &payload);
+        let order_class = "coherence.contract.obligation_order".to_string();
+
+        let relaxed = run_coherence_check_with_baseline(temp.path(), &contract_path, None)
+            .expect("coherence check should run");
+        assert!(relaxed.regression.is_none());
+        let relaxed = relaxed.witness;
+
+        let unchanged =
+            run_coherence_check_with_baseline(temp.path(), &contract_path, Some(&relaxed))
+                .expect("coherence check should run")
+                .regression
+                .expect("baseline should produce a summary");
+        assert_eq!(unchanged, CoherenceRegressionSummary::default());
+
+        payload["enforceObligationOrder"] = json!(true);
+        write_json_file(&contract_path, &payload);
+        let regressed =
+            run_coherence_check_with_baseline(temp.path(), &contract_path, Some(&relaxed))
+                .expect("coherence check should run");
+        let summary = regressed
+            .regression
+            .expect("baseline should produce a summary");
+        assert!(summary.is_regression);
+        assert_eq!(summary.new_failures, vec![order_class.clone()]);
+        assert!(summary.resolved_failures.is_empty());
+
+        let improved = coherence_regression_summary(&regressed.witness, &relaxed);
+        assert!(!improved.is_regression);
+        assert!(improved.new_failures.is_empty());
+        assert_eq!(improved.resolved_failures, vec![order_class]);
+    }
+
+    #[test]
+    fn check_gate_chain_parity_accepts_valid_lane_registry() {
+        let temp = TempDirGuard::new("gate-chain-lane-registry-valid");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["evidenceFactorization"]["factorizationRoutes"] =
-            json!(["eta.route_a", "eta.route_b"]);
         write_json_file(
             &temp
                 .path()
                 .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+            &base_control_plane_contract_payload(),
         );
         let contract =
             test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
         let evaluated =
             check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_EVIDENCE_FACTORIZATION_AMBIGUOUS_FAILURE.to_string())
-        );
+        assert!(evaluated.failure_classes.is_empty());
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_evidence_factorization_unbound_binding() {
-        let temp = TempDirGuard::new("gate-chain-evidence-factorization-unbound-binding");
+    fn check_gate_chain_parity_records_satisfied_reasons_when_verbose() {
+        let temp = TempDirGuard::new("gate-chain-verbose-reasons");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceFactorization"]["binding"]["policyDigestRef"] = json!("policy");
         write_json_file(
             &temp
                 .path()
                 .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
             &payload,
         );
-        let contract =
+        let mut contract =
             test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated =
+        let quiet =
             check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_EVIDENCE_FACTORIZATION_UNBOUND_FAILURE.to_string())
+            quiet.details["stage2Authority"]
+                .get("satisfiedReasons")
+                .is_none()
         );
-    }
 
-    #[test]
-    fn check_gate_chain_parity_rejects_stage1_missing_route() {
-        let temp = TempDirGuard::new("gate-chain-stage1-missing-route");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
-        let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage1Parity"]["authorityToTypedCoreRoute"] = json!("");
+        contract.verbose_reasons = true;
+        let verbose =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(verbose.failure_classes.is_empty());
+        assert_eq!(
+            verbose.details["stage2Authority"]["satisfiedReasons"],
+            json!(STAGE2_AUTHORITY_SATISFIED_REASONS)
+        );
+        assert_eq!(verbose.details["stage2Authority"]["reasons"], json!([]));
+
+        payload["evidenceStage2Authority"]["activeStage"] = json!("stage1");
         write_json_file(
             &temp
                 .path()
                 .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
             &payload,
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
-
-        let evaluated =
+        let failing =
             check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_STAGE1_PARITY_MISSING_FAILURE.to_string())
+        assert_eq!(
+            failing.details["stage2Authority"]["satisfiedReasons"],
+            json!([])
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage1_unbound_binding_tuple() {
-        let temp = TempDirGuard::new("gate-chain-stage1-unbound-binding");
+    fn check_gate_chain_parity_reports_typed_schema_mismatch() {
+        let temp = TempDirGuard::new("gate-chain-schema-mismatch");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage1Parity"]["comparisonTuple"]["normalizerIdRef"] = json!("normalizer");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
-        );
+        payload["schema"] = json!(2);
+        let control_plane_path = temp
+            .path()
+            .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        write_json_file(&control_plane_path, &payload);
         let contract =
             test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        let err = check_gate_chain_parity(temp.path(), &contract)
+            .expect_err("schema-2 control-plane contract should be rejected");
+        match err {
+            CoherenceError::SchemaMismatch {
+                expected,
+                got,
+                path,
+                field,
+            } => {
+                assert_eq!(expected, 1);
+                assert_eq!(got, 2);
+                assert_eq!(path, display_path(&control_plane_path));
+                assert_eq!(field, "schema");
+            }
+            other => panic!("expected SchemaMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn evaluate_site_case_gate_chain_parity_requires_case_context() {
+        let case_path = Path::new("fixtures/gate_chain/case.json");
+        let payload = base_control_plane_contract_payload();
+
+        let bound = evaluate_site_case_gate_chain_parity(
+            &json!({
+                "caseContext": {"obligationId": "gate_chain_parity"},
+                "controlPlaneContract": payload.clone(),
+            }),
+            case_path,
+            DEFAULT_CONTROL_PLANE_CONTRACT_KIND,
+        )
+        .expect("gate chain vector should evaluate");
         assert!(
-            evaluated
+            !bound
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE1_PARITY_UNBOUND_FAILURE.to_string())
+                .contains(&GATE_CHAIN_VECTOR_MISSING_CASE_CONTEXT_FAILURE.to_string())
         );
+
+        for artifacts in [
+            json!({"controlPlaneContract": payload.clone()}),
+            json!({
+                "caseContext": {"obligationId": "span_square_commutation"},
+                "controlPlaneContract": payload.clone(),
+            }),
+        ] {
+            let evaluated = evaluate_site_case_gate_chain_parity(
+                &artifacts,
+                case_path,
+                DEFAULT_CONTROL_PLANE_CONTRACT_KIND,
+            )
+            .expect("gate chain vector should evaluate");
+            assert_eq!(evaluated.result, "rejected");
+            assert!(
+                evaluated
+                    .failure_classes
+                    .contains(&GATE_CHAIN_VECTOR_MISSING_CASE_CONTEXT_FAILURE.to_string())
+            );
+        }
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage1_failure_class_mismatch() {
-        let temp = TempDirGuard::new("gate-chain-stage1-failure-class-mismatch");
+    fn evaluate_site_case_gate_chain_parity_reports_typed_mismatches() {
+        let case_path = Path::new("fixtures/gate_chain/case.json");
+        let mut payload = base_control_plane_contract_payload();
+        payload["schema"] = json!(2);
+        let err = evaluate_site_case_gate_chain_parity(
+            &json!({"controlPlaneContract": payload}),
+            case_path,
+            DEFAULT_CONTROL_PLANE_CONTRACT_KIND,
+        )
+        .expect_err("schema-2 control-plane contract should be rejected");
+        match err {
+            CoherenceError::SchemaMismatch {
+                expected: 1,
+                got: 2,
+                field,
+                ..
+            } => assert_eq!(field, "artifacts.controlPlaneContract.schema"),
+            other => panic!("expected SchemaMismatch, got {other:?}"),
+        }
+
+        let mut payload = base_control_plane_contract_payload();
+        payload["contractKind"] = json!("premath.control_plane.contract.v0");
+        let err = evaluate_site_case_gate_chain_parity(
+            &json!({"controlPlaneContract": payload}),
+            case_path,
+            DEFAULT_CONTROL_PLANE_CONTRACT_KIND,
+        )
+        .expect_err("non-canonical contract kind should be rejected");
+        match err {
+            CoherenceError::ContractKindMismatch {
+                expected,
+                got,
+                path,
+                field,
+            } => {
+                assert_eq!(expected, "premath.control_plane.contract.v1");
+                assert_eq!(got, "premath.control_plane.contract.v0");
+                assert_eq!(path, display_path(case_path));
+                assert_eq!(field, "artifacts.controlPlaneContract.contractKind");
+            }
+            other => panic!("expected ContractKindMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_cross_lane_artifact_kind_duplicate() {
+        let temp = TempDirGuard::new("gate-chain-lane-kind-cross-lane-duplicate");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage1Parity"]["failureClasses"]["mismatch"] = json!("ev.parity.mismatch");
+        payload["laneArtifactKinds"]["strict_checker"] =
+            json!(["coherence_obligation", "square_witness"]);
         write_json_file(
             &temp
                 .path()
@@ -6885,46 +11637,104 @@ Current deterministic projected check IDs include:
 
         let evaluated =
             check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_STAGE1_PARITY_MISMATCH_FAILURE.to_string())
+        assert_eq!(
+            evaluated.failure_classes,
+            vec![GATE_CHAIN_LANE_ARTIFACT_KIND_CROSS_LANE_DUPLICATE_FAILURE.to_string()]
+        );
+        assert_eq!(
+            evaluated.details["laneRegistry"]["laneArtifactKindCrossLaneDuplicates"],
+            json!({"square_witness": ["strict_checker", "witness_commutation"]})
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage1_missing_profile_kind() {
-        let temp = TempDirGuard::new("gate-chain-stage1-missing-profile-kind");
+    fn control_plane_completeness_full_reports_absent_sections() {
+        let repo_root = repo_root();
+        let mut payload =
+            read_json_value(&repo_root.join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"))
+                .expect("control plane contract should read");
+        payload["spanKindRegistry"] = json!(["run"]);
+        payload
+            .as_object_mut()
+            .expect("payload should be object")
+            .remove("evidenceStage1Rollback");
+        let parse = |payload: &Value| -> ControlPlaneProjectionContract {
+            serde_json::from_value(payload.clone()).expect("control plane contract should parse")
+        };
+
+        let partial = evaluate_control_plane_completeness(&parse(&payload));
+        assert!(partial.failure_classes.is_empty());
+        assert_eq!(partial.details["mode"], json!("partial"));
+        assert_eq!(partial.details["absentSections"], json!([]));
+
+        payload["completeness"] = json!("full");
+        let full = evaluate_control_plane_completeness(&parse(&payload));
+        assert_eq!(
+            full.failure_classes,
+            vec![GATE_CHAIN_SECTION_ABSENT_FAILURE.to_string()]
+        );
+        assert_eq!(
+            full.details["absentSections"],
+            json!(["evidenceStage1Rollback"])
+        );
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_lane_artifact_kind_outside_allowed_set() {
+        let temp = TempDirGuard::new("gate-chain-lane-kind-unknown");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage1Parity"]["profileKind"] = json!("");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
-        );
+        let allowed: BTreeSet<String> = payload["laneArtifactKinds"]
+            .as_object()
+            .expect("laneArtifactKinds should be an object")
+            .values()
+            .flat_map(|kinds| kinds.as_array().expect("kinds should be an array").clone())
+            .map(|kind| kind.as_str().expect("kind should be a string").to_string())
+            .collect();
+        payload["allowedLaneArtifactKinds"] = json!(allowed);
         let contract =
             test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        let contract_path = temp
+            .path()
+            .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated =
+        write_json_file(&contract_path, &payload);
+        let accepted =
             check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&GATE_CHAIN_STAGE1_PARITY_INVALID_FAILURE.to_string())
+        assert!(accepted.failure_classes.is_empty());
+        assert_eq!(
+            accepted.details["laneRegistry"]["unknownLaneArtifactKinds"],
+            json!([])
+        );
+
+        payload["laneArtifactKinds"]["runtime_transport"]
+            .as_array_mut()
+            .expect("runtime_transport kinds should be an array")
+            .push(json!("mystery_artifact"));
+        write_json_file(&contract_path, &payload);
+        let rejected =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert_eq!(
+            rejected.failure_classes,
+            vec![GATE_CHAIN_LANE_ARTIFACT_KIND_UNKNOWN_FAILURE.to_string()]
+        );
+        assert_eq!(
+            rejected.details["laneRegistry"]["unknownLaneArtifactKinds"],
+            json!(["mystery_artifact"])
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage1_rollback_missing_trigger_classes() {
-        let temp = TempDirGuard::new("gate-chain-stage1-rollback-missing-triggers");
+    fn check_gate_chain_parity_rejects_missing_schema_lifecycle() {
+        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-missing");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage1Rollback"]["triggerFailureClasses"] =
-            json!(["unification.evidence_stage1.parity.missing"]);
+        payload
+            .as_object_mut()
+            .expect("payload should be object")
+            .remove("schemaLifecycle");
         write_json_file(
             &temp
                 .path()
@@ -6939,43 +11749,262 @@ Current deterministic projected check IDs include:
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE.to_string())
+                .contains(&GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string())
         );
     }
 
+    fn evaluate_schema_lifecycle_with_delta_kind(delta_kind: Option<&str>) -> ObligationCheck {
+        let mut payload = base_control_plane_contract_payload();
+        if let Some(delta_kind) = delta_kind {
+            payload["requiredWitness"]["deltaKind"] = json!(delta_kind);
+        }
+        let contract: ControlPlaneProjectionContract =
+            serde_json::from_value(payload).expect("control plane contract should parse");
+        evaluate_control_plane_schema_lifecycle(&contract, DEFAULT_CONTROL_PLANE_CONTRACT_KIND)
+    }
+
     #[test]
-    fn check_gate_chain_parity_rejects_stage1_rollback_unbound_binding_tuple() {
-        let temp = TempDirGuard::new("gate-chain-stage1-rollback-unbound-binding");
-        write_gate_chain_mise(&temp.path().join(".mise.toml"));
-        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+    fn schema_lifecycle_resolves_required_delta_kind() {
+        for delta_kind in [None, Some("ci.required.delta.v1"), Some("ci.delta.v1")] {
+            let evaluated = evaluate_schema_lifecycle_with_delta_kind(delta_kind);
+            assert!(
+                evaluated.failure_classes.is_empty(),
+                "{delta_kind:?}: {:?}",
+                evaluated.details["reasons"]
+            );
+            assert_eq!(
+                evaluated.details["resolvedKinds"]["requiredDeltaKind"],
+                json!("ci.required.delta.v1")
+            );
+        }
+    }
+
+    fn evaluate_schema_lifecycle_with_delta_aliases(aliases: Value) -> ObligationCheck {
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage1Rollback"]["identityRefs"]["policyDigestRef"] = json!("policy");
-        write_json_file(
-            &temp
-                .path()
-                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
-            &payload,
+        payload["schemaLifecycle"]["kindFamilies"]["requiredDeltaKind"]["compatibilityAliases"] =
+            aliases;
+        payload["requiredWitness"]["deltaKind"] = json!("ci.delta.v0");
+        let contract: ControlPlaneProjectionContract =
+            serde_json::from_value(payload).expect("control plane contract should parse");
+        evaluate_control_plane_schema_lifecycle(&contract, DEFAULT_CONTROL_PLANE_CONTRACT_KIND)
+    }
+
+    #[test]
+    fn schema_lifecycle_resolves_chained_alias_to_canonical_kind() {
+        let evaluated = evaluate_schema_lifecycle_with_delta_aliases(json!([
+            {
+                "aliasKind": "ci.delta.v0",
+                "supportUntilEpoch": "2026-06",
+                "replacementKind": "ci.delta.v1"
+            },
+            {
+                "aliasKind": "ci.delta.v1",
+                "supportUntilEpoch": "2026-06",
+                "replacementKind": "ci.required.delta.v1"
+            }
+        ]));
+        assert!(
+            evaluated.failure_classes.is_empty(),
+            "{:?}",
+            evaluated.details["reasons"]
         );
-        let contract =
-            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        assert_eq!(
+            evaluated.details["resolvedKinds"]["requiredDeltaKind"],
+            json!("ci.required.delta.v1")
+        );
+    }
 
-        let evaluated =
-            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+    #[test]
+    fn schema_lifecycle_rejects_chain_through_expired_hop() {
+        let evaluated = evaluate_schema_lifecycle_with_delta_aliases(json!([
+            {
+                "aliasKind": "ci.delta.v0",
+                "supportUntilEpoch": "2026-06",
+                "replacementKind": "ci.delta.v1"
+            },
+            {
+                "aliasKind": "ci.delta.v1",
+                "supportUntilEpoch": "2026-01",
+                "replacementKind": "ci.required.delta.v1"
+            }
+        ]));
+        assert_eq!(
+            evaluated.failure_classes,
+            vec![GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string()]
+        );
+        assert!(
+            evaluated.details["reasons"]
+                .as_array()
+                .expect("reasons should be an array")
+                .iter()
+                .any(|reason| reason.as_str().is_some_and(|reason| {
+                    reason.starts_with("requiredWitness.deltaKind: ")
+                        && reason.contains("expired at `2026-01`")
+                }))
+        );
+    }
+
+    #[test]
+    fn schema_lifecycle_rejects_cyclic_alias_chain() {
+        let evaluated = evaluate_schema_lifecycle_with_delta_aliases(json!([
+            {
+                "aliasKind": "ci.delta.v0",
+                "supportUntilEpoch": "2026-06",
+                "replacementKind": "ci.delta.v1"
+            },
+            {
+                "aliasKind": "ci.delta.v1",
+                "supportUntilEpoch": "2026-06",
+                "replacementKind": "ci.delta.v0"
+            }
+        ]));
+        assert_eq!(
+            evaluated.failure_classes,
+            vec![GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string()]
+        );
+        assert!(evaluated.details["resolvedKinds"]["requiredDeltaKind"].is_null());
+        assert!(
+            evaluated.details["reasons"]
+                .as_array()
+                .expect("reasons should be an array")
+                .iter()
+                .any(|reason| reason.as_str().is_some_and(|reason| {
+                    reason.starts_with("requiredWitness.deltaKind: ") && reason.contains("cycles")
+                }))
+        );
+    }
+
+    #[test]
+    fn control_plane_checks_honor_configured_contract_kind() {
+        let downstream_kind = "acme.control_plane.contract.v1";
+        let mut payload = base_control_plane_contract_payload();
+        payload["contractKind"] = json!(downstream_kind);
+        payload["schemaLifecycle"]["kindFamilies"]["controlPlaneContractKind"] = json!({
+            "canonicalKind": downstream_kind,
+            "compatibilityAliases": []
+        });
+        let contract: ControlPlaneProjectionContract =
+            serde_json::from_value(payload.clone()).expect("control plane contract should parse");
+
+        let lifecycle = evaluate_control_plane_schema_lifecycle(&contract, downstream_kind);
+        assert!(
+            lifecycle.failure_classes.is_empty(),
+            "{:?}",
+            lifecycle.details["reasons"]
+        );
+        assert_eq!(
+            lifecycle.details["resolvedKinds"]["contractKind"],
+            json!(downstream_kind)
+        );
+        let mismatched =
+            evaluate_control_plane_schema_lifecycle(&contract, DEFAULT_CONTROL_PLANE_CONTRACT_KIND);
+        assert_eq!(
+            mismatched.failure_classes,
+            vec![GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string()]
+        );
+
+        let case_path = Path::new("fixtures/gate_chain/case.json");
+        let artifacts = json!({
+            "caseContext": {"obligationId": "gate_chain_parity"},
+            "controlPlaneContract": payload,
+        });
+        evaluate_site_case_gate_chain_parity(&artifacts, case_path, downstream_kind)
+            .expect("configured contract kind should be accepted");
+        match evaluate_site_case_gate_chain_parity(
+            &artifacts,
+            case_path,
+            DEFAULT_CONTROL_PLANE_CONTRACT_KIND,
+        ) {
+            Err(CoherenceError::ContractKindMismatch { expected, got, .. }) => {
+                assert_eq!(expected, DEFAULT_CONTROL_PLANE_CONTRACT_KIND);
+                assert_eq!(got, downstream_kind);
+            }
+            other => panic!("expected ContractKindMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn schema_lifecycle_rejects_expired_or_unknown_required_delta_kind() {
+        let mut payload = base_control_plane_contract_payload();
+        payload["schemaLifecycle"]["activeEpoch"] = json!("2026-07");
+        payload["requiredWitness"]["deltaKind"] = json!("ci.delta.v1");
+        let contract: ControlPlaneProjectionContract =
+            serde_json::from_value(payload).expect("control plane contract should parse");
+        let expired =
+            evaluate_control_plane_schema_lifecycle(&contract, DEFAULT_CONTROL_PLANE_CONTRACT_KIND);
+        assert!(
+            expired
+                .failure_classes
+                .contains(&GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string())
+        );
+        assert!(expired.details["resolvedKinds"]["requiredDeltaKind"].is_null());
+        assert!(
+            expired.details["reasons"]
+                .as_array()
+                .expect("reasons should be an array")
+                .iter()
+                .any(|reason| reason
+                    .as_str()
+                    .is_some_and(|reason| reason.starts_with("requiredWitness.deltaKind: ")
+                        && reason.contains("expired")))
+        );
+
+        let unknown = evaluate_schema_lifecycle_with_delta_kind(Some("ci.delta.v2"));
+        assert!(
+            unknown
+                .failure_classes
+                .contains(&GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string())
+        );
+    }
+
+    #[test]
+    fn check_operation_reachability_accepts_matching_world_route_bindings() {
+        let temp = TempDirGuard::new("operation-reachability-world-routes-valid");
+        write_operation_reachability_surfaces(
+            temp.path(),
+            &["dm.identity", "dm.profile.execution"],
+            &["dm.identity", "dm.profile.execution"],
+        );
+        let contract = test_contract_for_operation_reachability();
+        let evaluated = check_operation_reachability(temp.path(), &contract)
+            .expect("operation reachability should evaluate");
+        assert!(evaluated.failure_classes.is_empty());
+        assert_eq!(
+            evaluated.details["worldRouteValidation"]["result"],
+            json!("accepted")
+        );
+    }
+
+    #[test]
+    fn check_operation_reachability_rejects_world_route_morphism_drift() {
+        let temp = TempDirGuard::new("operation-reachability-world-routes-drift");
+        write_operation_reachability_surfaces(
+            temp.path(),
+            &["dm.identity"],
+            &["dm.identity", "dm.transport.world"],
+        );
+        let contract = test_contract_for_operation_reachability();
+        let evaluated = check_operation_reachability(temp.path(), &contract)
+            .expect("operation reachability should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE.to_string())
+                .contains(&"world_route_morphism_drift".to_string())
+        );
+        assert_eq!(
+            evaluated.details["worldRouteValidation"]["result"],
+            json!("rejected")
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage1_rollback_failure_class_mismatch() {
-        let temp = TempDirGuard::new("gate-chain-stage1-rollback-class-mismatch");
+    fn check_gate_chain_parity_rejects_expired_schema_alias() {
+        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-expired-alias");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage1Rollback"]["failureClasses"]["identityDrift"] =
-            json!("ev.rollback.identity");
+        payload["requiredWitness"]["witnessKind"] = json!("ci.required.v0");
+        payload["schemaLifecycle"]["activeEpoch"] = json!("2026-07");
         write_json_file(
             &temp
                 .path()
@@ -6990,17 +12019,22 @@ Current deterministic projected check IDs include:
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE1_ROLLBACK_MISMATCH_FAILURE.to_string())
+                .contains(&GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage2_alias_role_mismatch() {
-        let temp = TempDirGuard::new("gate-chain-stage2-alias-role-mismatch");
+    fn check_gate_chain_parity_rejects_freeze_with_active_aliases() {
+        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-freeze-with-aliases");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage2Authority"]["compatibilityAlias"]["role"] = json!("authority");
+        payload["schemaLifecycle"]["governance"] = json!({
+            "mode": "freeze",
+            "decisionRef": "decision-0105",
+            "owner": "premath-core",
+            "freezeReason": "release freeze for schema cut"
+        });
         write_json_file(
             &temp
                 .path()
@@ -7015,18 +12049,110 @@ Current deterministic projected check IDs include:
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_VIOLATION_FAILURE.to_string())
+                .contains(&GATE_CHAIN_SCHEMA_LIFECYCLE_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage2_alias_window_mismatch() {
-        let temp = TempDirGuard::new("gate-chain-stage2-alias-window-mismatch");
+    fn check_gate_chain_parity_accepts_freeze_without_aliases() {
+        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-freeze-no-aliases");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage2Authority"]["compatibilityAlias"]["supportUntilEpoch"] =
-            json!("2026-07");
+        payload["schemaLifecycle"]["governance"] = json!({
+            "mode": "freeze",
+            "decisionRef": "decision-0105",
+            "owner": "premath-core",
+            "freezeReason": "release freeze for schema cut"
+        });
+        if let Some(kind_families) = payload["schemaLifecycle"]["kindFamilies"].as_object_mut() {
+            for family in kind_families.values_mut() {
+                family["compatibilityAliases"] = json!([]);
+            }
+        }
+        payload
+            .as_object_mut()
+            .expect("payload should be object")
+            .remove("evidenceStage2Authority");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(evaluated.failure_classes.is_empty());
+    }
+
+    #[test]
+    fn check_gate_chain_parity_bounds_freeze_reason_length() {
+        let temp = TempDirGuard::new("gate-chain-schema-lifecycle-freeze-reason-length");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        if let Some(kind_families) = payload["schemaLifecycle"]["kindFamilies"].as_object_mut() {
+            for family in kind_families.values_mut() {
+                family["compatibilityAliases"] = json!([]);
+            }
+        }
+        payload
+            .as_object_mut()
+            .expect("payload should be object")
+            .remove("evidenceStage2Authority");
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+        let contract_path = temp
+            .path()
+            .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let phrase_of = |len: usize| format!("a {}", "b".repeat(len - 2));
+        let cases = [
+            (
+                phrase_of(9),
+                vec![GATE_CHAIN_SCHEMA_LIFECYCLE_FREEZE_REASON_TOO_SHORT_FAILURE],
+            ),
+            (
+                "x".repeat(10),
+                vec![GATE_CHAIN_SCHEMA_LIFECYCLE_FREEZE_REASON_TOO_SHORT_FAILURE],
+            ),
+            (phrase_of(10), vec![]),
+            (format!("  {}  ", phrase_of(10)), vec![]),
+            (phrase_of(256), vec![]),
+            (
+                phrase_of(257),
+                vec![GATE_CHAIN_SCHEMA_LIFECYCLE_FREEZE_REASON_TOO_LONG_FAILURE],
+            ),
+        ];
+        for (freeze_reason, expected) in cases {
+            payload["schemaLifecycle"]["governance"] = json!({
+                "mode": "freeze",
+                "decisionRef": "decision-0105",
+                "owner": "premath-core",
+                "freezeReason": freeze_reason,
+            });
+            write_json_file(&contract_path, &payload);
+            let evaluated = check_gate_chain_parity(temp.path(), &contract)
+                .expect("gate parity should evaluate");
+            assert_eq!(
+                evaluated.failure_classes,
+                expected,
+                "freezeReason of {} chars",
+                freeze_reason.len()
+            );
+        }
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_duplicate_lane_ids() {
+        let temp = TempDirGuard::new("gate-chain-lane-registry-duplicate-ids");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceLanes"]["runtimeTransport"] = json!("strict_checker");
         write_json_file(
             &temp
                 .path()
@@ -7041,17 +12167,17 @@ Current deterministic projected check IDs include:
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE.to_string())
+                .contains(&"coherence.gate_chain_parity.lane_unknown".to_string())
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage2_unbound_binding_tuple() {
-        let temp = TempDirGuard::new("gate-chain-stage2-unbound-binding");
+    fn check_gate_chain_parity_rejects_unknown_lane_artifact_kind_mapping() {
+        let temp = TempDirGuard::new("gate-chain-lane-registry-unknown-lane-kind");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage2Authority"]["typedAuthority"]["policyDigestRef"] = json!("policy");
+        payload["laneArtifactKinds"]["unknown_lane"] = json!(["opaque_kind"]);
         write_json_file(
             &temp
                 .path()
@@ -7066,18 +12192,17 @@ Current deterministic projected check IDs include:
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE.to_string())
+                .contains(&"coherence.gate_chain_parity.lane_kind_unbound".to_string())
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage2_failure_class_mismatch() {
-        let temp = TempDirGuard::new("gate-chain-stage2-failure-class-mismatch");
+    fn check_gate_chain_parity_rejects_missing_cross_lane_route() {
+        let temp = TempDirGuard::new("gate-chain-lane-registry-missing-route");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage2Authority"]["failureClasses"]["unbound"] =
-            json!("unification.evidence_stage2.not_bound");
+        payload["laneOwnership"]["requiredCrossLaneWitnessRoute"] = Value::Null;
         write_json_file(
             &temp
                 .path()
@@ -7092,18 +12217,18 @@ Current deterministic projected check IDs include:
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE2_AUTHORITY_INVALID_FAILURE.to_string())
+                .contains(&"coherence.gate_chain_parity.lane_route_missing".to_string())
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage2_bidir_route_obligation_mismatch() {
-        let temp = TempDirGuard::new("gate-chain-stage2-bidir-route-obligation-mismatch");
+    fn check_gate_chain_parity_rejects_checker_core_ownership_violation() {
+        let temp = TempDirGuard::new("gate-chain-lane-registry-ownership-violation");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage2Authority"]["bidirEvidenceRoute"]["requiredObligations"] =
-            json!(["stability"]);
+        payload["laneOwnership"]["checkerCoreOnlyObligations"] =
+            json!(["cwf_substitution_identity", "span_square_commutation"]);
         write_json_file(
             &temp
                 .path()
@@ -7118,18 +12243,17 @@ Current deterministic projected check IDs include:
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE2_KERNEL_MISSING_FAILURE.to_string())
+                .contains(&"coherence.gate_chain_parity.lane_ownership_violation".to_string())
         );
     }
 
     #[test]
-    fn check_gate_chain_parity_rejects_stage2_bidir_route_failure_class_mismatch() {
-        let temp = TempDirGuard::new("gate-chain-stage2-bidir-route-class-mismatch");
+    fn check_gate_chain_parity_rejects_worker_lane_default_mode_drift() {
+        let temp = TempDirGuard::new("gate-chain-worker-lane-default-mode-drift");
         write_gate_chain_mise(&temp.path().join(".mise.toml"));
         write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
         let mut payload = base_control_plane_contract_payload();
-        payload["evidenceStage2Authority"]["bidirEvidenceRoute"]["failureClasses"]["drift"] =
-            json!("unification.evidence_stage2.kernel_drift");
+        payload["workerLaneAuthority"]["mutationPolicy"]["defaultMode"] = json!("human-override");
         write_json_file(
             &temp
                 .path()
@@ -7144,461 +12268,1673 @@ Current deterministic projected check IDs include:
         assert!(
             evaluated
                 .failure_classes
-                .contains(&GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string())
+                .contains(&GATE_CHAIN_WORKER_MUTATION_MODE_DRIFT_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn semantic_digest_is_order_invariant_for_transport_payloads() {
-        let a = json!({
-            "terms": [{"sym": "v"}, {"sym": "u"}, {"sym": "u"}],
-            "arrow": "id_fx",
-        });
-        let b = json!({
-            "arrow": "id_fx",
-            "terms": [{"sym": "u"}, {"sym": "v"}],
-        });
-        assert_eq!(semantic_digest(&a), semantic_digest(&b));
-    }
+    fn check_gate_chain_parity_rejects_worker_lane_route_drift() {
+        let temp = TempDirGuard::new("gate-chain-worker-lane-route-drift");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["workerLaneAuthority"]["mutationRoutes"]["issueDiscover"] = json!("issue_discover");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-    #[test]
-    fn evaluate_transport_case_detects_identity_violation() {
-        let case = json!({
-            "artifacts": {
-                "binding": {
-                    "normalizerId": "normalizer.coherence.v1",
-                    "policyDigest": "policy.coherence.v1",
-                },
-                "base": {
-                    "identity": {"arrow": "id_x"},
-                    "f": {"arrow": "f"},
-                    "g": {"arrow": "g"},
-                    "gAfterF": {"arrow": "g_after_f"},
-                },
-                "fibre": {
-                    "identity": {"arrow": "id_fx"},
-                    "FIdentity": {"arrow": "id_fx_bad"},
-                    "FF": {"arrow": "f_f"},
-                    "FG": {"arrow": "f_g"},
-                    "FGAfterF": {"arrow": "f_g_after_f"},
-                    "FGAfterFF": {"arrow": "f_g_after_f"},
-                },
-                "naturality": {
-                    "left": {"square": {"bottom": "g_f"}},
-                    "right": {"square": {"bottom": "g_f"}},
-                },
-            }
-        });
-        let evaluated = evaluate_transport_case(&case, Path::new("transport-case.json"))
-            .expect("transport case should evaluate");
-        assert_eq!(evaluated.result, "rejected");
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.transport_functoriality.identity_violation".to_string())
+                .contains(&GATE_CHAIN_WORKER_ROUTE_UNBOUND_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn check_transport_functoriality_requires_golden_polarity_vector() {
-        let temp = TempDirGuard::new("transport-missing-golden");
-        let fixture_root = temp.path().join("fixtures");
-        write_transport_manifest(&fixture_root, &["adversarial/only_reject"]);
-        write_transport_vector(&fixture_root, "adversarial/only_reject", "rejected");
-        let contract = test_contract_with_transport_fixture_root("fixtures");
+    fn check_gate_chain_parity_rejects_worker_lane_policy_drift() {
+        let temp = TempDirGuard::new("gate-chain-worker-lane-policy-drift");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["workerLaneAuthority"]["mutationPolicy"]["compatibilityOverrides"][0]["supportUntilEpoch"] =
+            json!("2026-01");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated = check_transport_functoriality(temp.path(), &contract)
-            .expect("transport should evaluate");
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.transport_functoriality.missing_golden_vector".to_string())
+                .contains(&GATE_CHAIN_WORKER_POLICY_DRIFT_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn check_transport_functoriality_requires_adversarial_polarity_vector() {
-        let temp = TempDirGuard::new("transport-missing-adversarial");
-        let fixture_root = temp.path().join("fixtures");
-        write_transport_manifest(&fixture_root, &["golden/only_accept"]);
-        write_transport_vector(&fixture_root, "golden/only_accept", "accepted");
-        let contract = test_contract_with_transport_fixture_root("fixtures");
+    fn check_gate_chain_parity_rejects_evidence_factorization_missing_route() {
+        let temp = TempDirGuard::new("gate-chain-evidence-factorization-missing-route");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceFactorization"]["factorizationRoutes"] = json!([]);
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated = check_transport_functoriality(temp.path(), &contract)
-            .expect("transport should evaluate");
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
-            evaluated.failure_classes.contains(
-                &"coherence.transport_functoriality.missing_adversarial_vector".to_string()
-            )
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_EVIDENCE_FACTORIZATION_MISSING_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn check_transport_functoriality_requires_expected_accept_result_vector() {
-        let temp = TempDirGuard::new("transport-missing-expected-accept");
-        let fixture_root = temp.path().join("fixtures");
-        write_transport_manifest(
-            &fixture_root,
-            &["golden/reject_vector", "adversarial/reject_vector"],
+    fn check_gate_chain_parity_rejects_evidence_factorization_ambiguous_routes() {
+        let temp = TempDirGuard::new("gate-chain-evidence-factorization-ambiguous-routes");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceFactorization"]["factorizationRoutes"] =
+            json!(["eta.route_a", "eta.route_b"]);
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
         );
-        write_transport_vector(&fixture_root, "golden/reject_vector", "rejected");
-        write_transport_vector(&fixture_root, "adversarial/reject_vector", "rejected");
-        let contract = test_contract_with_transport_fixture_root("fixtures");
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated = check_transport_functoriality(temp.path(), &contract)
-            .expect("transport should evaluate");
-        assert!(evaluated.failure_classes.contains(
-            &"coherence.transport_functoriality.missing_expected_accepted_vector".to_string()
-        ));
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_EVIDENCE_FACTORIZATION_AMBIGUOUS_FAILURE.to_string())
+        );
     }
 
     #[test]
-    fn check_transport_functoriality_requires_expected_reject_result_vector() {
-        let temp = TempDirGuard::new("transport-missing-expected-reject");
-        let fixture_root = temp.path().join("fixtures");
-        write_transport_manifest(
-            &fixture_root,
-            &["golden/accept_vector", "adversarial/accept_vector"],
-        );
-        write_transport_vector(&fixture_root, "golden/accept_vector", "accepted");
-        write_transport_vector(&fixture_root, "adversarial/accept_vector", "accepted");
-        let contract = test_contract_with_transport_fixture_root("fixtures");
+    fn check_gate_chain_parity_rejects_evidence_factorization_unbound_binding() {
+        let temp = TempDirGuard::new("gate-chain-evidence-factorization-unbound-binding");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceFactorization"]["binding"]["policyDigestRef"] = json!("policy");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated = check_transport_functoriality(temp.path(), &contract)
-            .expect("transport should evaluate");
-        assert!(evaluated.failure_classes.contains(
-            &"coherence.transport_functoriality.missing_expected_rejected_vector".to_string()
-        ));
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_EVIDENCE_FACTORIZATION_UNBOUND_FAILURE.to_string())
+        );
     }
 
     #[test]
-    fn check_transport_functoriality_accepts_when_both_polarities_present() {
-        let temp = TempDirGuard::new("transport-both-polarities");
-        let fixture_root = temp.path().join("fixtures");
-        write_transport_manifest(
-            &fixture_root,
-            &["golden/accept_vector", "adversarial/reject_vector"],
+    fn check_gate_chain_parity_rejects_stage1_missing_route() {
+        let temp = TempDirGuard::new("gate-chain-stage1-missing-route");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Parity"]["authorityToTypedCoreRoute"] = json!("");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
         );
-        write_transport_vector(&fixture_root, "golden/accept_vector", "accepted");
-        write_transport_vector(&fixture_root, "adversarial/reject_vector", "rejected");
-        let contract = test_contract_with_transport_fixture_root("fixtures");
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated = check_transport_functoriality(temp.path(), &contract)
-            .expect("transport should evaluate");
-        assert!(evaluated.failure_classes.is_empty());
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_STAGE1_PARITY_MISSING_FAILURE.to_string())
+        );
     }
 
     #[test]
-    fn check_transport_functoriality_requires_invariance_pair_count() {
-        let temp = TempDirGuard::new("transport-invariance-pair-count");
-        let fixture_root = temp.path().join("fixtures");
-        write_transport_manifest(
-            &fixture_root,
-            &[
-                "golden/functorial_transport_accept",
-                "adversarial/identity_violation_reject",
-                "invariance/permuted_payload_local_accept",
-            ],
-        );
-        write_transport_vector(
-            &fixture_root,
-            "golden/functorial_transport_accept",
-            "accepted",
-        );
-        write_transport_vector(
-            &fixture_root,
-            "adversarial/identity_violation_reject",
-            "rejected",
-        );
-        write_transport_vector_with_metadata(
-            &fixture_root,
-            "invariance/permuted_payload_local_accept",
-            "accepted",
-            Some("transport_functoriality_invariance_pair"),
-            Some("local"),
+    fn check_gate_chain_parity_rejects_stage1_unbound_binding_tuple() {
+        let temp = TempDirGuard::new("gate-chain-stage1-unbound-binding");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Parity"]["comparisonTuple"]["normalizerIdRef"] = json!("normalizer");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
         );
-        let contract = test_contract_with_transport_fixture_root("fixtures");
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated = check_transport_functoriality(temp.path(), &contract)
-            .expect("transport should evaluate");
-        assert!(evaluated.failure_classes.contains(
-            &"coherence.transport_functoriality.invariance_pair_count_mismatch".to_string()
-        ));
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_STAGE1_PARITY_UNBOUND_FAILURE.to_string())
+        );
     }
 
     #[test]
-    fn check_transport_functoriality_requires_invariance_pair_result_match() {
-        let temp = TempDirGuard::new("transport-invariance-result-mismatch");
-        let fixture_root = temp.path().join("fixtures");
-        write_transport_manifest(
-            &fixture_root,
-            &[
-                "golden/functorial_transport_accept",
-                "adversarial/identity_violation_reject",
-                "invariance/permuted_payload_local_accept",
-                "invariance/permuted_payload_external_reject",
-            ],
-        );
-        write_transport_vector(
-            &fixture_root,
-            "golden/functorial_transport_accept",
-            "accepted",
-        );
-        write_transport_vector(
-            &fixture_root,
-            "adversarial/identity_violation_reject",
-            "rejected",
-        );
-        write_transport_vector_with_metadata(
-            &fixture_root,
-            "invariance/permuted_payload_local_accept",
-            "accepted",
-            Some("transport_functoriality_invariance_pair"),
-            Some("local"),
-        );
-        write_transport_vector_with_metadata(
-            &fixture_root,
-            "invariance/permuted_payload_external_reject",
-            "rejected",
-            Some("transport_functoriality_invariance_pair"),
-            Some("external"),
+    fn check_gate_chain_parity_rejects_stage1_failure_class_mismatch() {
+        let temp = TempDirGuard::new("gate-chain-stage1-failure-class-mismatch");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Parity"]["failureClasses"]["mismatch"] = json!("ev.parity.mismatch");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
         );
-        let contract = test_contract_with_transport_fixture_root("fixtures");
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-        let evaluated = check_transport_functoriality(temp.path(), &contract)
-            .expect("transport should evaluate");
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
-            evaluated.failure_classes.contains(
-                &"coherence.transport_functoriality.invariance_result_mismatch".to_string()
-            )
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_STAGE1_PARITY_MISMATCH_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn check_transport_functoriality_accepts_with_invariance_pair() {
-        let temp = TempDirGuard::new("transport-invariance-pair-pass");
-        let fixture_root = temp.path().join("fixtures");
-        write_transport_manifest(
-            &fixture_root,
-            &[
-                "golden/functorial_transport_accept",
-                "adversarial/identity_violation_reject",
-                "invariance/permuted_payload_local_accept",
-                "invariance/permuted_payload_external_accept",
-            ],
-        );
-        write_transport_vector(
-            &fixture_root,
-            "golden/functorial_transport_accept",
-            "accepted",
-        );
-        write_transport_vector(
-            &fixture_root,
-            "adversarial/identity_violation_reject",
-            "rejected",
-        );
-        write_transport_vector_with_metadata(
-            &fixture_root,
-            "invariance/permuted_payload_local_accept",
-            "accepted",
-            Some("transport_functoriality_invariance_pair"),
-            Some("local"),
-        );
-        write_transport_vector_with_metadata(
-            &fixture_root,
-            "invariance/permuted_payload_external_accept",
-            "accepted",
-            Some("transport_functoriality_invariance_pair"),
-            Some("external"),
+    fn check_gate_chain_parity_rejects_stage1_missing_profile_kind() {
+        let temp = TempDirGuard::new("gate-chain-stage1-missing-profile-kind");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Parity"]["profileKind"] = json!("");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
         );
-        let contract = test_contract_with_transport_fixture_root("fixtures");
-
-        let evaluated = check_transport_functoriality(temp.path(), &contract)
-            .expect("transport should evaluate");
-        assert!(evaluated.failure_classes.is_empty());
-    }
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
 
-    #[test]
-    fn evaluate_site_case_coverage_base_change_detects_violation() {
-        let case = json!({
-            "coverage": {
-                "baseCover": {"parts": ["U1", "U2"]},
-                "pullbackCover": {"parts": ["U1_pb", "WRONG_pb"]},
-                "pullbackOfParts": [
-                    {"source": "U1", "pullback": "U1_pb"},
-                    {"source": "U2", "pullback": "U2_pb"}
-                ]
-            }
-        });
         let evaluated =
-            evaluate_site_case_coverage_base_change(&case, Path::new("site-case-base-change.json"))
-                .expect("site base-change case should evaluate");
-        assert_eq!(evaluated.result, "rejected");
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.coverage_base_change.violation".to_string())
+                .contains(&GATE_CHAIN_STAGE1_PARITY_INVALID_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn evaluate_site_case_coverage_transitivity_detects_violation() {
-        let case = json!({
-            "coverage": {
-                "outerCover": {"parts": ["U1", "U2"]},
-                "refinementCovers": [
-                    {"over": "U1", "parts": ["U11"]},
-                    {"over": "U3", "parts": ["U31"]}
-                ],
-                "composedCover": {"parts": ["U11"]}
-            }
-        });
-        let evaluated = evaluate_site_case_coverage_transitivity(
-            &case,
-            Path::new("site-case-transitivity.json"),
-        )
-        .expect("site transitivity case should evaluate");
-        assert_eq!(evaluated.result, "rejected");
+    fn check_gate_chain_parity_rejects_empty_check_order() {
+        let temp = TempDirGuard::new("gate-chain-empty-check-order");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_text_file(
+            &temp.path().join("docs/design/CI-CLOSURE.md"),
+            r#"Current full baseline gate (`mise run baseline`) includes:
+- `baseline`
+- `build`
+- `test`
+Local command:
+
+Current deterministic projected check IDs include:
+## 5. Variants and capability projection
+"#,
+        );
+        let mut payload = base_control_plane_contract_payload();
+        payload["requiredGateProjection"]["checkOrder"] = json!([]);
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.coverage_transitivity.violation".to_string())
+                .contains(&"coherence.gate_chain_parity.projection_check_order_empty".to_string())
+        );
+        assert!(
+            !evaluated
+                .failure_classes
+                .contains(&"coherence.gate_chain_parity.projection_set_mismatch".to_string())
         );
     }
 
     #[test]
-    fn evaluate_site_case_glue_or_witness_detects_missing_both() {
-        let case = json!({
-            "descent": {
-                "locals": [{"id": "s1"}, {"id": "s2"}],
-                "compatibilityWitnesses": []
-            }
-        });
-        let evaluated = evaluate_site_case_glue_or_witness_contractibility(
-            &case,
-            Path::new("site-case-glue-or-witness.json"),
-        )
-        .expect("site glue-or-witness case should evaluate");
-        assert_eq!(evaluated.result, "rejected");
+    fn check_gate_chain_parity_reports_baseline_task_missing_from_ci_closure() {
+        let temp = TempDirGuard::new("gate-chain-baseline-missing");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_text_file(
+            &temp.path().join("docs/design/CI-CLOSURE.md"),
+            r#"Current full baseline gate (`mise run baseline`) includes:
+- `baseline`
+- `build`
+Local command:
+
+Current deterministic projected check IDs include:
+- `baseline`
+- `build`
+- `test`
+## 5. Variants and capability projection
+"#,
+        );
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &base_control_plane_contract_payload(),
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert_eq!(
+            evaluated.failure_classes,
+            vec!["coherence.gate_chain_parity.baseline_set_mismatch".to_string()]
+        );
+        assert_eq!(evaluated.details["baselineMissing"], json!(["test"]));
+        assert_eq!(evaluated.details["baselineExtra"], json!([]));
+        assert_eq!(evaluated.details["projectionMissing"], json!([]));
+        assert_eq!(evaluated.details["projectionExtra"], json!([]));
+    }
+
+    #[test]
+    fn stage1_parity_localizes_missing_profile_kind_reason() {
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Parity"]["profileKind"] = json!("");
+        let contract: ControlPlaneProjectionContract =
+            serde_json::from_value(payload).expect("control plane contract should parse");
+
+        let evaluated = evaluate_control_plane_stage1_parity(&contract);
+        let message = "evidenceStage1Parity.profileKind must be non-empty";
+        assert_eq!(evaluated.details["reasons"], json!([message]));
+        assert_eq!(
+            evaluated.details["structuredReasons"],
+            json!([{
+                "field": "evidenceStage1Parity.profileKind",
+                "message": message,
+                "class": GATE_CHAIN_STAGE1_PARITY_INVALID_FAILURE,
+            }])
+        );
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_stage1_rollback_missing_trigger_classes() {
+        let temp = TempDirGuard::new("gate-chain-stage1-rollback-missing-triggers");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Rollback"]["triggerFailureClasses"] =
+            json!(["unification.evidence_stage1.parity.missing"]);
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.glue_or_witness_contractibility.violation".to_string())
+                .contains(&GATE_CHAIN_STAGE1_ROLLBACK_PRECONDITION_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn evaluate_site_case_cwf_substitution_identity_detects_violation() {
-        let case = json!({
-            "cwf": {
-                "substitution": {
-                    "types": [
-                        {"label": "A", "direct": {"type": "A"}, "afterIdentity": {"type": "A_bad"}}
-                    ],
-                    "terms": [
-                        {"label": "t", "direct": {"term": "t"}, "afterIdentity": {"term": "t"}}
-                    ]
-                }
-            }
-        });
-        let evaluated = evaluate_site_case_cwf_substitution_identity(
-            &case,
-            Path::new("site-case-cwf-substitution-identity.json"),
-        )
-        .expect("cwf substitution identity should evaluate");
-        assert_eq!(evaluated.result, "rejected");
+    fn check_gate_chain_parity_rejects_stage1_rollback_unbound_binding_tuple() {
+        let temp = TempDirGuard::new("gate-chain-stage1-rollback-unbound-binding");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Rollback"]["identityRefs"]["policyDigestRef"] = json!("policy");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.cwf_substitution_identity.violation".to_string())
+                .contains(&GATE_CHAIN_STAGE1_ROLLBACK_UNBOUND_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn evaluate_site_case_cwf_substitution_composition_detects_violation() {
-        let case = json!({
-            "cwf": {
-                "substitution": {
-                    "types": [
-                        {"label": "A", "afterCompose": {"type": "A_fg"}, "afterStepwise": {"type": "A_fg"}}
-                    ],
-                    "terms": [
-                        {"label": "t", "afterCompose": {"term": "t_fg"}, "afterStepwise": {"term": "t_bad"}}
-                    ]
-                }
-            }
-        });
-        let evaluated = evaluate_site_case_cwf_substitution_composition(
-            &case,
-            Path::new("site-case-cwf-substitution-composition.json"),
-        )
-        .expect("cwf substitution composition should evaluate");
-        assert_eq!(evaluated.result, "rejected");
+    fn check_gate_chain_parity_rejects_stage1_rollback_failure_class_mismatch() {
+        let temp = TempDirGuard::new("gate-chain-stage1-rollback-class-mismatch");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Rollback"]["failureClasses"]["identityDrift"] =
+            json!("ev.rollback.identity");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.cwf_substitution_composition.violation".to_string())
+                .contains(&GATE_CHAIN_STAGE1_ROLLBACK_MISMATCH_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn evaluate_site_case_cwf_comprehension_beta_detects_violation() {
-        let case = json!({
-            "cwf": {
-                "comprehension": {
-                    "beta": [
-                        {"label": "a", "original": {"term": "a"}, "afterBeta": {"term": "a_bad"}}
-                    ]
-                }
-            }
-        });
-        let evaluated = evaluate_site_case_cwf_comprehension_beta(
-            &case,
-            Path::new("site-case-cwf-comprehension-beta.json"),
-        )
-        .expect("cwf comprehension beta should evaluate");
-        assert_eq!(evaluated.result, "rejected");
+    fn check_gate_chain_parity_rejects_stage1_rollback_trigger_drift() {
+        let temp = TempDirGuard::new("gate-chain-stage1-rollback-trigger-drift");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage1Parity"]["failureClasses"]["missing"] =
+            json!("unification.evidence_stage1.parity.absent");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.cwf_comprehension_beta.violation".to_string())
+                .contains(&GATE_CHAIN_STAGE1_ROLLBACK_TRIGGER_DRIFT_FAILURE.to_string())
+        );
+        assert_eq!(
+            evaluated.details["stage1Rollback"]["parityDeclaredFailureClasses"],
+            json!([
+                "unification.evidence_stage1.parity.absent",
+                STAGE1_PARITY_CLASS_MISMATCH,
+                STAGE1_PARITY_CLASS_UNBOUND,
+            ])
         );
     }
 
     #[test]
-    fn evaluate_site_case_cwf_comprehension_eta_detects_violation() {
-        let case = json!({
-            "cwf": {
-                "comprehension": {
-                    "eta": [
-                        {"label": "sigma", "original": {"subst": "sigma"}, "afterEta": {"subst": "sigma_bad"}}
-                    ]
-                }
-            }
-        });
-        let evaluated = evaluate_site_case_cwf_comprehension_eta(
-            &case,
-            Path::new("site-case-cwf-comprehension-eta.json"),
-        )
-        .expect("cwf comprehension eta should evaluate");
-        assert_eq!(evaluated.result, "rejected");
+    fn check_gate_chain_parity_rejects_stage2_alias_role_mismatch() {
+        let temp = TempDirGuard::new("gate-chain-stage2-alias-role-mismatch");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage2Authority"]["compatibilityAlias"]["role"] = json!("authority");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.cwf_comprehension_eta.violation".to_string())
+                .contains(&GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_VIOLATION_FAILURE.to_string())
         );
     }
 
     #[test]
-    fn evaluate_site_case_span_square_commutation_detects_violation() {
-        let failure_classes: Vec<String> = Vec::new();
-        let case = json!({
-            "spanSquare": {
-                "spans": [
-                    {
-                        "id": "top",
-                        "kind": "pipeline",
-                        "left": {"ctx": "Gamma"},
+    fn check_gate_chain_parity_rejects_stage_typed_digest_ref_drift() {
+        let temp = TempDirGuard::new("gate-chain-stage-digest-ref-drift");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage2Authority"]["typedAuthority"]["digestRef"] =
+            json!("typedCoreProjectionDigestV2");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert_eq!(
+            evaluated.failure_classes,
+            vec![GATE_CHAIN_STAGE_DIGEST_REF_DRIFT_FAILURE.to_string()]
+        );
+        assert_eq!(
+            evaluated.details["stage2Authority"]["structuredReasons"][0]["field"],
+            json!("evidenceStage2Authority.typedAuthority.digestRef")
+        );
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_stage2_alias_window_mismatch() {
+        let temp = TempDirGuard::new("gate-chain-stage2-alias-window-mismatch");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage2Authority"]["compatibilityAlias"]["supportUntilEpoch"] =
+            json!("2026-07");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_STAGE2_AUTHORITY_ALIAS_WINDOW_FAILURE.to_string())
+        );
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_stage2_unbound_binding_tuple() {
+        let temp = TempDirGuard::new("gate-chain-stage2-unbound-binding");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage2Authority"]["typedAuthority"]["policyDigestRef"] = json!("policy");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_STAGE2_AUTHORITY_UNBOUND_FAILURE.to_string())
+        );
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_stage2_failure_class_mismatch() {
+        let temp = TempDirGuard::new("gate-chain-stage2-failure-class-mismatch");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage2Authority"]["failureClasses"]["unbound"] =
+            json!("unification.evidence_stage2.not_bound");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_STAGE2_AUTHORITY_INVALID_FAILURE.to_string())
+        );
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_stage2_bidir_route_obligation_mismatch() {
+        let temp = TempDirGuard::new("gate-chain-stage2-bidir-route-obligation-mismatch");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage2Authority"]["bidirEvidenceRoute"]["requiredObligations"] =
+            json!(["stability"]);
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_STAGE2_KERNEL_MISSING_FAILURE.to_string())
+        );
+    }
+
+    #[test]
+    fn check_gate_chain_parity_rejects_stage2_bidir_route_failure_class_mismatch() {
+        let temp = TempDirGuard::new("gate-chain-stage2-bidir-route-class-mismatch");
+        write_gate_chain_mise(&temp.path().join(".mise.toml"));
+        write_gate_chain_ci_closure(&temp.path().join("docs/design/CI-CLOSURE.md"));
+        let mut payload = base_control_plane_contract_payload();
+        payload["evidenceStage2Authority"]["bidirEvidenceRoute"]["failureClasses"]["drift"] =
+            json!("unification.evidence_stage2.kernel_drift");
+        write_json_file(
+            &temp
+                .path()
+                .join("specs/premath/draft/CONTROL-PLANE-CONTRACT.json"),
+            &payload,
+        );
+        let contract =
+            test_contract_for_gate_chain("specs/premath/draft/CONTROL-PLANE-CONTRACT.json");
+
+        let evaluated =
+            check_gate_chain_parity(temp.path(), &contract).expect("gate parity should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&GATE_CHAIN_STAGE2_KERNEL_DRIFT_FAILURE.to_string())
+        );
+    }
+
+    fn nested_object(levels: usize) -> Value {
+        let mut value = json!({"b": 1, "a": 2});
+        for level in 0..levels - 1 {
+            value = json!({format!("level{level}"): value});
+        }
+        value
+    }
+
+    #[test]
+    fn normalize_semantics_leaves_values_past_depth_limit_unchanged() {
+        let deep = nested_object(70);
+        assert!(exceeds_nesting_depth(&deep, SEMANTIC_NORMALIZE_MAX_DEPTH));
+        assert_eq!(normalize_semantics(&deep), deep);
+        assert!(!exceeds_nesting_depth(&deep, 70));
+        assert_eq!(
+            normalize_semantics_with_depth_limit(&deep, 70),
+            normalize_semantics_within_limit(&deep)
+        );
+
+        let digest = semantic_digest(&deep, DigestScheme::default());
+        assert!(digest.starts_with("semantic_digest_depth_exceeded1_"));
+        assert!(
+            semantic_digest(&deep, DigestScheme::new(2))
+                .starts_with("semantic_digest_depth_exceeded2_")
+        );
+        assert_eq!(
+            digest,
+            semantic_digest(&deep.clone(), DigestScheme::default())
+        );
+        assert!(semantic_digest(&nested_object(64), DigestScheme::default()).starts_with("sem1_"));
+
+        let ((), exceeded) = with_semantic_depth_tracking(|| {
+            assert_eq!(sanitize_value_for_digest(&deep), deep);
+        });
+        assert!(exceeded);
+        let ((), exceeded) = with_semantic_depth_tracking(|| {
+            sanitize_value_for_digest(&nested_object(64));
+        });
+        assert!(!exceeded);
+    }
+
+    #[test]
+    fn semantic_depth_limit_hits_flag_the_obligation() {
+        let ((), exceeded) = with_semantic_depth_tracking(|| {
+            semantic_digest(&nested_object(64), DigestScheme::default());
+        });
+        assert!(!exceeded);
+        let ((), exceeded) = with_semantic_depth_tracking(|| {
+            semantic_digest(&nested_object(70), DigestScheme::default());
+        });
+        assert!(exceeded);
+        let ((), exceeded) = with_semantic_depth_tracking(|| {
+            normalize_semantics(&nested_object(70));
+        });
+        assert!(exceeded);
+
+        let mut details = json!({"warnings": ["coherence.demo.pending_vector"]});
+        mark_semantic_depth_exceeded(&mut details, "demo");
+        mark_semantic_depth_exceeded(&mut details, "demo");
+        assert_eq!(
+            details,
+            json!({
+                "semanticDepthExceeded": true,
+                "warnings": [
+                    "coherence.demo.pending_vector",
+                    "coherence.demo.semantic_depth_exceeded"
+                ]
+            })
+        );
+        let mut details = json!({});
+        mark_semantic_depth_exceeded(&mut details, "demo");
+        assert_eq!(
+            details["warnings"],
+            json!(["coherence.demo.semantic_depth_exceeded"])
+        );
+    }
+
+    #[test]
+    fn digests_replace_null_byte_strings_with_sentinel() {
+        let dirty = json!({"span": "run\u{0}on_base", "tags": ["ok", "a\u{0}b"], "k\u{0}": 1});
+        assert_eq!(
+            sanitize_value_for_digest(&dirty),
+            json!({
+                "span": DIGEST_NULL_BYTE_SENTINEL,
+                "tags": ["ok", DIGEST_NULL_BYTE_SENTINEL],
+                DIGEST_NULL_BYTE_SENTINEL: 1
+            })
+        );
+        assert_eq!(
+            semantic_digest(&dirty, DigestScheme::default()),
+            semantic_digest(&sanitize_value_for_digest(&dirty), DigestScheme::default())
+        );
+
+        let left = json!({"span": "a\u{0}"});
+        let sentinel = json!({"span": DIGEST_NULL_BYTE_SENTINEL});
+        let right = json!({"span": "b"});
+        assert_eq!(
+            composition_law_digest(
+                "span_identity",
+                "left",
+                &left,
+                &right,
+                "accepted",
+                &[],
+                DigestScheme::default()
+            ),
+            composition_law_digest(
+                "span_identity",
+                "left",
+                &sentinel,
+                &right,
+                "accepted",
+                &[],
+                DigestScheme::default()
+            )
+        );
+        let clean = json!({"span": "a"});
+        assert_eq!(sanitize_value_for_digest(&clean), clean);
+    }
+
+    #[test]
+    fn semantic_digest_is_order_invariant_for_transport_payloads() {
+        let a = json!({
+            "terms": [{"sym": "v"}, {"sym": "u"}, {"sym": "u"}],
+            "arrow": "id_fx",
+        });
+        let b = json!({
+            "arrow": "id_fx",
+            "terms": [{"sym": "u"}, {"sym": "v"}],
+        });
+        assert_eq!(
+            semantic_digest(&a, DigestScheme::default()),
+            semantic_digest(&b, DigestScheme::default())
+        );
+    }
+
+    #[test]
+    fn sort_object_keys_is_insertion_order_independent_and_keeps_arrays() {
+        let mut forward = Map::new();
+        forward.insert("alpha".to_string(), json!({"b": 1, "a": 2}));
+        forward.insert("vectors".to_string(), json!(["z", "a", "z"]));
+        forward.insert("zeta".to_string(), json!([{"y": 1, "x": 2}]));
+        let mut reverse = Map::new();
+        reverse.insert("zeta".to_string(), json!([{"x": 2, "y": 1}]));
+        reverse.insert("vectors".to_string(), json!(["z", "a", "z"]));
+        reverse.insert("alpha".to_string(), json!({"a": 2, "b": 1}));
+
+        let forward = sort_object_keys(&Value::Object(forward));
+        let reverse = sort_object_keys(&Value::Object(reverse));
+        assert_eq!(
+            serde_json::to_vec(&forward).expect("serialize"),
+            serde_json::to_vec(&reverse).expect("serialize")
+        );
+        assert_eq!(
+            serde_json::to_string(&forward).expect("serialize"),
+            r#"{"alpha":{"a":2,"b":1},"vectors":["z","a","z"],"zeta":[{"x":2,"y":1}]}"#
+        );
+    }
+
+    #[test]
+    fn evaluate_transport_case_detects_identity_violation() {
+        let case = json!({
+            "artifacts": {
+                "binding": {
+                    "normalizerId": "normalizer.coherence.v1",
+                    "policyDigest": "policy.coherence.v1",
+                },
+                "base": {
+                    "identity": {"arrow": "id_x"},
+                    "f": {"arrow": "f"},
+                    "g": {"arrow": "g"},
+                    "gAfterF": {"arrow": "g_after_f"},
+                },
+                "fibre": {
+                    "identity": {"arrow": "id_fx"},
+                    "FIdentity": {"arrow": "id_fx_bad"},
+                    "FF": {"arrow": "f_f"},
+                    "FG": {"arrow": "f_g"},
+                    "FGAfterF": {"arrow": "f_g_after_f"},
+                    "FGAfterFF": {"arrow": "f_g_after_f"},
+                },
+                "naturality": {
+                    "left": {"square": {"bottom": "g_f"}},
+                    "right": {"square": {"bottom": "g_f"}},
+                },
+            }
+        });
+        let evaluated = evaluate_transport_case(
+            &case,
+            Path::new("transport-case.json"),
+            DigestScheme::default(),
+        )
+        .expect("transport case should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.transport_functoriality.identity_violation".to_string())
+        );
+    }
+
+    #[test]
+    fn evaluate_transport_case_rejects_incomplete_naturality_square() {
+        let mut case = json!({
+            "artifacts": {
+                "binding": {
+                    "normalizerId": "normalizer.coherence.v1",
+                    "policyDigest": "policy.coherence.v1",
+                },
+                "base": {
+                    "identity": {"arrow": "id_x"},
+                    "f": {"arrow": "f"},
+                    "g": {"arrow": "g"},
+                    "gAfterF": {"arrow": "g_after_f"},
+                },
+                "fibre": {
+                    "identity": {"arrow": "id_fx"},
+                    "FIdentity": {"arrow": "id_fx"},
+                    "FF": {"arrow": "f_f"},
+                    "FG": {"arrow": "f_g"},
+                    "FGAfterF": {"arrow": "f_g_after_f"},
+                    "FGAfterFF": {"arrow": "f_g_after_f"},
+                },
+                "naturality": {
+                    "left": {"square": {"bottom": "g_f"}},
+                    "right": {"square": {"bottom": "g_f"}},
+                },
+            }
+        });
+        let evaluated = evaluate_transport_case(
+            &case,
+            Path::new("transport-case.json"),
+            DigestScheme::default(),
+        )
+        .expect("transport case should evaluate");
+        assert_eq!(evaluated.result, "accepted");
+        assert_eq!(
+            evaluated.details["digests"]["naturality"]["leftIsComplete"],
+            json!(true)
+        );
+        assert_eq!(
+            evaluated.details["digests"]["naturality"]["rightIsComplete"],
+            json!(true)
+        );
+
+        for (left, right) in [
+            (Value::Null, Value::Null),
+            (json!({}), json!({})),
+            (json!({"square": {"bottom": "g_f"}}), json!({})),
+        ] {
+            case["artifacts"]["naturality"] = json!({"left": left.clone(), "right": right.clone()});
+            let evaluated = evaluate_transport_case(
+                &case,
+                Path::new("transport-case.json"),
+                DigestScheme::default(),
+            )
+            .expect("transport case should evaluate");
+            assert_eq!(evaluated.result, "rejected");
+            assert!(evaluated.failure_classes.contains(
+                &"coherence.transport_functoriality.naturality_square_incomplete".to_string()
+            ));
+            assert_eq!(
+                evaluated.details["digests"]["naturality"]["leftIsComplete"],
+                json!(is_complete_naturality_side(&left))
+            );
+            assert_eq!(
+                evaluated.details["digests"]["naturality"]["rightIsComplete"],
+                json!(is_complete_naturality_side(&right))
+            );
+        }
+    }
+
+    #[test]
+    fn polarity_balance_score_tracks_golden_adversarial_skew() {
+        let coverage = |golden: usize, adversarial: usize| PolarityCoverage {
+            matched_golden_count: golden,
+            matched_adversarial_count: adversarial,
+            matched_invariance_count: 2,
+            ..PolarityCoverage::default()
+        };
+        let warnings_for = |coverage: PolarityCoverage| {
+            let mut warnings = Vec::new();
+            coverage.emit_balance_warning(&mut warnings, "coherence.test");
+            warnings
+        };
+
+        let balanced = coverage(3, 3);
+        assert_eq!(balanced.balance_score(), 1.0);
+        assert_eq!(balanced.total_vectors(), 8);
+        assert!(warnings_for(balanced).is_empty());
+        assert_eq!(coverage(4, 2).balance_score(), 0.5);
+        assert!(warnings_for(coverage(4, 2)).is_empty());
+
+        let one_sided = coverage(5, 0);
+        assert_eq!(one_sided.balance_score(), 0.0);
+        assert_eq!(
+            warnings_for(one_sided),
+            vec!["coherence.test.polarity_imbalance".to_string()]
+        );
+        assert_eq!(
+            warnings_for(coverage(1, 3)),
+            vec!["coherence.test.polarity_imbalance".to_string()]
+        );
+
+        let empty = PolarityCoverage::default();
+        assert_eq!(empty.total_vectors(), 0);
+        assert_eq!(empty.balance_score(), 1.0);
+        assert!(warnings_for(empty).is_empty());
+    }
+
+    #[test]
+    fn check_transport_functoriality_requires_golden_polarity_vector() {
+        let temp = TempDirGuard::new("transport-missing-golden");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(&fixture_root, &["adversarial/only_reject"]);
+        write_transport_vector(&fixture_root, "adversarial/only_reject", "rejected");
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.transport_functoriality.missing_golden_vector".to_string())
+        );
+        assert_eq!(evaluated.details["polarityBalanceScore"], json!(0.0));
+        assert_eq!(
+            evaluated.details["warnings"],
+            json!(["coherence.transport_functoriality.polarity_imbalance"])
+        );
+    }
+
+    #[test]
+    fn check_transport_functoriality_requires_adversarial_polarity_vector() {
+        let temp = TempDirGuard::new("transport-missing-adversarial");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(&fixture_root, &["golden/only_accept"]);
+        write_transport_vector(&fixture_root, "golden/only_accept", "accepted");
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(
+            evaluated.failure_classes.contains(
+                &"coherence.transport_functoriality.missing_adversarial_vector".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn check_transport_functoriality_requires_expected_accept_result_vector() {
+        let temp = TempDirGuard::new("transport-missing-expected-accept");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(
+            &fixture_root,
+            &["golden/reject_vector", "adversarial/reject_vector"],
+        );
+        write_transport_vector(&fixture_root, "golden/reject_vector", "rejected");
+        write_transport_vector(&fixture_root, "adversarial/reject_vector", "rejected");
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(evaluated.failure_classes.contains(
+            &"coherence.transport_functoriality.missing_expected_accepted_vector".to_string()
+        ));
+    }
+
+    #[test]
+    fn check_transport_functoriality_requires_expected_reject_result_vector() {
+        let temp = TempDirGuard::new("transport-missing-expected-reject");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(
+            &fixture_root,
+            &["golden/accept_vector", "adversarial/accept_vector"],
+        );
+        write_transport_vector(&fixture_root, "golden/accept_vector", "accepted");
+        write_transport_vector(&fixture_root, "adversarial/accept_vector", "accepted");
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(evaluated.failure_classes.contains(
+            &"coherence.transport_functoriality.missing_expected_rejected_vector".to_string()
+        ));
+    }
+
+    #[test]
+    fn check_transport_functoriality_accepts_when_both_polarities_present() {
+        let temp = TempDirGuard::new("transport-both-polarities");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(
+            &fixture_root,
+            &["golden/accept_vector", "adversarial/reject_vector"],
+        );
+        write_transport_vector(&fixture_root, "golden/accept_vector", "accepted");
+        write_transport_vector(&fixture_root, "adversarial/reject_vector", "rejected");
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(evaluated.failure_classes.is_empty());
+    }
+
+    #[test]
+    fn check_transport_functoriality_counts_skipped_and_warns_on_pending_vectors() {
+        let temp = TempDirGuard::new("transport-skipped-pending");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(
+            &fixture_root,
+            &[
+                "golden/accept_vector",
+                "adversarial/reject_vector",
+                "golden/skipped_vector",
+                "adversarial/pending_vector",
+            ],
+        );
+        write_transport_vector(&fixture_root, "golden/accept_vector", "accepted");
+        write_transport_vector(&fixture_root, "adversarial/reject_vector", "rejected");
+        for (vector_id, status) in [
+            ("golden/skipped_vector", "skipped"),
+            ("adversarial/pending_vector", "pending"),
+        ] {
+            write_json_file(
+                &fixture_root.join(vector_id).join("case.json"),
+                &json!({"schema": 1, "status": status, "vectorId": vector_id}),
+            );
+        }
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(evaluated.failure_classes.is_empty());
+        assert_eq!(
+            evaluated.details["skippedVectors"],
+            json!(["golden/skipped_vector"])
+        );
+        assert_eq!(
+            evaluated.details["pendingVectors"],
+            json!(["adversarial/pending_vector"])
+        );
+        assert_eq!(
+            evaluated.details["warnings"],
+            json!(["coherence.transport_functoriality.pending_vector"])
+        );
+    }
+
+    #[test]
+    fn check_transport_functoriality_skips_only_non_executable_manifest_entries() {
+        let temp = TempDirGuard::new("transport-manifest-skipped");
+        let fixture_root = temp.path().join("fixtures");
+        write_json_file(
+            &fixture_root.join("manifest.json"),
+            &json!({
+                "schema": 1,
+                "status": "executable",
+                "vectors": ["golden/missing", "golden/also_missing"],
+                "vectorStatuses": {"golden/missing": "skipped"}
+            }),
+        );
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert_eq!(
+            evaluated.details["skippedVectors"],
+            json!(["golden/missing"])
+        );
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.transport_functoriality.vector_case_invalid".to_string())
+        );
+
+        write_json_file(
+            &fixture_root.join("manifest.json"),
+            &json!({"schema": 1, "status": "skipped", "vectors": ["golden/missing"]}),
+        );
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.transport_functoriality.manifest_invalid_status".to_string())
+        );
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.transport_functoriality.vector_case_invalid".to_string())
+        );
+    }
+
+    #[test]
+    fn detect_scenario_id_namespace_collisions_pairs_dot_prefixes() {
+        let row = |vector_id: &str| {
+            (
+                vector_id.to_string(),
+                "profile_a".to_string(),
+                "accepted".to_string(),
+                Vec::new(),
+            )
+        };
+        let groups: InvarianceGroups = [
+            "foo.bar",
+            "foo.bar.baz",
+            "foo.bar.baz.qux",
+            "foo.barn",
+            "foo.bar-x",
+            "other",
+        ]
+        .into_iter()
+        .map(|id| (id.to_string(), vec![row(id)]))
+        .collect();
+
+        let pair = |a: &str, b: &str| (a.to_string(), b.to_string());
+        assert_eq!(
+            detect_scenario_id_namespace_collisions(&groups),
+            vec![
+                pair("foo.bar", "foo.bar.baz"),
+                pair("foo.bar", "foo.bar.baz.qux"),
+                pair("foo.bar.baz", "foo.bar.baz.qux"),
+            ]
+        );
+
+        let mut failures = Vec::new();
+        validate_invariance_groups(&mut failures, "coherence.test", &groups);
+        assert_eq!(
+            failures
+                .iter()
+                .filter(
+                    |class| *class == "coherence.test.invariance_scenario_id_namespace_collision"
+                )
+                .count(),
+            3
+        );
+
+        let distinct: InvarianceGroups = ["foo.bar", "foo.barn", "foo_bar_baz"]
+            .into_iter()
+            .map(|id| (id.to_string(), vec![row(id)]))
+            .collect();
+        assert!(detect_scenario_id_namespace_collisions(&distinct).is_empty());
+    }
+
+    #[test]
+    fn check_transport_functoriality_requires_invariance_pair_count() {
+        let temp = TempDirGuard::new("transport-invariance-pair-count");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(
+            &fixture_root,
+            &[
+                "golden/functorial_transport_accept",
+                "adversarial/identity_violation_reject",
+                "invariance/permuted_payload_local_accept",
+            ],
+        );
+        write_transport_vector(
+            &fixture_root,
+            "golden/functorial_transport_accept",
+            "accepted",
+        );
+        write_transport_vector(
+            &fixture_root,
+            "adversarial/identity_violation_reject",
+            "rejected",
+        );
+        write_transport_vector_with_metadata(
+            &fixture_root,
+            "invariance/permuted_payload_local_accept",
+            "accepted",
+            Some("transport_functoriality_invariance_pair"),
+            Some("local"),
+        );
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(evaluated.failure_classes.contains(
+            &"coherence.transport_functoriality.invariance_pair_count_mismatch".to_string()
+        ));
+    }
+
+    #[test]
+    fn check_transport_functoriality_requires_invariance_pair_result_match() {
+        let temp = TempDirGuard::new("transport-invariance-result-mismatch");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(
+            &fixture_root,
+            &[
+                "golden/functorial_transport_accept",
+                "adversarial/identity_violation_reject",
+                "invariance/permuted_payload_local_accept",
+                "invariance/permuted_payload_external_reject",
+            ],
+        );
+        write_transport_vector(
+            &fixture_root,
+            "golden/functorial_transport_accept",
+            "accepted",
+        );
+        write_transport_vector(
+            &fixture_root,
+            "adversarial/identity_violation_reject",
+            "rejected",
+        );
+        write_transport_vector_with_metadata(
+            &fixture_root,
+            "invariance/permuted_payload_local_accept",
+            "accepted",
+            Some("transport_functoriality_invariance_pair"),
+            Some("local"),
+        );
+        write_transport_vector_with_metadata(
+            &fixture_root,
+            "invariance/permuted_payload_external_reject",
+            "rejected",
+            Some("transport_functoriality_invariance_pair"),
+            Some("external"),
+        );
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(
+            evaluated.failure_classes.contains(
+                &"coherence.transport_functoriality.invariance_result_mismatch".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn check_transport_functoriality_accepts_with_invariance_pair() {
+        let temp = TempDirGuard::new("transport-invariance-pair-pass");
+        let fixture_root = temp.path().join("fixtures");
+        write_transport_manifest(
+            &fixture_root,
+            &[
+                "golden/functorial_transport_accept",
+                "adversarial/identity_violation_reject",
+                "invariance/permuted_payload_local_accept",
+                "invariance/permuted_payload_external_accept",
+            ],
+        );
+        write_transport_vector(
+            &fixture_root,
+            "golden/functorial_transport_accept",
+            "accepted",
+        );
+        write_transport_vector(
+            &fixture_root,
+            "adversarial/identity_violation_reject",
+            "rejected",
+        );
+        write_transport_vector_with_metadata(
+            &fixture_root,
+            "invariance/permuted_payload_local_accept",
+            "accepted",
+            Some("transport_functoriality_invariance_pair"),
+            Some("local"),
+        );
+        write_transport_vector_with_metadata(
+            &fixture_root,
+            "invariance/permuted_payload_external_accept",
+            "accepted",
+            Some("transport_functoriality_invariance_pair"),
+            Some("external"),
+        );
+        let contract = test_contract_with_transport_fixture_root("fixtures");
+
+        let evaluated = check_transport_functoriality(temp.path(), &contract)
+            .expect("transport should evaluate");
+        assert!(evaluated.failure_classes.is_empty());
+    }
+
+    #[test]
+    fn evaluate_site_case_coverage_base_change_detects_violation() {
+        let case = json!({
+            "coverage": {
+                "baseCover": {"parts": ["U1", "U2"]},
+                "pullbackCover": {"parts": ["U1_pb", "WRONG_pb"]},
+                "pullbackOfParts": [
+                    {"source": "U1", "pullback": "U1_pb"},
+                    {"source": "U2", "pullback": "U2_pb"}
+                ]
+            }
+        });
+        let evaluated = evaluate_site_case_coverage_base_change(
+            &case,
+            Path::new("site-case-base-change.json"),
+            DigestScheme::default(),
+        )
+        .expect("site base-change case should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.coverage_base_change.violation".to_string())
+        );
+    }
+
+    #[test]
+    fn evaluate_site_case_coverage_base_change_multiset_compares_multiplicities() {
+        let mut case = json!({
+            "coverage": {
+                "baseCover": {"parts": ["U1", "U1", "U2"]},
+                "pullbackCover": {"parts": ["U1_pb", "U1_pb", "U2_pb"]},
+                "pullbackOfParts": [
+                    {"source": "U1", "pullback": "U1_pb"},
+                    {"source": "U1", "pullback": "U1_pb"},
+                    {"source": "U2", "pullback": "U2_pb"}
+                ]
+            }
+        });
+        let path = Path::new("site-case-base-change.json");
+        let evaluated =
+            evaluate_site_case_coverage_base_change(&case, path, DigestScheme::default())
+                .expect("site base-change case should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+
+        case["coverage"]["multiset"] = json!(true);
+        let evaluated =
+            evaluate_site_case_coverage_base_change(&case, path, DigestScheme::default())
+                .expect("site base-change case should evaluate");
+        assert_eq!(evaluated.result, "accepted");
+        assert_eq!(evaluated.details["multiset"], json!(true));
+
+        case["coverage"]["pullbackOfParts"]
+            .as_array_mut()
+            .expect("mapping array")
+            .remove(1);
+        let evaluated =
+            evaluate_site_case_coverage_base_change(&case, path, DigestScheme::default())
+                .expect("site base-change case should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+
+        case["coverage"]["multiset"] = json!("yes");
+        assert!(
+            evaluate_site_case_coverage_base_change(&case, path, DigestScheme::default()).is_err()
+        );
+    }
+
+    #[test]
+    fn evaluate_site_case_coverage_transitivity_detects_violation() {
+        let case = json!({
+            "coverage": {
+                "outerCover": {"parts": ["U1", "U2"]},
+                "refinementCovers": [
+                    {"over": "U1", "parts": ["U11"]},
+                    {"over": "U3", "parts": ["U31"]}
+                ],
+                "composedCover": {"parts": ["U11"]}
+            }
+        });
+        let evaluated = evaluate_site_case_coverage_transitivity(
+            &case,
+            Path::new("site-case-transitivity.json"),
+            DigestScheme::default(),
+        )
+        .expect("site transitivity case should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.coverage_transitivity.violation".to_string())
+        );
+    }
+
+    #[test]
+    fn gate_chain_and_projection_share_task_set_normalization() {
+        let raw: Vec<String> = [" baseline", "build", "", "baseline ", "  ", "Build"]
+            .iter()
+            .map(|item| (*item).to_string())
+            .collect();
+        let gate_chain = sorted_vec_from_set(&normalize_task_set(&raw));
+        assert_eq!(gate_chain, vec!["Build", "baseline", "build"]);
+        assert_eq!(normalize_projection_paths(&raw), gate_chain);
+    }
+
+    #[test]
+    fn evaluate_site_case_coverage_transitivity_checks_composition_associativity() {
+        let mut case = json!({
+            "coverage": {
+                "outerCover": {"parts": ["U1"]},
+                "refinementCovers": [{"over": "U1", "parts": ["U11", "U12"]}],
+                "composedCover": {"parts": ["U11", "U12"]},
+                "compositionAssociativity": {
+                    "leftAssociated": {"parts": ["U111", "U121"]},
+                    "rightAssociated": {"parts": ["U121", "U111"]},
+                    "expectedCover": {"parts": ["U111", "U121"]}
+                }
+            }
+        });
+        let path = Path::new("site-case-transitivity-associativity.json");
+        let evaluated =
+            evaluate_site_case_coverage_transitivity(&case, path, DigestScheme::default())
+                .expect("site transitivity case should evaluate");
+        assert_eq!(evaluated.result, "accepted");
+        assert_eq!(
+            evaluated.details["compositionAssociativity"]["rightAssociated"],
+            json!(["U111", "U121"])
+        );
+
+        case["coverage"]["compositionAssociativity"]["rightAssociated"]["parts"] =
+            json!(["U111", "U122"]);
+        let evaluated =
+            evaluate_site_case_coverage_transitivity(&case, path, DigestScheme::default())
+                .expect("site transitivity case should evaluate");
+        assert_eq!(
+            evaluated.failure_classes,
+            vec!["coherence.coverage_transitivity.associativity_violation".to_string()]
+        );
+    }
+
+    #[test]
+    fn evaluate_site_case_glue_or_witness_detects_missing_both() {
+        let case = json!({
+            "descent": {
+                "locals": [{"id": "s1"}, {"id": "s2"}],
+                "compatibilityWitnesses": []
+            }
+        });
+        let evaluated = evaluate_site_case_glue_or_witness_contractibility(
+            &case,
+            Path::new("site-case-glue-or-witness.json"),
+            DigestScheme::default(),
+        )
+        .expect("site glue-or-witness case should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.glue_or_witness_contractibility.violation".to_string())
+        );
+    }
+
+    #[test]
+    fn overlap_joins_declared_locals_allows_underscored_ids() {
+        let local_ids: BTreeSet<&str> = ["s1", "s_2", "s_2_b"].into_iter().collect();
+        assert!(overlap_joins_declared_locals("s1_s_2", &local_ids));
+        assert!(overlap_joins_declared_locals("s_2_b_s1", &local_ids));
+        assert!(!overlap_joins_declared_locals("s1_s3", &local_ids));
+        assert!(!overlap_joins_declared_locals("", &local_ids));
+    }
+
+    #[test]
+    fn evaluate_site_case_glue_or_witness_restricts_obstruction_classes() {
+        let path = Path::new("site-case-glue-or-witness.json");
+        let mut case = json!({
+            "descent": {
+                "locals": [{"id": "s1"}],
+                "compatibilityWitnesses": [],
+                "obstruction": {"class": "descent_falure"}
+            }
+        });
+        let evaluated = evaluate_site_case_glue_or_witness_contractibility(
+            &case,
+            path,
+            DigestScheme::default(),
+        )
+        .expect("site glue-or-witness case should evaluate");
+        assert_eq!(evaluated.result, "accepted");
+
+        case["descent"]["allowedObstructionClasses"] = json!(["descent_failure"]);
+        let evaluated = evaluate_site_case_glue_or_witness_contractibility(
+            &case,
+            path,
+            DigestScheme::default(),
+        )
+        .expect("site glue-or-witness case should evaluate");
+        assert_eq!(
+            evaluated.failure_classes,
+            vec!["coherence.glue_or_witness_contractibility.unknown_obstruction_class".to_string()]
+        );
+
+        case["descent"]["allowedObstructionClasses"] = json!(["descent_failure", ""]);
+        assert!(
+            evaluate_site_case_glue_or_witness_contractibility(
+                &case,
+                path,
+                DigestScheme::default()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn evaluate_site_case_cwf_substitution_identity_detects_violation() {
+        let case = json!({
+            "cwf": {
+                "substitution": {
+                    "types": [
+                        {"label": "A", "direct": {"type": "A"}, "afterIdentity": {"type": "A_bad"}}
+                    ],
+                    "terms": [
+                        {"label": "t", "direct": {"term": "t"}, "afterIdentity": {"term": "t"}}
+                    ]
+                }
+            }
+        });
+        let evaluated = evaluate_site_case_cwf_substitution_identity(
+            &case,
+            Path::new("site-case-cwf-substitution-identity.json"),
+            DigestScheme::default(),
+        )
+        .expect("cwf substitution identity should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.cwf_substitution_identity.violation".to_string())
+        );
+    }
+
+    #[test]
+    fn evaluate_cwf_row_equalities_honors_expect_equal() {
+        let case_path = Path::new("site-case-cwf-expect-equal.json");
+        let rows = [
+            json!({"label": "same", "direct": {"type": "A"}, "afterIdentity": {"type": "A"}}),
+            json!({
+                "label": "differs",
+                "expectEqual": false,
+                "direct": {"type": "B"},
+                "afterIdentity": {"type": "B_legacy"}
+            }),
+            json!({
+                "label": "wrongly_matches",
+                "expectEqual": false,
+                "direct": {"type": "C"},
+                "afterIdentity": {"type": "C"}
+            }),
+        ];
+        let (mismatch_labels, digest_rows) = evaluate_cwf_row_equalities(
+            &rows,
+            case_path,
+            "types",
+            "direct",
+            "afterIdentity",
+            DigestScheme::default(),
+        )
+        .expect("rows should evaluate");
+        assert_eq!(mismatch_labels, vec!["wrongly_matches".to_string()]);
+        assert_eq!(digest_rows[0]["expectEqual"], json!(true));
+        assert_eq!(digest_rows[1]["expectEqual"], json!(false));
+
+        let malformed = [json!({
+            "expectEqual": "no",
+            "direct": {"type": "A"},
+            "afterIdentity": {"type": "A"}
+        })];
+        assert!(matches!(
+            evaluate_cwf_row_equalities(&malformed, case_path, "types", "direct", "afterIdentity", DigestScheme::default()),
+            Err(CoherenceError::Contract(message)) if message.contains("types[0].expectEqual")
+        ));
+    }
+
+    #[test]
+    fn evaluate_site_case_cwf_substitution_composition_detects_violation() {
+        let case = json!({
+            "cwf": {
+                "substitution": {
+                    "types": [
+                        {"label": "A", "afterCompose": {"type": "A_fg"}, "afterStepwise": {"type": "A_fg"}}
+                    ],
+                    "terms": [
+                        {"label": "t", "afterCompose": {"term": "t_fg"}, "afterStepwise": {"term": "t_bad"}}
+                    ]
+                }
+            }
+        });
+        let evaluated = evaluate_site_case_cwf_substitution_composition(
+            &case,
+            Path::new("site-case-cwf-substitution-composition.json"),
+            DigestScheme::default(),
+        )
+        .expect("cwf substitution composition should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.cwf_substitution_composition.violation".to_string())
+        );
+    }
+
+    #[test]
+    fn evaluate_site_case_cwf_comprehension_beta_detects_violation() {
+        let case = json!({
+            "cwf": {
+                "comprehension": {
+                    "beta": [
+                        {"label": "a", "original": {"term": "a"}, "afterBeta": {"term": "a_bad"}}
+                    ]
+                }
+            }
+        });
+        let evaluated = evaluate_site_case_cwf_comprehension_beta(
+            &case,
+            Path::new("site-case-cwf-comprehension-beta.json"),
+            DigestScheme::default(),
+        )
+        .expect("cwf comprehension beta should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.cwf_comprehension_beta.violation".to_string())
+        );
+    }
+
+    #[test]
+    fn evaluate_site_case_cwf_comprehension_eta_detects_violation() {
+        let case = json!({
+            "cwf": {
+                "comprehension": {
+                    "eta": [
+                        {"label": "sigma", "original": {"subst": "sigma"}, "afterEta": {"subst": "sigma_bad"}}
+                    ]
+                }
+            }
+        });
+        let evaluated = evaluate_site_case_cwf_comprehension_eta(
+            &case,
+            Path::new("site-case-cwf-comprehension-eta.json"),
+            DigestScheme::default(),
+        )
+        .expect("cwf comprehension eta should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.cwf_comprehension_eta.violation".to_string())
+        );
+    }
+
+    #[test]
+    fn evaluate_site_case_span_square_commutation_detects_violation() {
+        let failure_classes: Vec<String> = Vec::new();
+        let case = json!({
+            "spanSquare": {
+                "spans": [
+                    {
+                        "id": "top",
+                        "kind": "pipeline",
+                        "left": {"ctx": "Gamma"},
                         "apex": {"run": "a"},
                         "right": {"out": "x"}
                     },
@@ -7633,14 +13969,15 @@ Current deterministic projected check IDs include:
                         "right": "right",
                         "result": "accepted",
                         "failureClasses": failure_classes,
-                        "digest": square_witness_digest("top", "bottom", "left", "right", "accepted", &Vec::new())
+                        "digest": square_witness_digest("top", "bottom", "left", "right", "accepted", &Vec::new(), DigestScheme::default())
                     }
                 ]
             }
         });
-        let evaluated = evaluate_site_case_span_square_commutation(
+        let evaluated = evaluate_span_square_without_registry(
             &case,
             Path::new("site-case-span-square-commutation.json"),
+            DigestScheme::default(),
         )
         .expect("span/square commutation case should evaluate");
         assert_eq!(evaluated.result, "rejected");
@@ -7835,7 +14172,7 @@ Current deterministic projected check IDs include:
                         "right": "reindex_output",
                         "result": "accepted",
                         "failureClasses": square_failures,
-                        "digest": square_witness_digest("run_on_base", "run_after_reindex", "reindex_input", "reindex_output", "accepted", &Vec::new())
+                        "digest": square_witness_digest("run_on_base", "run_after_reindex", "reindex_input", "reindex_output", "accepted", &Vec::new(), DigestScheme::default())
                     },
                     {
                         "id": "sq_id",
@@ -7845,7 +14182,7 @@ Current deterministic projected check IDs include:
                         "right": "reindex_output",
                         "result": "accepted",
                         "failureClasses": [],
-                        "digest": square_witness_digest("run_on_base", "run_after_reindex", "reindex_input", "reindex_output", "accepted", &Vec::new())
+                        "digest": square_witness_digest("run_on_base", "run_after_reindex", "reindex_input", "reindex_output", "accepted", &Vec::new(), DigestScheme::default())
                     }
                 ],
                 "compositionLaws": {
@@ -7860,7 +14197,7 @@ Current deterministic projected check IDs include:
                             "right": span_identity_right,
                             "result": "accepted",
                             "failureClasses": [],
-                            "digest": composition_law_digest("span", "span_identity", &span_identity_left, &span_identity_right, "accepted", &Vec::new())
+                            "digest": composition_law_digest("span", "span_identity", &span_identity_left, &span_identity_right, "accepted", &Vec::new(), DigestScheme::default())
                         },
                         {
                             "id": "law_span_assoc",
@@ -7870,7 +14207,7 @@ Current deterministic projected check IDs include:
                             "right": span_assoc_right,
                             "result": "accepted",
                             "failureClasses": [],
-                            "digest": composition_law_digest("span", "span_associativity", &span_assoc_left, &span_assoc_right, "accepted", &Vec::new())
+                            "digest": composition_law_digest("span", "span_associativity", &span_assoc_left, &span_assoc_right, "accepted", &Vec::new(), DigestScheme::default())
                         },
                         {
                             "id": "law_sq_identity",
@@ -7880,7 +14217,7 @@ Current deterministic projected check IDs include:
                             "right": square_identity_right,
                             "result": "accepted",
                             "failureClasses": [],
-                            "digest": composition_law_digest("square", "square_identity", &square_identity_left, &square_identity_right, "accepted", &Vec::new())
+                            "digest": composition_law_digest("square", "square_identity", &square_identity_left, &square_identity_right, "accepted", &Vec::new(), DigestScheme::default())
                         },
                         {
                             "id": "law_sq_assoc_h",
@@ -7890,7 +14227,7 @@ Current deterministic projected check IDs include:
                             "right": square_assoc_horizontal_right,
                             "result": "accepted",
                             "failureClasses": [],
-                            "digest": composition_law_digest("square", "square_associativity_horizontal", &square_assoc_horizontal_left, &square_assoc_horizontal_right, "accepted", &Vec::new())
+                            "digest": composition_law_digest("square", "square_associativity_horizontal", &square_assoc_horizontal_left, &square_assoc_horizontal_right, "accepted", &Vec::new(), DigestScheme::default())
                         },
                         {
                             "id": "law_sq_assoc_v",
@@ -7900,7 +14237,7 @@ Current deterministic projected check IDs include:
                             "right": square_assoc_vertical_right,
                             "result": "accepted",
                             "failureClasses": [],
-                            "digest": composition_law_digest("square", "square_associativity_vertical", &square_assoc_vertical_left, &square_assoc_vertical_right, "accepted", &Vec::new())
+                            "digest": composition_law_digest("square", "square_associativity_vertical", &square_assoc_vertical_left, &square_assoc_vertical_right, "accepted", &Vec::new(), DigestScheme::default())
                         },
                         {
                             "id": "law_sq_hv",
@@ -7910,7 +14247,7 @@ Current deterministic projected check IDs include:
                             "right": square_hv_right,
                             "result": "accepted",
                             "failureClasses": [],
-                            "digest": composition_law_digest("square", "square_hv_compatibility", &square_hv_left, &square_hv_right, "accepted", &Vec::new())
+                            "digest": composition_law_digest("square", "square_hv_compatibility", &square_hv_left, &square_hv_right, "accepted", &Vec::new(), DigestScheme::default())
                         },
                         {
                             "id": "law_sq_interchange",
@@ -7920,178 +14257,1009 @@ Current deterministic projected check IDs include:
                             "right": square_interchange_right,
                             "result": "accepted",
                             "failureClasses": [],
-                            "digest": composition_law_digest("square", "square_interchange", &square_interchange_left, &square_interchange_right, "accepted", &Vec::new())
+                            "digest": composition_law_digest("square", "square_interchange", &square_interchange_left, &square_interchange_right, "accepted", &Vec::new(), DigestScheme::default())
+                        }
+                    ]
+                }
+            }
+        });
+        let evaluated = evaluate_span_square_without_registry(
+            &case,
+            Path::new("site-case-span-square-commutation-composition-accept.json"),
+            DigestScheme::default(),
+        )
+        .expect("span/square commutation composition case should evaluate");
+        assert_eq!(evaluated.result, "accepted");
+        assert!(evaluated.failure_classes.is_empty());
+        assert_eq!(evaluated.details["unreferencedSpans"], json!([]));
+        assert_eq!(evaluated.details["unreferencedSquares"], json!([]));
+
+        let mut dead = case.clone();
+        dead["spanSquare"]["squares"]
+            .as_array_mut()
+            .expect("squares should be an array")
+            .push(json!({
+                "id": "sq_dead",
+                "top": "run_on_base",
+                "bottom": "run_after_reindex",
+                "left": "reindex_input",
+                "right": "reindex_output",
+                "result": "accepted",
+                "failureClasses": [],
+                "digest": square_witness_digest("run_on_base", "run_after_reindex", "reindex_input", "reindex_output", "accepted", &Vec::new(), DigestScheme::default())
+            }));
+        let path = Path::new("site-case-span-square-commutation-dead-square.json");
+        let reported = evaluate_span_square_without_registry(&dead, path, DigestScheme::default())
+            .expect("dead square case should evaluate");
+        assert_eq!(reported.result, "accepted");
+        assert_eq!(reported.details["unreferencedSquares"], json!(["sq_dead"]));
+        assert_eq!(reported.details["unreferencedSpans"], json!([]));
+
+        dead["spanSquare"]["forbidUnreferencedArtifacts"] = json!(true);
+        let forbidden = evaluate_span_square_without_registry(&dead, path, DigestScheme::default())
+            .expect("dead square case should evaluate");
+        assert_eq!(
+            forbidden.failure_classes,
+            vec!["coherence.span_square_commutation.unreferenced_artifact".to_string()]
+        );
+    }
+
+    #[test]
+    fn evaluate_site_case_span_square_commutation_rejects_missing_composition_law_coverage() {
+        let span_identity_left =
+            json!({"compose": {"left": {"span": "span_id"}, "right": {"span": "run_on_base"}}});
+        let span_identity_right = json!({"span": "run_on_base"});
+        let case = json!({
+            "spanSquare": {
+                "spans": [
+                    {
+                        "id": "span_id",
+                        "kind": "identity",
+                        "left": {"ctx": "Gamma"},
+                        "apex": {"id": true},
+                        "right": {"ctx": "Gamma"}
+                    },
+                    {
+                        "id": "run_on_base",
+                        "kind": "pipeline",
+                        "left": {"ctx": "Gamma"},
+                        "apex": {"run": "base"},
+                        "right": {"out": "y"}
+                    },
+                    {
+                        "id": "run_after_reindex",
+                        "kind": "pipeline",
+                        "left": {"ctx": "Gamma"},
+                        "apex": {"run": "base"},
+                        "right": {"out": "y"}
+                    },
+                    {
+                        "id": "reindex_input",
+                        "kind": "base_change",
+                        "left": {"ctx": "Delta"},
+                        "apex": {"map": "rho"},
+                        "right": {"ctx": "Gamma"}
+                    },
+                    {
+                        "id": "reindex_output",
+                        "kind": "base_change",
+                        "left": {"out": "y"},
+                        "apex": {"map": "rho"},
+                        "right": {"out": "y"}
+                    }
+                ],
+                "squares": [
+                    {
+                        "id": "sq_accept",
+                        "top": "run_on_base",
+                        "bottom": "run_after_reindex",
+                        "left": "reindex_input",
+                        "right": "reindex_output",
+                        "result": "accepted",
+                        "failureClasses": [],
+                        "digest": square_witness_digest("run_on_base", "run_after_reindex", "reindex_input", "reindex_output", "accepted", &Vec::new(), DigestScheme::default())
+                    }
+                ],
+                "compositionLaws": {
+                    "identitySpanIds": ["span_id"],
+                    "identitySquareIds": [],
+                    "laws": [
+                        {
+                            "id": "law_span_identity",
+                            "kind": "span",
+                            "law": "span_identity",
+                            "left": span_identity_left,
+                            "right": span_identity_right,
+                            "result": "accepted",
+                            "failureClasses": [],
+                            "digest": composition_law_digest("span", "span_identity", &span_identity_left, &span_identity_right, "accepted", &Vec::new(), DigestScheme::default())
                         }
                     ]
                 }
             }
         });
-        let evaluated = evaluate_site_case_span_square_commutation(
-            &case,
-            Path::new("site-case-span-square-commutation-composition-accept.json"),
+        let evaluated = evaluate_span_square_without_registry(
+            &case,
+            Path::new("site-case-span-square-commutation-composition-missing-coverage.json"),
+            DigestScheme::default(),
+        )
+        .expect("span/square commutation composition case should evaluate");
+        assert_eq!(evaluated.result, "rejected");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.violation".to_string())
+        );
+    }
+
+    #[test]
+    fn check_site_obligation_requires_golden_polarity_vector() {
+        let temp = TempDirGuard::new("site-obligation-missing-golden");
+        let fixture_root = temp.path().join("fixtures");
+        write_site_manifest(
+            &fixture_root,
+            &["adversarial/only_vector"],
+            &["adversarial/only_vector"],
+        );
+        write_site_vector(
+            &fixture_root,
+            "adversarial/only_vector",
+            "span_square_commutation",
+            "accepted",
+        );
+        let contract = test_contract_with_site_fixture_root("fixtures");
+
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
+        )
+        .expect("site obligation should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.missing_golden_vector".to_string())
+        );
+    }
+
+    #[test]
+    fn check_site_obligation_requires_adversarial_polarity_vector() {
+        let temp = TempDirGuard::new("site-obligation-missing-adversarial");
+        let fixture_root = temp.path().join("fixtures");
+        write_site_manifest(
+            &fixture_root,
+            &["golden/only_vector"],
+            &["golden/only_vector"],
+        );
+        write_site_vector(
+            &fixture_root,
+            "golden/only_vector",
+            "span_square_commutation",
+            "accepted",
+        );
+        let contract = test_contract_with_site_fixture_root("fixtures");
+
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
+        )
+        .expect("site obligation should evaluate");
+        assert!(
+            evaluated.failure_classes.contains(
+                &"coherence.span_square_commutation.missing_adversarial_vector".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn evaluate_single_vector_reports_match_against_expectation() {
+        let temp = TempDirGuard::new("single-vector-eval");
+        let fixture_root = temp.path().join("fixtures");
+        write_site_vector(
+            &fixture_root,
+            "golden/ok_vector",
+            "span_square_commutation",
+            "accepted",
+        );
+        write_site_vector(
+            &fixture_root,
+            "adversarial/bad_vector",
+            "span_square_commutation",
+            "accepted",
+        );
+        // The fixture is valid, so claiming rejection makes it a known-bad vector.
+        write_json_file(
+            &fixture_root.join("adversarial/bad_vector/expect.json"),
+            &json!({"schema": 1, "status": "executable", "result": "rejected"}),
+        );
+        let contract = test_contract_with_site_fixture_root("fixtures");
+
+        let good = evaluate_single_vector(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            "golden/ok_vector",
+        )
+        .expect("single vector should evaluate");
+        assert_eq!(good.result, "accepted");
+        assert!(good.matched);
+
+        let bad = evaluate_single_vector(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            "adversarial/bad_vector",
+        )
+        .expect("single vector should evaluate");
+        assert_eq!(bad.result, "accepted");
+        assert_eq!(bad.expected_result, "rejected");
+        assert!(!bad.matched);
+
+        let err = evaluate_single_vector(
+            temp.path(),
+            &contract,
+            "coverage_base_change",
+            "golden/ok_vector",
+        )
+        .expect_err("obligation mismatch should error");
+        assert!(err.to_string().contains("does not match"));
+    }
+
+    #[test]
+    fn check_site_obligation_accepts_when_both_polarities_present() {
+        let temp = TempDirGuard::new("site-obligation-both-polarities");
+        let fixture_root = temp.path().join("fixtures");
+        write_site_manifest(
+            &fixture_root,
+            &["golden/ok_vector", "adversarial/ok_vector"],
+            &["golden/ok_vector", "adversarial/ok_vector"],
+        );
+        write_site_vector(
+            &fixture_root,
+            "golden/ok_vector",
+            "span_square_commutation",
+            "accepted",
+        );
+        write_site_vector(
+            &fixture_root,
+            "adversarial/ok_vector",
+            "span_square_commutation",
+            "rejected",
+        );
+        let contract = test_contract_with_site_fixture_root("fixtures");
+
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
+        )
+        .expect("site obligation should evaluate");
+        assert!(evaluated.failure_classes.is_empty());
+    }
+
+    fn inline_span_square_vector(expected_result: &str) -> Value {
+        let expected_failure_classes = if expected_result == "rejected" {
+            json!(["coherence.span_square_commutation.violation"])
+        } else {
+            json!([])
+        };
+        json!({
+            "case": {
+                "schema": 1,
+                "status": "executable",
+                "obligationId": "span_square_commutation",
+                "artifacts": valid_span_square_artifacts_for_result(expected_result),
+            },
+            "expect": {
+                "schema": 1,
+                "status": "executable",
+                "result": expected_result,
+                "expectedFailureClasses": expected_failure_classes,
+            }
+        })
+    }
+
+    #[test]
+    fn check_site_obligation_evaluates_inline_and_on_disk_vectors() {
+        let temp = TempDirGuard::new("site-obligation-inline-vectors");
+        let fixture_root = temp.path().join("fixtures");
+        write_json_file(
+            &fixture_root.join("manifest.json"),
+            &json!({
+                "schema": 1,
+                "status": "executable",
+                "vectors": ["golden/on_disk_accept"],
+                "obligationVectors": {
+                    "span_square_commutation": [
+                        "golden/on_disk_accept",
+                        "adversarial/inline_reject"
+                    ]
+                },
+                "inlineVectors": {
+                    "adversarial/inline_reject": inline_span_square_vector("rejected")
+                }
+            }),
+        );
+        write_site_vector(
+            &fixture_root,
+            "golden/on_disk_accept",
+            "span_square_commutation",
+            "accepted",
+        );
+        let contract = test_contract_with_site_fixture_root("fixtures");
+
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
+        )
+        .expect("site obligation should evaluate");
+        assert!(evaluated.failure_classes.is_empty());
+        assert_eq!(evaluated.details["matchedVectors"], 2);
+        assert_eq!(
+            evaluated.details["manifestInlineVectors"],
+            json!(["adversarial/inline_reject"])
+        );
+    }
+
+    #[test]
+    fn site_manifest_unclaimed_vectors_lists_orphans() {
+        let manifest: SiteManifest = serde_json::from_value(json!({
+            "schema": 1,
+            "status": "executable",
+            "vectors": ["golden/a", "golden/orphan", "adversarial/b"],
+            "obligationVectors": {
+                "span_square_commutation": ["golden/a"],
+                "coverage_base_change": ["adversarial/b"]
+            },
+            "inlineVectors": {
+                "golden/inline_orphan": {"case": {}, "expect": {}}
+            }
+        }))
+        .expect("manifest should parse");
+        assert_eq!(
+            manifest.unclaimed_vectors(),
+            vec![
+                "golden/inline_orphan".to_string(),
+                "golden/orphan".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_site_manifest_matches_site_obligation_manifest_failures() {
+        let mut manifest = json!({
+            "schema": 1,
+            "status": "executable",
+            "vectors": ["golden/a", "adversarial/b"],
+            "obligationVectors": {
+                "span_square_commutation": ["golden/a", "golden/inline"],
+                "coverage_base_change": ["adversarial/b"]
+            },
+            "inlineVectors": {
+                "golden/inline": {"case": {}, "expect": {}}
+            }
+        });
+        assert!(validate_site_manifest(&manifest).is_empty());
+
+        manifest["obligationVectors"]["coverage_base_change"] = json!(["adversarial/missing"]);
+        manifest["obligationVectors"]["coverage_transitivity"] = json!([]);
+        assert_eq!(
+            validate_site_manifest(&manifest),
+            vec![
+                "coherence.coverage_base_change.manifest_obligation_vector_not_declared"
+                    .to_string(),
+                "coherence.coverage_base_change.manifest_unreferenced_vectors".to_string(),
+                "coherence.coverage_transitivity.manifest_unreferenced_vectors".to_string(),
+                "coherence.span_square_commutation.manifest_unreferenced_vectors".to_string(),
+            ]
+        );
+
+        manifest["obligationVectors"]["coverage_base_change"] = json!(["adversarial/b"]);
+        manifest["inlineVectors"]["golden/a"] = json!({"case": {}, "expect": {}});
+        assert_eq!(
+            validate_site_manifest(&manifest),
+            vec![
+                "coherence.coverage_base_change.manifest_inline_vector_collision".to_string(),
+                "coherence.coverage_transitivity.manifest_inline_vector_collision".to_string(),
+                "coherence.span_square_commutation.manifest_inline_vector_collision".to_string(),
+            ]
+        );
+
+        manifest["vectors"] = json!([]);
+        manifest["inlineVectors"] = json!({});
+        manifest["obligationVectors"] = json!({"coverage_base_change": []});
+        assert_eq!(
+            validate_site_manifest(&manifest),
+            vec!["coherence.coverage_base_change.manifest_empty".to_string()]
+        );
+
+        manifest["obligationVectors"] = json!({});
+        assert_eq!(
+            validate_site_manifest(&manifest),
+            vec![
+                "coherence.site_manifest.manifest_empty".to_string(),
+                "coherence.site_manifest.manifest_missing_obligation_vectors".to_string(),
+            ]
+        );
+        assert_eq!(
+            validate_site_manifest(&json!({"vectors": []})),
+            vec!["coherence.site_manifest.manifest_invalid".to_string()]
+        );
+    }
+
+    #[test]
+    fn check_site_obligation_rejects_unreferenced_manifest_vector() {
+        let temp = TempDirGuard::new("site-obligation-unreferenced-vector");
+        let fixture_root = temp.path().join("fixtures");
+        write_site_manifest(
+            &fixture_root,
+            &[
+                "golden/ok_vector",
+                "adversarial/reject_vector",
+                "golden/orphan_vector",
+            ],
+            &["golden/ok_vector", "adversarial/reject_vector"],
+        );
+        write_site_vector(
+            &fixture_root,
+            "golden/ok_vector",
+            "span_square_commutation",
+            "accepted",
+        );
+        write_site_vector(
+            &fixture_root,
+            "adversarial/reject_vector",
+            "span_square_commutation",
+            "rejected",
+        );
+        let contract = test_contract_with_site_fixture_root("fixtures");
+
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
         )
-        .expect("span/square commutation composition case should evaluate");
-        assert_eq!(evaluated.result, "accepted");
+        .expect("site obligation should evaluate");
+        assert_eq!(
+            evaluated.failure_classes,
+            vec!["coherence.span_square_commutation.manifest_unreferenced_vectors".to_string()]
+        );
+        assert_eq!(
+            evaluated.details["manifestUnreferencedVectors"],
+            json!(["golden/orphan_vector"])
+        );
+    }
+
+    fn large_span_square_artifacts(span_count: usize) -> Value {
+        let spans: Vec<Value> = (0..span_count)
+            .map(|index| {
+                json!({
+                    "id": format!("span_{index:05}"),
+                    "kind": if index % 2 == 0 { "pipeline" } else { "base_change" },
+                    "left": {"ctx": "Gamma", "input": format!("x{}", index % 7)},
+                    "apex": {"run": format!("r{index}")},
+                    "right": {"out": format!("y{}", index % 11)}
+                })
+            })
+            .collect();
+        let squares: Vec<Value> = (0..span_count / 4)
+            .map(|index| {
+                let ids: Vec<String> = (0..4)
+                    .map(|offset| format!("span_{:05}", index * 4 + offset))
+                    .collect();
+                let failures = vec!["coherence.span_square_commutation.violation".to_string()];
+                json!({
+                    "id": format!("sq_{index:05}"),
+                    "top": ids[0],
+                    "bottom": ids[1],
+                    "left": ids[2],
+                    "right": ids[3],
+                    "result": "rejected",
+                    "failureClasses": failures,
+                    "digest": square_witness_digest(
+                        &ids[0], &ids[1], &ids[2], &ids[3], "rejected", &failures, DigestScheme::default())
+                })
+            })
+            .collect();
+        json!({"spanSquare": {"spans": spans, "squares": squares}})
+    }
+
+    #[test]
+    fn parallel_digests_match_serial_digests_on_large_fixture() {
+        let artifacts = large_span_square_artifacts(2048);
+        let spans = artifacts["spanSquare"]["spans"]
+            .as_array()
+            .expect("spans array");
+        let serial: Vec<String> = spans
+            .iter()
+            .map(|span| semantic_digest(span, DigestScheme::default()))
+            .collect();
+        for workers in [1, 3, 8] {
+            assert_eq!(
+                parallel_digests_with_workers(spans, workers, |span| {
+                    semantic_digest(span, DigestScheme::default())
+                }),
+                serial
+            );
+        }
+
+        let case_path = Path::new("fixtures/golden/case.json");
+        let evaluated =
+            evaluate_span_square_without_registry(&artifacts, case_path, DigestScheme::default())
+                .expect("large span square should evaluate");
         assert!(evaluated.failure_classes.is_empty());
+
+        let expected: BTreeMap<String, String> = spans
+            .iter()
+            .map(|span| {
+                let digest = semantic_digest(
+                    &json!({
+                        "kind": span["kind"],
+                        "left": span["left"],
+                        "apex": span["apex"],
+                        "right": span["right"],
+                    }),
+                    DigestScheme::default(),
+                );
+                (span["id"].as_str().expect("span id").to_string(), digest)
+            })
+            .collect();
+        let evaluated_digests: BTreeMap<String, String> = evaluated.details["spans"]
+            .as_array()
+            .expect("span rows")
+            .iter()
+            .map(|row| {
+                (
+                    row["id"].as_str().expect("row id").to_string(),
+                    row["digest"].as_str().expect("row digest").to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(evaluated_digests, expected);
     }
 
     #[test]
-    fn evaluate_site_case_span_square_commutation_rejects_missing_composition_law_coverage() {
-        let span_identity_left =
-            json!({"compose": {"left": {"span": "span_id"}, "right": {"span": "run_on_base"}}});
-        let span_identity_right = json!({"span": "run_on_base"});
-        let case = json!({
-            "spanSquare": {
-                "spans": [
-                    {
-                        "id": "span_id",
-                        "kind": "identity",
-                        "left": {"ctx": "Gamma"},
-                        "apex": {"id": true},
-                        "right": {"ctx": "Gamma"}
-                    },
-                    {
-                        "id": "run_on_base",
-                        "kind": "pipeline",
-                        "left": {"ctx": "Gamma"},
-                        "apex": {"run": "base"},
-                        "right": {"out": "y"}
-                    },
-                    {
-                        "id": "run_after_reindex",
-                        "kind": "pipeline",
-                        "left": {"ctx": "Gamma"},
-                        "apex": {"run": "base"},
-                        "right": {"out": "y"}
-                    },
-                    {
-                        "id": "reindex_input",
-                        "kind": "base_change",
-                        "left": {"ctx": "Delta"},
-                        "apex": {"map": "rho"},
-                        "right": {"ctx": "Gamma"}
-                    },
-                    {
-                        "id": "reindex_output",
-                        "kind": "base_change",
-                        "left": {"out": "y"},
-                        "apex": {"map": "rho"},
-                        "right": {"out": "y"}
-                    }
-                ],
-                "squares": [
-                    {
-                        "id": "sq_accept",
-                        "top": "run_on_base",
-                        "bottom": "run_after_reindex",
-                        "left": "reindex_input",
-                        "right": "reindex_output",
-                        "result": "accepted",
-                        "failureClasses": [],
-                        "digest": square_witness_digest("run_on_base", "run_after_reindex", "reindex_input", "reindex_output", "accepted", &Vec::new())
-                    }
-                ],
-                "compositionLaws": {
-                    "identitySpanIds": ["span_id"],
-                    "identitySquareIds": [],
-                    "laws": [
-                        {
-                            "id": "law_span_identity",
-                            "kind": "span",
-                            "law": "span_identity",
-                            "left": span_identity_left,
-                            "right": span_identity_right,
-                            "result": "accepted",
-                            "failureClasses": [],
-                            "digest": composition_law_digest("span", "span_identity", &span_identity_left, &span_identity_right, "accepted", &Vec::new())
-                        }
-                    ]
-                }
-            }
+    fn parallel_digests_propagate_depth_exceeded_from_workers() {
+        let mut artifacts = large_span_square_artifacts(512);
+        let spans = artifacts["spanSquare"]["spans"]
+            .as_array_mut()
+            .expect("spans array");
+        assert!(spans.len() > PARALLEL_DIGEST_MIN_ITEMS);
+        let last = spans.len() - 1;
+        spans[last]["apex"] = nested_object(SEMANTIC_NORMALIZE_MAX_DEPTH + 6);
+
+        for workers in [1, 4] {
+            let (_, exceeded) = with_semantic_depth_tracking(|| {
+                parallel_digests_with_workers(spans, workers, |span| {
+                    semantic_digest(span, DigestScheme::default())
+                })
+            });
+            assert!(exceeded, "depth overflow lost with {workers} workers");
+        }
+        let (_, exceeded) = with_semantic_depth_tracking(|| {
+            parallel_digests_with_workers(&spans[..last], 4, |span| {
+                semantic_digest(span, DigestScheme::default())
+            })
+        });
+        assert!(!exceeded);
+
+        let case_path = Path::new("fixtures/golden/case.json");
+        let (_, exceeded) = with_semantic_depth_tracking(|| {
+            evaluate_span_square_without_registry(&artifacts, case_path, DigestScheme::default())
         });
+        assert!(exceeded);
+    }
+
+    #[test]
+    fn parallel_span_digests_still_detect_duplicate_ids() {
+        let mut artifacts = large_span_square_artifacts(1024);
+        let spans = artifacts["spanSquare"]["spans"]
+            .as_array_mut()
+            .expect("spans array");
+        let mut duplicate = spans[10].clone();
+        duplicate["apex"] = json!({"run": "shadow"});
+        spans.push(duplicate);
+
+        let case_path = Path::new("fixtures/golden/case.json");
+        let evaluated =
+            evaluate_span_square_without_registry(&artifacts, case_path, DigestScheme::default())
+                .expect("large span square should evaluate");
+        assert_eq!(
+            evaluated.failure_classes,
+            vec!["coherence.span_square_commutation.violation".to_string()]
+        );
+    }
+
+    #[test]
+    fn span_square_accepts_any_span_kind_without_registry() {
+        let artifacts = valid_span_square_artifacts_for_result("accepted");
+        let case_path = Path::new("fixtures/golden/case.json");
         let evaluated = evaluate_site_case_span_square_commutation(
-            &case,
-            Path::new("site-case-span-square-commutation-composition-missing-coverage.json"),
+            &artifacts,
+            case_path,
+            None,
+            DigestScheme::default(),
         )
-        .expect("span/square commutation composition case should evaluate");
+        .expect("span square should evaluate");
+        assert!(
+            !evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.unregistered_span_kind".to_string())
+        );
+    }
+
+    #[test]
+    fn span_square_rejects_unregistered_span_kind_with_registry() {
+        let artifacts = valid_span_square_artifacts_for_result("accepted");
+        let case_path = Path::new("fixtures/golden/case.json");
+
+        let full: BTreeSet<String> = ["pipeline", "base_change"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let evaluated = evaluate_site_case_span_square_commutation(
+            &artifacts,
+            case_path,
+            Some(&full),
+            DigestScheme::default(),
+        )
+        .expect("span square should evaluate");
+        assert!(
+            !evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.unregistered_span_kind".to_string())
+        );
+
+        let partial: BTreeSet<String> = ["pipeline".to_string()].into_iter().collect();
+        let evaluated = evaluate_site_case_span_square_commutation(
+            &artifacts,
+            case_path,
+            Some(&partial),
+            DigestScheme::default(),
+        )
+        .expect("span square should evaluate");
         assert_eq!(evaluated.result, "rejected");
         assert!(
             evaluated
                 .failure_classes
-                .contains(&"coherence.span_square_commutation.violation".to_string())
+                .contains(&"coherence.span_square_commutation.unregistered_span_kind".to_string())
+        );
+    }
+
+    #[test]
+    fn load_span_kind_registry_reads_control_plane_contract() {
+        let temp = TempDirGuard::new("span-kind-registry");
+        let mut contract = test_contract_with_site_fixture_root("fixtures");
+        assert_eq!(
+            load_span_kind_registry(temp.path(), &contract).expect("registry should load"),
+            None
+        );
+
+        let mut payload = base_control_plane_contract_payload();
+        write_json_file(&temp.path().join("CONTROL-PLANE-CONTRACT.json"), &payload);
+        contract.surfaces.control_plane_contract_path = "CONTROL-PLANE-CONTRACT.json".to_string();
+        assert_eq!(
+            load_span_kind_registry(temp.path(), &contract).expect("registry should load"),
+            None
+        );
+
+        payload["spanKindRegistry"] = json!(["pipeline", " base_change "]);
+        write_json_file(&temp.path().join("CONTROL-PLANE-CONTRACT.json"), &payload);
+        let expected: BTreeSet<String> = ["base_change".to_string(), "pipeline".to_string()]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            load_span_kind_registry(temp.path(), &contract).expect("registry should load"),
+            Some(expected.clone())
+        );
+
+        // Unrelated control-plane fields do not need to parse.
+        write_json_file(
+            &temp.path().join("CONTROL-PLANE-CONTRACT.json"),
+            &json!({"schema": "not-a-number", "spanKindRegistry": ["pipeline", "base_change"]}),
         );
+        assert_eq!(
+            load_span_kind_registry(temp.path(), &contract).expect("registry should load"),
+            Some(expected)
+        );
+
+        write_json_file(
+            &temp.path().join("CONTROL-PLANE-CONTRACT.json"),
+            &json!({"spanKindRegistry": "pipeline"}),
+        );
+        assert!(matches!(
+            load_span_kind_registry(temp.path(), &contract),
+            Err(CoherenceError::ParseJson { .. })
+        ));
+    }
+
+    fn set_site_vector_status(fixture_root: &Path, vector_id: &str, status: &str) {
+        let case_path = fixture_root.join(vector_id).join("case.json");
+        let mut case_payload: Value =
+            serde_json::from_slice(&fs::read(&case_path).expect("case should be readable"))
+                .expect("case should parse");
+        case_payload["status"] = json!(status);
+        write_json_file(&case_path, &case_payload);
     }
 
     #[test]
-    fn check_site_obligation_requires_golden_polarity_vector() {
-        let temp = TempDirGuard::new("site-obligation-missing-golden");
+    fn check_site_obligation_counts_skipped_and_warns_on_pending_vectors() {
+        let temp = TempDirGuard::new("site-obligation-skipped-pending");
         let fixture_root = temp.path().join("fixtures");
-        write_site_manifest(
+        let vectors = [
+            "golden/ok_vector",
+            "adversarial/reject_vector",
+            "golden/skipped_vector",
+            "golden/pending_vector",
+        ];
+        write_site_manifest(&fixture_root, &vectors, &vectors);
+        write_site_vector(
             &fixture_root,
-            &["adversarial/only_vector"],
-            &["adversarial/only_vector"],
+            "golden/ok_vector",
+            "span_square_commutation",
+            "accepted",
         );
         write_site_vector(
             &fixture_root,
-            "adversarial/only_vector",
+            "adversarial/reject_vector",
             "span_square_commutation",
-            "accepted",
+            "rejected",
         );
+        for (vector_id, status) in [
+            ("golden/skipped_vector", "skipped"),
+            ("golden/pending_vector", "pending"),
+        ] {
+            write_site_vector(
+                &fixture_root,
+                vector_id,
+                "span_square_commutation",
+                "accepted",
+            );
+            set_site_vector_status(&fixture_root, vector_id, status);
+            // Non-executable vectors are never evaluated, so a broken expect is inert.
+            write_text_file(&fixture_root.join(vector_id).join("expect.json"), "{");
+        }
         let contract = test_contract_with_site_fixture_root("fixtures");
 
         let evaluated = check_site_obligation(
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
-        assert!(
-            evaluated
-                .failure_classes
-                .contains(&"coherence.span_square_commutation.missing_golden_vector".to_string())
+        assert!(evaluated.failure_classes.is_empty());
+        assert_eq!(evaluated.details["matchedVectors"], 2);
+        assert_eq!(
+            evaluated.details["skippedVectors"],
+            json!(["golden/skipped_vector"])
+        );
+        assert_eq!(
+            evaluated.details["pendingVectors"],
+            json!(["golden/pending_vector"])
+        );
+        assert_eq!(
+            evaluated.details["warnings"],
+            json!(["coherence.span_square_commutation.pending_vector"])
         );
     }
 
     #[test]
-    fn check_site_obligation_requires_adversarial_polarity_vector() {
-        let temp = TempDirGuard::new("site-obligation-missing-adversarial");
+    fn warnings_as_errors_promotes_pending_vector_warnings() {
+        let temp = TempDirGuard::new("warnings-as-errors-pending-vector");
         let fixture_root = temp.path().join("fixtures");
-        write_site_manifest(
-            &fixture_root,
-            &["golden/only_vector"],
-            &["golden/only_vector"],
-        );
+        let vectors = [
+            "golden/ok_vector",
+            "adversarial/reject_vector",
+            "golden/pending_vector",
+        ];
+        write_site_manifest(&fixture_root, &vectors, &vectors);
+        for (vector_id, expected) in [
+            ("golden/ok_vector", "accepted"),
+            ("adversarial/reject_vector", "rejected"),
+            ("golden/pending_vector", "accepted"),
+        ] {
+            write_site_vector(
+                &fixture_root,
+                vector_id,
+                "span_square_commutation",
+                expected,
+            );
+        }
+        set_site_vector_status(&fixture_root, "golden/pending_vector", "pending");
+        let mut payload = serde_json::to_value(test_contract_with_site_fixture_root("fixtures"))
+            .expect("contract should serialize");
+        payload["obligations"] = json!([]);
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        write_json_file(&contract_path, &payload);
+        let pending_class = "coherence.span_square_commutation.pending_vector".to_string();
+        let span_square = |witness: &CoherenceWitness| {
+            witness
+                .obligations
+                .iter()
+                .find(|obligation| obligation.obligation_id == "span_square_commutation")
+                .expect("span square obligation should run")
+                .clone()
+        };
+
+        let witness =
+            run_coherence_check(temp.path(), &contract_path).expect("coherence check should run");
+        let obligation = span_square(&witness);
+        assert_eq!(obligation.result, "accepted", "{:?}", obligation.details);
+        assert_eq!(obligation.details["warnings"], json!([pending_class]));
+        assert!(!witness.failure_classes.contains(&pending_class));
+
+        let witness = run_coherence_check_with_options(
+            temp.path(),
+            &contract_path,
+            CoherenceCheckOptions {
+                warnings_as_errors: true,
+                ..CoherenceCheckOptions::default()
+            },
+        )
+        .expect("coherence check should run");
+        let obligation = span_square(&witness);
+        assert_eq!(obligation.result, "rejected");
+        assert_eq!(obligation.failure_classes, vec![pending_class.clone()]);
+        assert_eq!(obligation.details["warnings"], json!([pending_class]));
+        assert!(witness.failure_classes.contains(&pending_class));
+        assert_eq!(witness.result, "rejected");
+    }
+
+    #[test]
+    fn check_site_obligation_sets_aside_only_non_executable_manifest_entries() {
+        let temp = TempDirGuard::new("site-obligation-manifest-status");
+        let fixture_root = temp.path().join("fixtures");
+        let contract = test_contract_with_site_fixture_root("fixtures");
         write_site_vector(
             &fixture_root,
-            "golden/only_vector",
+            "golden/broken_vector",
             "span_square_commutation",
             "accepted",
         );
-        let contract = test_contract_with_site_fixture_root("fixtures");
+        write_text_file(&fixture_root.join("golden/broken_vector/expect.json"), "{");
+        let vectors = [
+            "golden/broken_vector",
+            "golden/skipped_vector",
+            "golden/pending_vector",
+        ];
+        let manifest = |pending_status: &str| {
+            json!({
+                "schema": 1,
+                "status": "executable",
+                "vectors": vectors,
+                "obligationVectors": {"span_square_commutation": vectors},
+                // Neither entry has fixture files; only these two are set aside.
+                "vectorStatuses": {
+                    "golden/skipped_vector": "skipped",
+                    "golden/pending_vector": pending_status,
+                }
+            })
+        };
+        write_json_file(&fixture_root.join("manifest.json"), &manifest("pending"));
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
+        )
+        .expect("site obligation should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.vector_expect_invalid".to_string())
+        );
+        assert!(
+            !evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.vector_case_invalid".to_string())
+        );
+        assert_eq!(
+            evaluated.details["skippedVectors"],
+            json!(["golden/skipped_vector"])
+        );
+        assert_eq!(
+            evaluated.details["pendingVectors"],
+            json!(["golden/pending_vector"])
+        );
+        assert!(
+            evaluated.details["warnings"]
+                .as_array()
+                .expect("warnings should be an array")
+                .contains(&json!("coherence.span_square_commutation.pending_vector"))
+        );
 
+        write_json_file(&fixture_root.join("manifest.json"), &manifest("later"));
         let evaluated = check_site_obligation(
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
         assert!(
-            evaluated.failure_classes.contains(
-                &"coherence.span_square_commutation.missing_adversarial_vector".to_string()
-            )
+            evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.manifest_invalid_status".to_string())
+        );
+
+        write_json_file(
+            &fixture_root.join("manifest.json"),
+            &json!({
+                "schema": 1,
+                "status": "skipped",
+                "vectors": ["golden/broken_vector"],
+                "obligationVectors": {"span_square_commutation": ["golden/broken_vector"]}
+            }),
+        );
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
+        )
+        .expect("site obligation should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.manifest_invalid_status".to_string())
+        );
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.vector_expect_invalid".to_string())
+        );
+
+        write_json_file(
+            &fixture_root.join("manifest.json"),
+            &json!({
+                "schema": 1,
+                "status": "draft",
+                "vectors": [],
+                "obligationVectors": {}
+            }),
+        );
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
+        )
+        .expect("site obligation should evaluate");
+        assert!(
+            evaluated
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.manifest_invalid_status".to_string())
         );
     }
 
     #[test]
-    fn check_site_obligation_accepts_when_both_polarities_present() {
-        let temp = TempDirGuard::new("site-obligation-both-polarities");
+    fn check_site_obligation_rejects_inline_vector_id_collision() {
+        let temp = TempDirGuard::new("site-obligation-inline-collision");
         let fixture_root = temp.path().join("fixtures");
-        write_site_manifest(
-            &fixture_root,
-            &["golden/ok_vector", "adversarial/ok_vector"],
-            &["golden/ok_vector", "adversarial/ok_vector"],
+        write_json_file(
+            &fixture_root.join("manifest.json"),
+            &json!({
+                "schema": 1,
+                "status": "executable",
+                "vectors": ["golden/ok_vector", "adversarial/reject_vector"],
+                "obligationVectors": {
+                    "span_square_commutation": [
+                        "golden/ok_vector",
+                        "adversarial/reject_vector"
+                    ]
+                },
+                "inlineVectors": {
+                    "golden/ok_vector": inline_span_square_vector("accepted")
+                }
+            }),
         );
         write_site_vector(
             &fixture_root,
@@ -8101,7 +15269,7 @@ Current deterministic projected check IDs include:
         );
         write_site_vector(
             &fixture_root,
-            "adversarial/ok_vector",
+            "adversarial/reject_vector",
             "span_square_commutation",
             "rejected",
         );
@@ -8111,10 +15279,13 @@ Current deterministic projected check IDs include:
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
-        assert!(evaluated.failure_classes.is_empty());
+        assert_eq!(
+            evaluated.failure_classes,
+            vec!["coherence.span_square_commutation.manifest_inline_vector_collision".to_string()]
+        );
     }
 
     #[test]
@@ -8144,7 +15315,7 @@ Current deterministic projected check IDs include:
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
         assert!(evaluated.failure_classes.contains(
@@ -8179,7 +15350,7 @@ Current deterministic projected check IDs include:
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
         assert!(evaluated.failure_classes.contains(
@@ -8191,14 +15362,21 @@ Current deterministic projected check IDs include:
     fn check_site_obligation_ignores_unscoped_malformed_vectors() {
         let temp = TempDirGuard::new("site-obligation-scope-isolation");
         let fixture_root = temp.path().join("fixtures");
-        write_site_manifest(
-            &fixture_root,
-            &[
-                "golden/ok_vector",
-                "adversarial/ok_vector",
-                "golden/unscoped_bad_vector",
-            ],
-            &["golden/ok_vector", "adversarial/ok_vector"],
+        write_json_file(
+            &fixture_root.join("manifest.json"),
+            &json!({
+                "schema": 1,
+                "status": "executable",
+                "vectors": [
+                    "golden/ok_vector",
+                    "adversarial/ok_vector",
+                    "golden/unscoped_bad_vector"
+                ],
+                "obligationVectors": {
+                    "span_square_commutation": ["golden/ok_vector", "adversarial/ok_vector"],
+                    "coverage_base_change": ["golden/unscoped_bad_vector"]
+                }
+            }),
         );
         write_site_vector(
             &fixture_root,
@@ -8224,7 +15402,7 @@ Current deterministic projected check IDs include:
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
         assert!(evaluated.failure_classes.is_empty());
@@ -8273,7 +15451,7 @@ Current deterministic projected check IDs include:
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
         assert!(evaluated.failure_classes.contains(
@@ -8334,7 +15512,7 @@ Current deterministic projected check IDs include:
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
         assert!(
@@ -8397,12 +15575,187 @@ Current deterministic projected check IDs include:
             temp.path(),
             &contract,
             "span_square_commutation",
-            evaluate_site_case_span_square_commutation,
+            evaluate_span_square_without_registry,
         )
         .expect("site obligation should evaluate");
         assert!(evaluated.failure_classes.is_empty());
     }
 
+    #[test]
+    fn check_site_obligation_flags_unknown_expected_failure_class() {
+        let temp = TempDirGuard::new("site-obligation-unknown-expected-class");
+        let fixture_root = temp.path().join("fixtures");
+        let vectors = ["golden/ok_vector", "adversarial/reject_vector"];
+        write_site_manifest(&fixture_root, &vectors, &vectors);
+        write_site_vector(
+            &fixture_root,
+            "golden/ok_vector",
+            "span_square_commutation",
+            "accepted",
+        );
+        write_site_vector(
+            &fixture_root,
+            "adversarial/reject_vector",
+            "span_square_commutation",
+            "rejected",
+        );
+        write_json_file(
+            &fixture_root.join("adversarial/reject_vector/expect.json"),
+            &json!({
+                "schema": 1,
+                "status": "executable",
+                "result": "rejected",
+                "expectedFailureClasses": ["coherence.span_square_commutation.voilation"],
+            }),
+        );
+
+        let contract = test_contract_with_site_fixture_root("fixtures");
+        let evaluated = check_site_obligation(
+            temp.path(),
+            &contract,
+            "span_square_commutation",
+            evaluate_span_square_without_registry,
+        )
+        .expect("site obligation should evaluate");
+        assert_eq!(
+            evaluated.failure_classes,
+            vec![
+                "coherence.span_square_commutation.failure_class_mismatch".to_string(),
+                "coherence.span_square_commutation.unknown_expected_failure_class".to_string(),
+            ]
+        );
+        let row = evaluated.details["vectors"]
+            .as_array()
+            .expect("vector rows")
+            .iter()
+            .find(|row| row["vectorId"] == "adversarial/reject_vector")
+            .expect("rejected vector row");
+        assert_eq!(
+            row["unknownExpectedFailureClasses"],
+            json!(["coherence.span_square_commutation.voilation"])
+        );
+        assert!(
+            unknown_expected_failure_classes(
+                "span_square_commutation",
+                &["coherence.span_square_commutation.violation".to_string()]
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn check_site_obligation_honors_custom_polarity_prefixes() {
+        let temp = TempDirGuard::new("site-obligation-custom-prefixes");
+        let fixture_root = temp.path().join("fixtures");
+        let vectors = [
+            "pos/ok_vector",
+            "neg/reject_vector",
+            "inv/local",
+            "inv/external",
+        ];
+        write_site_manifest(&fixture_root, &vectors, &vectors);
+        write_site_vector(
+            &fixture_root,
+            "pos/ok_vector",
+            "span_square_commutation",
+            "accepted",
+        );
+        write_site_vector(
+            &fixture_root,
+            "neg/reject_vector",
+            "span_square_commutation",
+            "rejected",
+        );
+        for (vector_id, profile) in [("inv/local", "local"), ("inv/external", "external")] {
+            write_site_vector_with_metadata(
+                &fixture_root,
+                vector_id,
+                "span_square_commutation",
+                "accepted",
+                Some("span_square_equiv"),
+                Some(profile),
+            );
+        }
+
+        let mut contract = test_contract_with_site_fixture_root("fixtures");
+        let evaluate = |contract: &CoherenceContract| {
+            check_site_obligation(
+                temp.path(),
+                contract,
+                "span_square_commutation",
+                evaluate_span_square_without_registry,
+            )
+            .expect("site obligation should evaluate")
+        };
+        let defaults = evaluate(&contract);
+        assert!(
+            defaults
+                .failure_classes
+                .contains(&"coherence.span_square_commutation.missing_golden_vector".to_string())
+        );
+
+        contract.polarity_prefixes = PolarityPrefixes {
+            golden: "pos/".to_string(),
+            adversarial: "neg/".to_string(),
+            invariance: "inv/".to_string(),
+        };
+        let evaluated = evaluate(&contract);
+        assert!(
+            evaluated.failure_classes.is_empty(),
+            "{:?}",
+            evaluated.failure_classes
+        );
+        assert_eq!(
+            evaluated.details["matchedVectorKinds"]["invariance"],
+            json!(2)
+        );
+    }
+
+    #[test]
+    fn polarity_prefixes_reject_empty_and_overlapping_entries() {
+        assert_eq!(PolarityPrefixes::default().validate(), Ok(()));
+        let empty = PolarityPrefixes {
+            adversarial: String::new(),
+            ..PolarityPrefixes::default()
+        };
+        assert_eq!(
+            empty.validate(),
+            Err("polarityPrefixes.adversarial must be non-empty".to_string())
+        );
+        let overlapping = PolarityPrefixes {
+            invariance: "golden/inv/".to_string(),
+            ..PolarityPrefixes::default()
+        };
+        assert!(
+            overlapping
+                .validate()
+                .expect_err("nested prefixes should overlap")
+                .contains("polarityPrefixes.golden")
+        );
+        let duplicate = PolarityPrefixes {
+            golden: "v/".to_string(),
+            adversarial: "v/".to_string(),
+            invariance: "inv/".to_string(),
+        };
+        assert!(duplicate.validate().is_err());
+
+        let temp = TempDirGuard::new("polarity-prefixes-invalid");
+        let mut payload = serde_json::to_value(test_contract_with_fixture_roots("", ""))
+            .expect("contract should serialize");
+        payload["obligations"] = json!([]);
+        payload["polarityPrefixes"] = json!({"golden": "", "adversarial": "adversarial/"});
+        let contract_path = temp.path().join("COHERENCE-CONTRACT.json");
+        write_json_file(&contract_path, &payload);
+        let err = run_coherence_check(temp.path(), &contract_path)
+            .expect_err("empty golden prefix should be rejected at load");
+        assert!(
+            err.to_string()
+                .contains("polarityPrefixes.golden must be non-empty"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
     #[test]
     fn validate_required_obligation_parity_reports_missing_and_unknown() {
         let declared: BTreeSet<String> = ["scope_noncontradiction", "unknown_obligation"]
@@ -4,7 +4,7 @@ use premath_kernel::{
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value, json};
 use sha2::{Digest, Sha256};
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use thiserror::Error;
 
 const PROPOSAL_KINDS: &[&str] = &["value", "derivation", "refinementPlan"];
@@ -191,15 +191,72 @@ fn as_object<'a>(
         .ok_or_else(|| ProposalError::new(failure_class, message.to_string()))
 }
 
+fn steps_dependent(left: &ProposalStep, right: &ProposalStep) -> bool {
+    let touches = |outputs: &[String], refs: &[String]| refs.iter().any(|r| outputs.contains(r));
+    touches(&left.outputs, &right.inputs)
+        || touches(&right.outputs, &left.inputs)
+        || touches(&left.outputs, &right.outputs)
+}
+
+/// Canonical step order: dependent steps keep their relative order, and
+/// among steps whose dependencies are already placed the smallest by
+/// serialized content goes first.
+fn canonical_step_order(steps: &[ProposalStep]) -> Vec<ProposalStep> {
+    let keys: Vec<String> = steps
+        .iter()
+        .map(|step| serde_json::to_string(step).expect("proposal step should serialize"))
+        .collect();
+    let mut pending_deps: Vec<usize> = (0..steps.len())
+        .map(|idx| {
+            (0..idx)
+                .filter(|&prev| steps_dependent(&steps[prev], &steps[idx]))
+                .count()
+        })
+        .collect();
+    let mut ready: BTreeSet<(&str, usize)> = (0..steps.len())
+        .filter(|&idx| pending_deps[idx] == 0)
+        .map(|idx| (keys[idx].as_str(), idx))
+        .collect();
+    let mut ordered = Vec::with_capacity(steps.len());
+    while let Some((_, idx)) = ready.pop_first() {
+        ordered.push(steps[idx].clone());
+        for next in idx + 1..steps.len() {
+            if steps_dependent(&steps[idx], &steps[next]) {
+                pending_deps[next] -= 1;
+                if pending_deps[next] == 0 {
+                    ready.insert((keys[next].as_str(), next));
+                }
+            }
+        }
+    }
+    ordered
+}
+
+fn canonicalize_proposal(canonical: &CanonicalProposal) -> CanonicalProposal {
+    let mut normalized = canonical.clone();
+    normalized.candidate_refs.sort();
+    normalized.candidate_refs.dedup();
+    normalized.steps = canonical.steps.as_deref().map(canonical_step_order);
+    normalized
+}
+
+/// `prop1_` digest of the canonical proposal payload.
+///
+/// Order-insensitive parts are normalized before hashing: `candidateRefs` is
+/// a set, and steps that share no ref (no step consumes or overwrites
+/// another's outputs) may appear in any order. Order is significant between
+/// dependent steps and for the `inputs`/`outputs` lists within a step.
 pub fn compute_proposal_digest(canonical: &CanonicalProposal) -> String {
-    let payload = serde_json::to_value(canonical).expect("proposal should serialize");
+    let payload =
+        serde_json::to_value(canonicalize_proposal(canonical)).expect("proposal should serialize");
     format!("prop1_{}", stable_hash(&payload))
 }
 
+/// `kcir1_` ref over the same canonical payload as [`compute_proposal_digest`].
 pub fn compute_proposal_kcir_ref(canonical: &CanonicalProposal) -> String {
     let payload = json!({
         "kind": "kcir.proposal.v1",
-        "canonicalProposal": canonical,
+        "canonicalProposal": canonicalize_proposal(canonical),
     });
     format!("kcir1_{}", stable_hash(&payload))
 }
@@ -378,7 +435,7 @@ pub fn validate_proposal_payload(raw: &Value) -> Result<ValidatedProposal, Propo
         }
     }
 
-    let canonical = CanonicalProposal {
+    let canonical = canonicalize_proposal(&CanonicalProposal {
         proposal_kind: proposal_kind.clone(),
         target_ctx_ref,
         target_judgment: ProposalTargetJudgment {
@@ -391,7 +448,7 @@ pub fn validate_proposal_payload(raw: &Value) -> Result<ValidatedProposal, Propo
             policy_digest,
         },
         steps: if steps.is_empty() { None } else { Some(steps) },
-    };
+    });
 
     let digest = compute_proposal_digest(&canonical);
     let kcir_ref = compute_proposal_kcir_ref(&canonical);
@@ -598,6 +655,26 @@ pub fn discharge_proposal_obligations(
     }
 }
 
+/// Compile and discharge obligations for many proposals, in input order.
+///
+/// Proposals with the same canonical digest share one compiled obligation
+/// set, so repeated proposals in a bulk check are compiled once. Each entry
+/// equals `discharge_proposal_obligations(p, &compile_proposal_obligations(p))`.
+pub fn discharge_proposal_obligations_batched(
+    proposals: &[CanonicalProposal],
+) -> Vec<ProposalDischarge> {
+    let mut compiled: BTreeMap<String, Vec<ProposalObligation>> = BTreeMap::new();
+    proposals
+        .iter()
+        .map(|canonical| {
+            let obligations = compiled
+                .entry(compute_proposal_digest(canonical))
+                .or_insert_with(|| compile_proposal_obligations(canonical));
+            discharge_proposal_obligations(canonical, obligations)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -657,6 +734,55 @@ mod tests {
         assert_eq!(err.failure_class, "proposal_nondeterministic");
     }
 
+    fn derivation_proposal(steps: Value) -> Value {
+        let mut proposal = base_proposal();
+        proposal["proposalKind"] = json!("derivation");
+        proposal["steps"] = steps;
+        proposal
+    }
+
+    fn step(rule_id: &str, inputs: &[&str], outputs: &[&str]) -> Value {
+        json!({
+            "ruleId": rule_id,
+            "inputs": inputs,
+            "outputs": outputs,
+            "claim": format!("apply {rule_id}"),
+        })
+    }
+
+    fn obligation_ids(canonical: &CanonicalProposal) -> Vec<String> {
+        compile_proposal_obligations(canonical)
+            .into_iter()
+            .map(|obligation| obligation.obligation_id)
+            .collect()
+    }
+
+    #[test]
+    fn proposal_digest_ignores_independent_step_order_only() {
+        let lemma_a = step("eq.trans", &["ref:a"], &["ref:lemma-a"]);
+        let lemma_b = step("eq.sym", &["ref:b"], &["ref:lemma-b"]);
+        let goal = step("eq.rewrite", &["ref:lemma-a", "ref:lemma-b"], &["ref:goal"]);
+        let digest = |steps: Value| {
+            validate_proposal_payload(&derivation_proposal(steps))
+                .expect("proposal should validate")
+        };
+
+        let original = digest(json!([lemma_a, lemma_b, goal]));
+        let swapped_independent = digest(json!([lemma_b, lemma_a, goal]));
+        assert_eq!(original.digest, swapped_independent.digest);
+        assert_eq!(original.kcir_ref, swapped_independent.kcir_ref);
+        assert_eq!(original.canonical, swapped_independent.canonical);
+        assert_eq!(
+            obligation_ids(&original.canonical),
+            obligation_ids(&swapped_independent.canonical)
+        );
+
+        let swapped_dependent = digest(json!([lemma_a, goal, lemma_b]));
+        assert_ne!(original.digest, swapped_dependent.digest);
+        let reversed = digest(json!([goal, lemma_b, lemma_a]));
+        assert_ne!(original.digest, reversed.digest);
+    }
+
     #[test]
     fn compile_and_discharge_ext_gap_rejects() {
         let proposal = json!({
@@ -681,4 +807,37 @@ mod tests {
             vec!["descent_failure".to_string()]
         );
     }
+
+    #[test]
+    fn batched_discharge_matches_single_proposal_discharge() {
+        let mut ambiguous = base_proposal();
+        ambiguous["candidateRefs"] = json!(["obj:alpha", "obj:beta"]);
+        let mut gap = base_proposal();
+        gap["candidateRefs"] = json!([]);
+        let mut refinement = base_proposal();
+        refinement["proposalKind"] = json!("refinementPlan");
+        refinement["candidateRefs"] = json!(["obj:alpha", "hint:adjoint_triangle"]);
+
+        let proposals: Vec<CanonicalProposal> =
+            [base_proposal(), ambiguous, gap, refinement, base_proposal()]
+                .iter()
+                .map(|raw| {
+                    validate_proposal_payload(raw)
+                        .expect("proposal should validate")
+                        .canonical
+                })
+                .collect();
+        let expected: Vec<ProposalDischarge> = proposals
+            .iter()
+            .map(|canonical| {
+                discharge_proposal_obligations(canonical, &compile_proposal_obligations(canonical))
+            })
+            .collect();
+
+        let batched = discharge_proposal_obligations_batched(&proposals);
+        assert_eq!(batched, expected);
+        assert_eq!(batched[0].outcome, "accepted");
+        assert_eq!(batched[1].outcome, "rejected");
+        assert!(discharge_proposal_obligations_batched(&[]).is_empty());
+    }
 }
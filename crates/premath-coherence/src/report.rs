@@ -0,0 +1,881 @@
+use crate::{CoherenceError, CoherenceWitness, ObligationWitness, display_path};
+use serde_json::json;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::io::IsTerminal;
+use std::path::Path;
+
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+const OBLIGATION_HEADER: &str = "Obligation";
+const RESULT_HEADER: &str = "Result";
+const FAILURES_HEADER: &str = "Failures";
+
+const HTML_STYLE: &str = "body{font-family:system-ui,sans-serif;margin:2rem;color:#222}\
+table{border-collapse:collapse}th,td{border:1px solid #ccc;padding:.3rem .6rem;text-align:left}\
+.result{font-weight:600}.accepted{color:#1a7f37}.rejected{color:#cf222e}.skipped{color:#9a6700}\
+pre{background:#f6f8fa;padding:.6rem;overflow-x:auto}section{margin-top:1.5rem}";
+
+/// Weight of the result ordinal in `severity_score`; failure counts are capped
+/// below it so a worse result always outranks more failures.
+const SEVERITY_RESULT_WEIGHT: u32 = 1_000;
+
+impl CoherenceWitness {
+    /// Render obligations as a fixed-width `Obligation | Result | Failures` table.
+    ///
+    /// Result cells are padded before coloring so column alignment is the same
+    /// with and without ANSI escapes.
+    pub fn to_terminal_table(&self, use_color: bool) -> String {
+        let rows: Vec<(&str, &str, String)> = self
+            .obligations
+            .iter()
+            .map(|obligation| {
+                let failures = if obligation.failure_classes.is_empty() {
+                    "-".to_string()
+                } else {
+                    obligation.failure_classes.join(", ")
+                };
+                (
+                    obligation.obligation_id.as_str(),
+                    obligation.result.as_str(),
+                    failures,
+                )
+            })
+            .collect();
+        let obligation_width = rows
+            .iter()
+            .map(|(obligation_id, _, _)| obligation_id.len())
+            .fold(OBLIGATION_HEADER.len(), usize::max);
+        let result_width = rows
+            .iter()
+            .map(|(_, result, _)| result.len())
+            .fold(RESULT_HEADER.len(), usize::max);
+        let failures_width = rows
+            .iter()
+            .map(|(_, _, failures)| failures.len())
+            .fold(FAILURES_HEADER.len(), usize::max);
+
+        let mut out = String::new();
+        push_row(
+            &mut out,
+            &format!("{OBLIGATION_HEADER:<obligation_width$}"),
+            &format!("{RESULT_HEADER:<result_width$}"),
+            FAILURES_HEADER,
+        );
+        push_row(
+            &mut out,
+            &"-".repeat(obligation_width),
+            &"-".repeat(result_width),
+            &"-".repeat(failures_width),
+        );
+        for (obligation_id, result, failures) in &rows {
+            let padded_result = format!("{result:<result_width$}");
+            let result_cell = match (use_color, *result) {
+                (true, "accepted") => format!("{ANSI_GREEN}{padded_result}{ANSI_RESET}"),
+                (true, "rejected") => format!("{ANSI_RED}{padded_result}{ANSI_RESET}"),
+                _ => padded_result,
+            };
+            push_row(
+                &mut out,
+                &format!("{obligation_id:<obligation_width$}"),
+                &result_cell,
+                failures,
+            );
+        }
+        out
+    }
+
+    /// Count obligations by `result`.
+    pub fn result_histogram(&self) -> BTreeMap<String, usize> {
+        let mut histogram = BTreeMap::new();
+        for obligation in &self.obligations {
+            *histogram.entry(obligation.result.clone()).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Count, per failure class, how many obligations reported it.
+    pub fn failure_class_histogram(&self) -> BTreeMap<String, usize> {
+        let mut histogram = BTreeMap::new();
+        for obligation in &self.obligations {
+            let classes: BTreeSet<&String> = obligation.failure_classes.iter().collect();
+            for class_name in classes {
+                *histogram.entry(class_name.clone()).or_insert(0) += 1;
+            }
+        }
+        histogram
+    }
+
+    /// Obligation id of a `coherence.{obligation_id}.{class}` failure class.
+    pub fn obligation_domain_for_class(class: &str) -> Option<&str> {
+        let (obligation_id, specific) = class.strip_prefix("coherence.")?.split_once('.')?;
+        (!obligation_id.is_empty() && !specific.is_empty()).then_some(obligation_id)
+    }
+
+    /// Group `failure_classes` by obligation domain; classes without the
+    /// `coherence.{obligation_id}.` prefix land under `"unknown"`.
+    pub fn failure_classes_by_obligation(&self) -> BTreeMap<String, Vec<String>> {
+        let mut grouped: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for class in &self.failure_classes {
+            let domain = Self::obligation_domain_for_class(class).unwrap_or("unknown");
+            grouped
+                .entry(domain.to_string())
+                .or_default()
+                .push(class.clone());
+        }
+        grouped
+    }
+
+    /// Same as [`CoherenceWitness::to_terminal_table`], coloring only when
+    /// stdout is a terminal.
+    pub fn to_terminal_table_auto(&self) -> String {
+        self.to_terminal_table(std::io::stdout().is_terminal())
+    }
+}
+
+/// `accepted` < `partial` < `rejected`; unknown results rank as rejected.
+fn result_ordinal(result: &str) -> u32 {
+    match result {
+        "accepted" => 0,
+        "partial" => 1,
+        _ => 2,
+    }
+}
+
+impl CoherenceWitness {
+    fn severity_key(&self) -> (u32, usize) {
+        (result_ordinal(&self.result), self.failure_classes.len())
+    }
+
+    /// Weighted `(result, failure count)` score; higher is more severe.
+    pub fn severity_score(&self) -> u32 {
+        let failure_count = u32::try_from(self.failure_classes.len())
+            .unwrap_or(u32::MAX)
+            .min(SEVERITY_RESULT_WEIGHT - 1);
+        result_ordinal(&self.result) * SEVERITY_RESULT_WEIGHT + failure_count
+    }
+}
+
+/// Orders by `(result, failure count)`. Distinct witnesses with the same key
+/// are incomparable rather than equal.
+impl PartialOrd for CoherenceWitness {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.severity_key().cmp(&other.severity_key()) {
+            Ordering::Equal => (self == other).then_some(Ordering::Equal),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// Sort most severe first; ties keep their input order.
+pub fn sort_witnesses_by_severity(witnesses: &mut [CoherenceWitness]) {
+    witnesses.sort_by_key(|witness| std::cmp::Reverse(witness.severity_key()));
+}
+
+/// Plain-text report: the overall verdict, one `PASS`/`FAIL`/`SKIP` line per
+/// obligation with its failure classes indented below, and a closing count.
+/// Skipped obligations do not count as failed.
+pub fn render_witness_text(witness: &CoherenceWitness) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{}: {}", witness.contract_id, witness.result);
+    let mut failed = 0usize;
+    for obligation in &witness.obligations {
+        let status = match obligation.result.as_str() {
+            "accepted" => "PASS",
+            "skipped" => "SKIP",
+            _ => {
+                failed += 1;
+                "FAIL"
+            }
+        };
+        let _ = writeln!(out, "  {status} {}", obligation.obligation_id);
+        for class in &obligation.failure_classes {
+            let _ = writeln!(out, "      {class}");
+        }
+    }
+    let _ = writeln!(
+        out,
+        "{failed}/{} obligations failed, {} failure classes",
+        witness.obligations.len(),
+        witness.failure_classes.len()
+    );
+    out
+}
+
+/// Occurrences of each failure class across every obligation of every witness.
+///
+/// Counts come from obligation-level classes, so a class is not double
+/// counted via the witness-level `failure_classes` union. For a single
+/// witness, see `CoherenceWitness::failure_class_histogram`.
+pub fn obligation_failure_class_histogram(
+    witnesses: &[CoherenceWitness],
+) -> BTreeMap<String, usize> {
+    let mut histogram = BTreeMap::new();
+    for class in witnesses
+        .iter()
+        .flat_map(|witness| &witness.obligations)
+        .flat_map(|obligation| &obligation.failure_classes)
+    {
+        *histogram.entry(class.clone()).or_insert(0) += 1;
+    }
+    histogram
+}
+
+impl ObligationWitness {
+    /// True when every failure is a `surface_error`, i.e. the check could not
+    /// read its inputs rather than finding a coherence violation.
+    pub fn is_retriable(&self) -> bool {
+        let surface_error = format!("coherence.{}.surface_error", self.obligation_id);
+        !self.failure_classes.is_empty()
+            && self
+                .failure_classes
+                .iter()
+                .all(|class| class.starts_with(&surface_error))
+    }
+}
+
+impl CoherenceWitness {
+    /// Required obligation ids with no entry in `obligations`, in required order.
+    pub fn missing_obligation_ids(&self) -> Vec<&'static str> {
+        let present: BTreeSet<&str> = self
+            .obligations
+            .iter()
+            .map(|obligation| obligation.obligation_id.as_str())
+            .collect();
+        crate::REQUIRED_OBLIGATION_IDS
+            .iter()
+            .copied()
+            .filter(|obligation_id| !present.contains(obligation_id))
+            .collect()
+    }
+
+    /// True when every required obligation has a witness entry.
+    pub fn is_complete(&self) -> bool {
+        self.missing_obligation_ids().is_empty()
+    }
+
+    pub fn retriable_obligations(&self) -> Vec<&ObligationWitness> {
+        self.obligations
+            .iter()
+            .filter(|obligation| obligation.is_retriable())
+            .collect()
+    }
+
+    /// Copy with retriable obligations replaced by `skipped` entries; the
+    /// aggregate failure classes and result are recomputed from the rest.
+    ///
+    /// Digests are recomputed under the witness's digest scheme, and the
+    /// result gets its own `witness_id` derived from this one.
+    pub fn strip_retriable_failures(&self) -> CoherenceWitness {
+        if self.retriable_obligations().is_empty() {
+            return self.clone();
+        }
+        let scheme = self.digest_scheme;
+        let mut stripped = self.clone();
+        for obligation in &mut stripped.obligations {
+            if obligation.is_retriable() {
+                let details = json!({
+                    "skippedReason": "surface_error",
+                    "retriableFailureClasses": obligation.failure_classes,
+                });
+                *obligation = ObligationWitness {
+                    obligation_id: obligation.obligation_id.clone(),
+                    result: "skipped".to_string(),
+                    failure_classes: Vec::new(),
+                    details_digest: crate::semantic_digest(&details, scheme),
+                    details,
+                    inputs_digest: None,
+                };
+            }
+        }
+        let failure_classes: BTreeSet<String> = stripped
+            .obligations
+            .iter()
+            .flat_map(|obligation| obligation.failure_classes.iter().cloned())
+            .collect();
+        stripped.failure_classes = failure_classes.into_iter().collect();
+        stripped.obligations_details_digest =
+            crate::obligations_details_digest(&stripped.obligations, scheme);
+        stripped.witness_id = crate::derived_witness_id(
+            &self.witness_id,
+            "strip_retriable_failures",
+            &stripped.obligations_details_digest,
+            scheme,
+        );
+        stripped.result = if stripped.failure_classes.is_empty() {
+            "accepted".to_string()
+        } else {
+            "rejected".to_string()
+        };
+        stripped
+    }
+}
+
+impl CoherenceWitness {
+    /// Self-contained HTML page: a summary table plus one section per
+    /// obligation with its failure classes and collapsible `details` JSON.
+    /// Styling is inline; the page loads no external resources.
+    pub fn to_html_report(&self, title: &str) -> String {
+        let mut out = String::new();
+        let title = escape_html(title);
+        let _ = write!(
+            out,
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title}</title>\n<style>{HTML_STYLE}</style>\n</head>\n<body>\n\
+             <h1>{title}</h1>\n<p>Contract <code>{}</code> ({}): \
+             <span class=\"result {}\">{}</span></p>\n",
+            escape_html(&self.contract_id),
+            escape_html(&self.contract_digest),
+            result_css_class(&self.result),
+            escape_html(&self.result),
+        );
+
+        out.push_str(
+            "<table>\n<thead><tr><th>Obligation</th><th>Result</th><th>Failures</th></tr></thead>\n<tbody>\n",
+        );
+        for obligation in &self.obligations {
+            let obligation_id = escape_html(&obligation.obligation_id);
+            let _ = writeln!(
+                out,
+                "<tr><td><a href=\"#obligation-{obligation_id}\">{obligation_id}</a></td>\
+                 <td class=\"result {}\">{}</td><td>{}</td></tr>",
+                result_css_class(&obligation.result),
+                escape_html(&obligation.result),
+                obligation.failure_classes.len(),
+            );
+        }
+        out.push_str("</tbody>\n</table>\n");
+
+        for obligation in &self.obligations {
+            let obligation_id = escape_html(&obligation.obligation_id);
+            let _ = writeln!(
+                out,
+                "<section class=\"obligation\" id=\"obligation-{obligation_id}\">\n\
+                 <h2>{obligation_id} <span class=\"result {}\">{}</span></h2>",
+                result_css_class(&obligation.result),
+                escape_html(&obligation.result),
+            );
+            if !obligation.failure_classes.is_empty() {
+                out.push_str("<ul>\n");
+                for class in &obligation.failure_classes {
+                    let _ = writeln!(out, "<li><code>{}</code></li>", escape_html(class));
+                }
+                out.push_str("</ul>\n");
+            }
+            let details =
+                serde_json::to_string_pretty(&obligation.details).unwrap_or_else(|_| "{}".into());
+            let _ = writeln!(
+                out,
+                "<details><summary>details</summary><pre>{}</pre></details>\n</section>",
+                escape_html(&details)
+            );
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    /// Write [`CoherenceWitness::to_html_report`] to `path`.
+    pub fn to_html_report_file(&self, path: &Path, title: &str) -> Result<(), CoherenceError> {
+        std::fs::write(path, self.to_html_report(title)).map_err(|source| {
+            CoherenceError::WriteFile {
+                path: display_path(path),
+                source,
+            }
+        })
+    }
+}
+
+fn result_css_class(result: &str) -> &'static str {
+    match result {
+        "accepted" => "accepted",
+        "skipped" => "skipped",
+        _ => "rejected",
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn push_row(out: &mut String, obligation: &str, result: &str, failures: &str) {
+    out.push_str(obligation);
+    out.push_str("  ");
+    out.push_str(result);
+    out.push_str("  ");
+    out.push_str(failures);
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        CoherenceBinding, CoherenceConstructor, CoherenceConstructorSources, DigestScheme,
+    };
+
+    fn sample_witness() -> CoherenceWitness {
+        let binding = CoherenceBinding {
+            normalizer_id: "normalizer.coherence.v1".to_string(),
+            policy_digest: "policy.coherence.v1".to_string(),
+        };
+        CoherenceWitness {
+            schema: 1,
+            witness_kind: "premath.coherence.v1".to_string(),
+            contract_kind: "premath.coherence.contract.v1".to_string(),
+            contract_id: "coherence.test.v1".to_string(),
+            contract_ref: "specs/premath/draft/COHERENCE-CONTRACT.json".to_string(),
+            contract_digest: "cohctr1_test".to_string(),
+            witness_id: "wit1_test".to_string(),
+            binding: binding.clone(),
+            result: "rejected".to_string(),
+            obligations: vec![
+                ObligationWitness {
+                    obligation_id: "scope_noncontradiction".to_string(),
+                    result: "accepted".to_string(),
+                    failure_classes: Vec::new(),
+                    details: json!({}),
+                    details_digest: "sem1_test".to_string(),
+                    inputs_digest: None,
+                },
+                ObligationWitness {
+                    obligation_id: "gate_chain_parity".to_string(),
+                    result: "rejected".to_string(),
+                    failure_classes: vec![
+                        "coherence.gate_chain_parity.schema_lifecycle_invalid".to_string(),
+                        "coherence.gate_chain_parity.unbound".to_string(),
+                    ],
+                    details: json!({}),
+                    details_digest: "sem1_test".to_string(),
+                    inputs_digest: None,
+                },
+            ],
+            obligations_details_digest: "sem1_test".to_string(),
+            failure_classes: vec![
+                "coherence.gate_chain_parity.schema_lifecycle_invalid".to_string(),
+                "coherence.gate_chain_parity.unbound".to_string(),
+            ],
+            constructor: CoherenceConstructor {
+                schema: 1,
+                constructor_kind: "premath.coherence.constructor.v1".to_string(),
+                contract_ref: "specs/premath/draft/COHERENCE-CONTRACT.json".to_string(),
+                contract_digest: "cohctr1_test".to_string(),
+                binding,
+                declared_obligation_ids: Vec::new(),
+                required_obligation_ids: Vec::new(),
+                execution_obligation_ids: Vec::new(),
+                sources: CoherenceConstructorSources {
+                    control_plane_contract_path: String::new(),
+                    doctrine_site_path: String::new(),
+                    doctrine_site_input_path: String::new(),
+                    doctrine_operation_registry_path: String::new(),
+                },
+            },
+            consulted_surfaces: BTreeMap::new(),
+            declared_surfaces: BTreeMap::new(),
+            warnings: Vec::new(),
+            contract_snapshot: None,
+            digest_scheme: DigestScheme::default(),
+        }
+    }
+
+    fn surface_error_obligation(obligation_id: &str) -> ObligationWitness {
+        ObligationWitness {
+            obligation_id: obligation_id.to_string(),
+            result: "rejected".to_string(),
+            failure_classes: vec![format!("coherence.{obligation_id}.surface_error")],
+            details: json!({"error": "missing surface"}),
+            details_digest: "sem1_test".to_string(),
+            inputs_digest: None,
+        }
+    }
+
+    #[test]
+    fn failure_classes_group_by_obligation_domain() {
+        let mut witness = sample_witness();
+        witness.failure_classes.extend([
+            "coherence.contract.unknown_obligation".to_string(),
+            "coherence.gate_chain_parity".to_string(),
+            "unification.evidence_stage1.parity.missing".to_string(),
+        ]);
+
+        assert_eq!(
+            CoherenceWitness::obligation_domain_for_class(
+                "coherence.span_square_commutation.lane.unknown"
+            ),
+            Some("span_square_commutation")
+        );
+        assert_eq!(
+            CoherenceWitness::obligation_domain_for_class("coherence..violation"),
+            None
+        );
+        assert_eq!(
+            witness.failure_classes_by_obligation(),
+            BTreeMap::from([
+                (
+                    "contract".to_string(),
+                    vec!["coherence.contract.unknown_obligation".to_string()]
+                ),
+                (
+                    "gate_chain_parity".to_string(),
+                    vec![
+                        "coherence.gate_chain_parity.schema_lifecycle_invalid".to_string(),
+                        "coherence.gate_chain_parity.unbound".to_string(),
+                    ]
+                ),
+                (
+                    "unknown".to_string(),
+                    vec![
+                        "coherence.gate_chain_parity".to_string(),
+                        "unification.evidence_stage1.parity.missing".to_string(),
+                    ]
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn html_report_is_self_contained_with_one_section_per_obligation() {
+        let mut witness = sample_witness();
+        witness.obligations = crate::REQUIRED_OBLIGATION_IDS
+            .iter()
+            .map(|obligation_id| surface_error_obligation(obligation_id))
+            .collect();
+        witness.obligations[0].details = json!({"note": "<script>alert(1)</script>"});
+
+        let html = witness.to_html_report("Coherence <nightly>");
+        assert!(html.starts_with("<!DOCTYPE html>\n<html"));
+        assert!(html.contains("<body>"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert!(!html.contains("http://") && !html.contains("https://"));
+        assert!(html.contains("<title>Coherence &lt;nightly&gt;</title>"));
+        assert!(!html.contains("<script>"));
+        assert_eq!(
+            html.matches("<section class=\"obligation\"").count(),
+            crate::REQUIRED_OBLIGATION_IDS.len()
+        );
+        assert_eq!(
+            html.matches("<details>").count(),
+            crate::REQUIRED_OBLIGATION_IDS.len()
+        );
+        for obligation_id in crate::REQUIRED_OBLIGATION_IDS {
+            assert_eq!(
+                html.matches(&format!("id=\"obligation-{obligation_id}\""))
+                    .count(),
+                1
+            );
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "premath-coherence-report-{}.html",
+            std::process::id()
+        ));
+        witness
+            .to_html_report_file(&path, "Coherence <nightly>")
+            .expect("report should write");
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("report should read"),
+            html
+        );
+        std::fs::remove_file(&path).expect("temp report should be removable");
+    }
+
+    #[test]
+    fn missing_obligation_ids_follow_required_order() {
+        let mut witness = sample_witness();
+        witness.obligations = crate::REQUIRED_OBLIGATION_IDS
+            .iter()
+            .map(|obligation_id| surface_error_obligation(obligation_id))
+            .collect();
+        assert!(witness.is_complete());
+        assert!(witness.missing_obligation_ids().is_empty());
+
+        let last = crate::REQUIRED_OBLIGATION_IDS.len() - 1;
+        witness.obligations.remove(last);
+        witness.obligations.remove(0);
+        witness
+            .obligations
+            .push(surface_error_obligation("contract_obligation_set"));
+        assert!(!witness.is_complete());
+        assert_eq!(
+            witness.missing_obligation_ids(),
+            vec![
+                crate::REQUIRED_OBLIGATION_IDS[0],
+                crate::REQUIRED_OBLIGATION_IDS[last]
+            ]
+        );
+    }
+
+    #[test]
+    fn retriable_obligations_are_surface_errors_only() {
+        let mut witness = sample_witness();
+        witness
+            .obligations
+            .push(surface_error_obligation("capability_parity"));
+        let mut mixed = surface_error_obligation("operation_reachability");
+        mixed
+            .failure_classes
+            .push("coherence.operation_reachability.unreachable".to_string());
+        witness.obligations.push(mixed);
+        let mut foreign = surface_error_obligation("overlay_traceability");
+        foreign.failure_classes = vec!["coherence.capability_parity.surface_error".to_string()];
+        witness.obligations.push(foreign);
+
+        let retriable: Vec<&str> = witness
+            .retriable_obligations()
+            .iter()
+            .map(|obligation| obligation.obligation_id.as_str())
+            .collect();
+        assert_eq!(retriable, vec!["capability_parity"]);
+        assert!(!witness.obligations[0].is_retriable());
+        assert!(!witness.obligations[1].is_retriable());
+    }
+
+    #[test]
+    fn strip_retriable_failures_skips_surface_errors_and_recomputes_result() {
+        let mut witness = sample_witness();
+        witness
+            .obligations
+            .push(surface_error_obligation("capability_parity"));
+        witness
+            .failure_classes
+            .push("coherence.capability_parity.surface_error".to_string());
+
+        let stripped = witness.strip_retriable_failures();
+        assert_eq!(stripped.obligations.len(), witness.obligations.len());
+        let skipped = &stripped.obligations[2];
+        assert_eq!(skipped.result, "skipped");
+        assert!(skipped.failure_classes.is_empty());
+        assert_eq!(
+            skipped.details["retriableFailureClasses"],
+            json!(["coherence.capability_parity.surface_error"])
+        );
+        assert_eq!(stripped.result, "rejected");
+        assert_eq!(
+            stripped.failure_classes,
+            vec![
+                "coherence.gate_chain_parity.schema_lifecycle_invalid".to_string(),
+                "coherence.gate_chain_parity.unbound".to_string(),
+            ]
+        );
+
+        assert_eq!(
+            skipped.details_digest,
+            crate::semantic_digest(&skipped.details, DigestScheme::default())
+        );
+        assert_eq!(
+            stripped.obligations_details_digest,
+            crate::obligations_details_digest(&stripped.obligations, DigestScheme::default())
+        );
+        assert_ne!(
+            stripped.obligations_details_digest,
+            witness.obligations_details_digest
+        );
+        assert!(stripped.witness_id.starts_with("wit1_"));
+        assert_ne!(stripped.witness_id, witness.witness_id);
+        assert_eq!(
+            witness.strip_retriable_failures().witness_id,
+            stripped.witness_id
+        );
+
+        let mut scheme_v2 = witness.clone();
+        scheme_v2.digest_scheme = crate::DigestScheme::new(2);
+        let stripped_v2 = scheme_v2.strip_retriable_failures();
+        assert!(stripped_v2.witness_id.starts_with("wit2_"));
+        assert!(
+            stripped_v2.obligations[2]
+                .details_digest
+                .starts_with("sem2_")
+        );
+
+        witness.obligations.remove(1);
+        let only_surface = witness.strip_retriable_failures();
+        assert_eq!(only_surface.result, "accepted");
+        assert!(only_surface.failure_classes.is_empty());
+        assert_eq!(witness.result, "rejected");
+
+        let unchanged = sample_witness().strip_retriable_failures();
+        assert_eq!(unchanged, sample_witness());
+    }
+
+    fn witness_with(result: &str, failure_count: usize) -> CoherenceWitness {
+        let mut witness = sample_witness();
+        witness.result = result.to_string();
+        witness.failure_classes = (0..failure_count)
+            .map(|idx| format!("coherence.test.failure_{idx}"))
+            .collect();
+        witness
+    }
+
+    #[test]
+    fn render_witness_text_snapshot() {
+        let expected = [
+            "coherence.test.v1: rejected",
+            "  PASS scope_noncontradiction",
+            "  FAIL gate_chain_parity",
+            "      coherence.gate_chain_parity.schema_lifecycle_invalid",
+            "      coherence.gate_chain_parity.unbound",
+            "  SKIP transport_functoriality",
+            "1/3 obligations failed, 2 failure classes",
+            "",
+        ]
+        .join("\n");
+        let mut witness = sample_witness();
+        witness.obligations.push(ObligationWitness {
+            obligation_id: "transport_functoriality".to_string(),
+            result: "skipped".to_string(),
+            failure_classes: Vec::new(),
+            details: json!({"skippedReason": "surface_error"}),
+            details_digest: "sem1_test".to_string(),
+            inputs_digest: None,
+        });
+        assert_eq!(render_witness_text(&witness), expected);
+    }
+
+    #[test]
+    fn obligation_failure_class_histogram_counts_across_witnesses() {
+        let first = sample_witness();
+        let mut second = sample_witness();
+        second.obligations[1].failure_classes =
+            vec!["coherence.gate_chain_parity.unbound".to_string()];
+
+        let histogram = obligation_failure_class_histogram(&[first, second]);
+        assert_eq!(
+            histogram,
+            BTreeMap::from([
+                (
+                    "coherence.gate_chain_parity.schema_lifecycle_invalid".to_string(),
+                    1
+                ),
+                ("coherence.gate_chain_parity.unbound".to_string(), 2),
+            ])
+        );
+        assert!(obligation_failure_class_histogram(&[]).is_empty());
+    }
+
+    #[test]
+    fn sort_witnesses_by_severity_ranks_result_before_failure_count() {
+        let mut witnesses = vec![
+            witness_with("accepted", 2),
+            witness_with("rejected", 2),
+            witness_with("partial", 2),
+            witness_with("rejected", 3),
+            witness_with("accepted", 0),
+        ];
+        sort_witnesses_by_severity(&mut witnesses);
+        let order: Vec<(&str, usize)> = witnesses
+            .iter()
+            .map(|witness| (witness.result.as_str(), witness.failure_classes.len()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("rejected", 3),
+                ("rejected", 2),
+                ("partial", 2),
+                ("accepted", 2),
+                ("accepted", 0),
+            ]
+        );
+        let scores: Vec<u32> = witnesses
+            .iter()
+            .map(CoherenceWitness::severity_score)
+            .collect();
+        assert_eq!(scores, vec![2003, 2002, 1002, 2, 0]);
+    }
+
+    #[test]
+    fn partial_cmp_orders_by_result_then_failure_count() {
+        assert!(witness_with("partial", 0) > witness_with("accepted", 5));
+        assert!(witness_with("rejected", 1) > witness_with("partial", 1));
+        assert!(witness_with("rejected", 2) > witness_with("rejected", 1));
+        assert_eq!(
+            witness_with("rejected", 2).partial_cmp(&witness_with("rejected", 2)),
+            Some(Ordering::Equal)
+        );
+        let mut renamed = witness_with("rejected", 2);
+        renamed.contract_id = "coherence.other.v1".to_string();
+        assert_eq!(renamed.partial_cmp(&witness_with("rejected", 2)), None);
+    }
+
+    #[test]
+    fn terminal_table_without_color_is_fixed_width() {
+        insta::assert_snapshot!(sample_witness().to_terminal_table(false), @r"
+        Obligation              Result    Failures
+        ----------------------  --------  -----------------------------------------------------------------------------------------
+        scope_noncontradiction  accepted  -
+        gate_chain_parity       rejected  coherence.gate_chain_parity.schema_lifecycle_invalid, coherence.gate_chain_parity.unbound
+        ");
+    }
+
+    #[cfg(feature = "markdown-surfaces")]
+    #[test]
+    fn histograms_count_results_and_contributing_obligations() {
+        let mut witness = sample_witness();
+        witness.obligations = crate::REQUIRED_OBLIGATION_IDS
+            .iter()
+            .enumerate()
+            .map(|(index, obligation_id)| {
+                let mut failure_classes = Vec::new();
+                if index % 5 == 0 {
+                    failure_classes.push("coherence.shared.surface_error".to_string());
+                }
+                if index == 5 {
+                    failure_classes.push(format!("coherence.{obligation_id}.violation"));
+                    failure_classes.push(format!("coherence.{obligation_id}.violation"));
+                }
+                ObligationWitness {
+                    obligation_id: (*obligation_id).to_string(),
+                    result: if failure_classes.is_empty() {
+                        "accepted".to_string()
+                    } else {
+                        "rejected".to_string()
+                    },
+                    failure_classes,
+                    details: json!({}),
+                    details_digest: "sem1_test".to_string(),
+                    inputs_digest: None,
+                }
+            })
+            .collect();
+        assert_eq!(witness.obligations.len(), 14);
+
+        assert_eq!(
+            witness.result_histogram(),
+            BTreeMap::from([("accepted".to_string(), 11), ("rejected".to_string(), 3)])
+        );
+        assert_eq!(
+            witness.failure_class_histogram(),
+            BTreeMap::from([
+                ("coherence.shared.surface_error".to_string(), 3),
+                ("coherence.transport_functoriality.violation".to_string(), 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn terminal_table_with_color_wraps_result_cells() {
+        let table = sample_witness().to_terminal_table(true);
+        assert!(table.contains("\x1b[32maccepted\x1b[0m"));
+        assert!(table.contains("\x1b[31mrejected\x1b[0m"));
+
+        let stripped = table
+            .replace(ANSI_GREEN, "")
+            .replace(ANSI_RED, "")
+            .replace(ANSI_RESET, "");
+        assert_eq!(stripped, sample_witness().to_terminal_table(false));
+    }
+}
@@ -19,6 +19,28 @@ pub struct RequiredWitnessDecideRequest {
     pub gate_witness_payloads: Option<BTreeMap<String, Value>>,
     #[serde(default)]
     pub native_required_checks: Vec<String>,
+    /// Record each decision rule and its outcome in `trace`.
+    #[serde(default)]
+    pub with_trace: bool,
+}
+
+/// One evaluated decision rule. `outcome` is `pass`, `fail`, or `skipped`;
+/// `errors` are the errors this rule contributed to the result.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionStep {
+    pub rule: String,
+    pub outcome: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+fn record_step(trace: &mut Vec<DecisionStep>, rule: &str, errors: &[String]) {
+    trace.push(DecisionStep {
+        rule: rule.to_string(),
+        outcome: if errors.is_empty() { "pass" } else { "fail" }.to_string(),
+        errors: errors.to_vec(),
+    });
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -40,6 +62,9 @@ pub struct RequiredWitnessDecideResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required_checks: Option<Vec<String>>,
     pub errors: Vec<String>,
+    /// Rules in evaluation order; present only when the request sets `withTrace`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<Vec<DecisionStep>>,
 }
 
 fn normalize_path(path: &str) -> String {
@@ -113,26 +138,38 @@ fn reject_result(
         policy_digest: metadata.policy_digest,
         required_checks: metadata.required_checks,
         errors,
+        trace: None,
     }
 }
 
 pub fn decide_required_witness_request(
     request: &RequiredWitnessDecideRequest,
+) -> RequiredWitnessDecideResult {
+    let mut trace = Vec::new();
+    let mut result = decide_with_trace(request, &mut trace);
+    result.trace = request.with_trace.then_some(trace);
+    result
+}
+
+fn decide_with_trace(
+    request: &RequiredWitnessDecideRequest,
+    trace: &mut Vec<DecisionStep>,
 ) -> RequiredWitnessDecideResult {
     let Some(witness) = request.witness.as_object() else {
-        return reject_result(
-            "invalid_witness_shape",
-            vec!["witness must be an object".to_string()],
-            RejectMetadata::default(),
-        );
+        let errors = vec!["witness must be an object".to_string()];
+        record_step(trace, "witness_shape", &errors);
+        return reject_result("invalid_witness_shape", errors, RejectMetadata::default());
     };
+    record_step(trace, "witness_shape", &[]);
 
     let changed_paths = match extract_string_list(witness.get("changedPaths"), "changedPaths") {
         Ok(paths) => paths,
         Err(errors) => {
+            record_step(trace, "changed_paths", &errors);
             return reject_result("invalid_witness_shape", errors, RejectMetadata::default());
         }
     };
+    record_step(trace, "changed_paths", &[]);
 
     let witness_root = request.witness_root.as_ref().map(Path::new);
     let verify = verify_required_witness_payload(
@@ -144,22 +181,35 @@ pub fn decide_required_witness_request(
     );
 
     let mut errors = verify.errors;
+    record_step(trace, "witness_verification", &errors);
     if let Some(expected_paths_raw) = request.expected_changed_paths.as_ref() {
         let expected_paths = normalize_paths(expected_paths_raw);
         let witness_paths = normalize_paths(&changed_paths);
+        let mut delta_errors = Vec::new();
         if expected_paths != witness_paths {
-            errors.push(format!(
+            delta_errors.push(format!(
                 "delta comparison mismatch (detected={expected_paths:?}, witness={witness_paths:?})"
             ));
         }
+        record_step(trace, "delta_comparison", &delta_errors);
+        errors.extend(delta_errors);
+    } else {
+        trace.push(DecisionStep {
+            rule: "delta_comparison".to_string(),
+            outcome: "skipped".to_string(),
+            errors: Vec::new(),
+        });
     }
 
     let witness_verdict = witness.get("verdictClass").and_then(Value::as_str);
+    let mut verdict_errors = Vec::new();
     if witness_verdict != Some(VERDICT_ACCEPTED) {
-        errors.push(format!(
+        verdict_errors.push(format!(
             "required witness verdict must be accepted for decision accept (actual={witness_verdict:?})"
         ));
     }
+    record_step(trace, "verdict_class", &verdict_errors);
+    errors.extend(verdict_errors);
 
     let decision = if errors.is_empty() {
         "accept"
@@ -183,6 +233,7 @@ pub fn decide_required_witness_request(
         policy_digest: verify.derived.policy_digest,
         required_checks: Some(verify.derived.required_checks),
         errors,
+        trace: None,
     }
 }
 
@@ -295,6 +346,7 @@ mod tests {
             witness_root: None,
             gate_witness_payloads: Some(gate_payloads),
             native_required_checks: Vec::new(),
+            with_trace: false,
         };
         let result = decide_required_witness_request(&request);
         assert_eq!(result.decision, "accept");
@@ -313,6 +365,7 @@ mod tests {
             witness_root: None,
             gate_witness_payloads: Some(gate_payloads),
             native_required_checks: Vec::new(),
+            with_trace: false,
         };
         let result = decide_required_witness_request(&request);
         assert_eq!(result.decision, "reject");
@@ -325,6 +378,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decide_required_witness_trace_lists_rules_for_rejection() {
+        let (witness, gate_payloads) = accepted_fixture();
+        let request = RequiredWitnessDecideRequest {
+            witness,
+            expected_changed_paths: Some(vec!["README.md".to_string()]),
+            witness_root: None,
+            gate_witness_payloads: Some(gate_payloads),
+            native_required_checks: Vec::new(),
+            with_trace: true,
+        };
+        let result = decide_required_witness_request(&request);
+        assert_eq!(result.decision, "reject");
+        let trace = result.trace.expect("trace should be recorded");
+        let steps: Vec<(&str, &str)> = trace
+            .iter()
+            .map(|step| (step.rule.as_str(), step.outcome.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                ("witness_shape", "pass"),
+                ("changed_paths", "pass"),
+                ("witness_verification", "pass"),
+                ("delta_comparison", "fail"),
+                ("verdict_class", "pass"),
+            ]
+        );
+        let traced_errors: Vec<String> = trace.into_iter().flat_map(|step| step.errors).collect();
+        assert_eq!(traced_errors, result.errors);
+
+        let untraced = decide_required_witness_request(&RequiredWitnessDecideRequest {
+            with_trace: false,
+            ..request
+        });
+        assert!(untraced.trace.is_none());
+        assert_eq!(untraced.errors, result.errors);
+    }
+
     #[test]
     fn decide_required_witness_rejects_invalid_shape() {
         let request = RequiredWitnessDecideRequest {
@@ -333,6 +425,7 @@ mod tests {
             witness_root: None,
             gate_witness_payloads: None,
             native_required_checks: Vec::new(),
+            with_trace: false,
         };
         let result = decide_required_witness_request(&request);
         assert_eq!(result.decision, "reject");
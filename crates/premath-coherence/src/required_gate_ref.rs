@@ -28,12 +28,25 @@ pub struct RequiredGateRefRequest {
     pub fallback: Option<RequiredGateRefFallback>,
 }
 
+/// Why a gate ref was synthesized from `fallback` instead of `gatePayload`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RequiredGateRefFallbackReason {
+    /// No `gatePayload` was provided.
+    PrimaryRefMissing,
+    /// `gatePayload` was provided but is not a JSON object.
+    PrimaryRefInvalid,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct RequiredGateRefResult {
     pub gate_witness_ref: RequiredGateWitnessRef,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gate_payload: Option<Value>,
+    /// Set exactly when the fallback path produced `gate_payload`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_reason: Option<RequiredGateRefFallbackReason>,
 }
 
 fn ensure_non_empty(value: &str, label: &str) -> Result<String, RequiredWitnessError> {
@@ -227,14 +240,18 @@ pub fn build_required_gate_ref(
     let check_id = ensure_non_empty(&request.check_id, "checkId")?;
     let artifact_rel_path = ensure_non_empty(&request.artifact_rel_path, "artifactRelPath")?;
 
-    let source = request
-        .source
-        .as_deref()
-        .unwrap_or(if request.fallback.is_some() {
-            "fallback"
-        } else {
-            "native"
-        });
+    let primary = match request.gate_payload.as_ref() {
+        None => Err(RequiredGateRefFallbackReason::PrimaryRefMissing),
+        Some(payload) => payload
+            .as_object()
+            .ok_or(RequiredGateRefFallbackReason::PrimaryRefInvalid),
+    };
+
+    let source = request.source.as_deref().unwrap_or(if primary.is_ok() {
+        "native"
+    } else {
+        "fallback"
+    });
     if source != "native" && source != "fallback" {
         return Err(RequiredWitnessError {
             failure_class: "required_gate_ref_invalid".to_string(),
@@ -242,33 +259,30 @@ pub fn build_required_gate_ref(
         });
     }
 
-    let has_payload = request.gate_payload.is_some();
-    let has_fallback = request.fallback.is_some();
-    if has_payload == has_fallback {
-        return Err(RequiredWitnessError {
-            failure_class: "required_gate_ref_invalid".to_string(),
-            message: "exactly one of gatePayload or fallback must be provided".to_string(),
-        });
-    }
-
-    if let Some(payload) = request.gate_payload.as_ref() {
-        let Some(payload_obj) = payload.as_object() else {
+    let (fallback, fallback_reason) = match (primary, request.fallback.as_ref()) {
+        (Ok(payload_obj), _) => {
+            let gate_witness_ref =
+                build_gate_ref(&check_id, &artifact_rel_path, source, payload_obj);
+            return Ok(RequiredGateRefResult {
+                gate_witness_ref,
+                gate_payload: None,
+                fallback_reason: None,
+            });
+        }
+        (Err(reason), Some(fallback)) => (fallback, reason),
+        (Err(RequiredGateRefFallbackReason::PrimaryRefMissing), None) => {
+            return Err(RequiredWitnessError {
+                failure_class: "required_gate_ref_invalid".to_string(),
+                message: "one of gatePayload or fallback must be provided".to_string(),
+            });
+        }
+        (Err(RequiredGateRefFallbackReason::PrimaryRefInvalid), None) => {
             return Err(RequiredWitnessError {
                 failure_class: "required_gate_ref_invalid".to_string(),
                 message: "gatePayload must be an object".to_string(),
             });
-        };
-        let gate_witness_ref = build_gate_ref(&check_id, &artifact_rel_path, source, payload_obj);
-        return Ok(RequiredGateRefResult {
-            gate_witness_ref,
-            gate_payload: None,
-        });
-    }
-
-    let fallback = request
-        .fallback
-        .as_ref()
-        .expect("fallback should be present when payload is absent");
+        }
+    };
     let _projection_digest =
         ensure_non_empty(&fallback.projection_digest, "fallback.projectionDigest")?;
     let _policy_digest = ensure_non_empty(&fallback.policy_digest, "fallback.policyDigest")?;
@@ -290,6 +304,7 @@ pub fn build_required_gate_ref(
     Ok(RequiredGateRefResult {
         gate_witness_ref,
         gate_payload: Some(gate_payload),
+        fallback_reason: Some(fallback_reason),
     })
 }
 
@@ -319,6 +334,13 @@ mod tests {
         assert_eq!(result.gate_witness_ref.result.as_deref(), Some("accepted"));
         assert!(result.gate_witness_ref.failure_classes.is_empty());
         assert!(result.gate_payload.is_none());
+        assert_eq!(result.fallback_reason, None);
+        assert!(
+            serde_json::to_value(&result)
+                .expect("result should serialize")
+                .get("fallbackReason")
+                .is_none()
+        );
     }
 
     #[test]
@@ -346,5 +368,43 @@ mod tests {
             vec!["descent_failure".to_string()]
         );
         assert!(result.gate_payload.is_some());
+        assert_eq!(
+            result.fallback_reason,
+            Some(RequiredGateRefFallbackReason::PrimaryRefMissing)
+        );
+    }
+
+    #[test]
+    fn build_required_gate_ref_reports_invalid_primary_fallback() {
+        let fallback = RequiredGateRefFallback {
+            exit_code: 0,
+            projection_digest: "proj1_demo".to_string(),
+            policy_digest: "ci-topos-v0".to_string(),
+            ctx_ref: "origin/main".to_string(),
+            data_head_ref: "HEAD".to_string(),
+        };
+        let mut request = RequiredGateRefRequest {
+            check_id: "baseline".to_string(),
+            artifact_rel_path: "gates/proj1_demo/01-baseline.json".to_string(),
+            source: None,
+            gate_payload: Some(json!("not-a-gate-witness")),
+            fallback: Some(fallback),
+        };
+
+        let result = build_required_gate_ref(&request).expect("fallback should cover bad payload");
+        assert_eq!(result.gate_witness_ref.source, "fallback");
+        assert_eq!(result.gate_witness_ref.result.as_deref(), Some("accepted"));
+        assert_eq!(
+            result.fallback_reason,
+            Some(RequiredGateRefFallbackReason::PrimaryRefInvalid)
+        );
+        assert_eq!(
+            serde_json::to_value(&result).expect("result should serialize")["fallbackReason"],
+            json!("primary_ref_invalid")
+        );
+
+        request.fallback = None;
+        let err = build_required_gate_ref(&request).expect_err("bad payload alone should fail");
+        assert_eq!(err.message, "gatePayload must be an object");
     }
 }
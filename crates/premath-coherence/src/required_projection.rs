@@ -119,15 +119,25 @@ fn normalize_path(path: &str) -> String {
     normalized
 }
 
+/// Shared normalization for every task/path set the coherence checks compare:
+/// trim, drop empties, dedupe, and sort. Case is preserved (task ids and
+/// repo paths are case-sensitive).
+pub(crate) fn normalize_task_set<I, S>(items: I) -> BTreeSet<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    items
+        .into_iter()
+        .map(|item| item.as_ref().trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
 pub fn normalize_paths(paths: &[String]) -> Vec<String> {
-    let mut out: BTreeSet<String> = BTreeSet::new();
-    for path in paths {
-        let normalized = normalize_path(path);
-        if !normalized.is_empty() {
-            out.insert(normalized);
-        }
-    }
-    out.into_iter().collect()
+    normalize_task_set(paths.iter().map(|path| normalize_path(path)))
+        .into_iter()
+        .collect()
 }
 
 fn is_doc_like_path(path: &str) -> bool {
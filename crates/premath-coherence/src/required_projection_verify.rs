@@ -0,0 +1,152 @@
+use crate::required_projection::{
+    RequiredProjectionRequest, RequiredProjectionResult, project_required_checks,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequiredProjectionVerifyResult {
+    pub errors: Vec<String>,
+    /// Checks the re-projection requires that the recorded plan lacks.
+    pub added_checks: Vec<String>,
+    /// Checks the recorded plan requires that the re-projection drops.
+    pub removed_checks: Vec<String>,
+    pub derived: RequiredProjectionResult,
+}
+
+fn compare_field<T: PartialEq + Debug>(
+    key: &str,
+    recorded: &T,
+    reprojected: &T,
+    errors: &mut Vec<String>,
+) {
+    if recorded != reprojected {
+        errors.push(format!(
+            "{key} mismatch (recorded={recorded:?}, reprojected={reprojected:?})"
+        ));
+    }
+}
+
+/// Re-run `project_required_checks` over `request` and diff the result
+/// against a previously emitted projection.
+pub fn verify_required_projection(
+    request: &RequiredProjectionRequest,
+    expected: &RequiredProjectionResult,
+) -> RequiredProjectionVerifyResult {
+    let derived = project_required_checks(&request.changed_paths);
+    let mut errors = Vec::new();
+    compare_field("schema", &expected.schema, &derived.schema, &mut errors);
+    compare_field(
+        "projectionPolicy",
+        &expected.projection_policy,
+        &derived.projection_policy,
+        &mut errors,
+    );
+    compare_field(
+        "projectionDigest",
+        &expected.projection_digest,
+        &derived.projection_digest,
+        &mut errors,
+    );
+    compare_field(
+        "changedPaths",
+        &expected.changed_paths,
+        &derived.changed_paths,
+        &mut errors,
+    );
+    compare_field(
+        "requiredChecks",
+        &expected.required_checks,
+        &derived.required_checks,
+        &mut errors,
+    );
+    compare_field(
+        "docsOnly",
+        &expected.docs_only,
+        &derived.docs_only,
+        &mut errors,
+    );
+    compare_field("reasons", &expected.reasons, &derived.reasons, &mut errors);
+
+    let recorded: BTreeSet<&String> = expected.required_checks.iter().collect();
+    let reprojected: BTreeSet<&String> = derived.required_checks.iter().collect();
+    let added_checks = reprojected
+        .difference(&recorded)
+        .map(|check| (*check).clone())
+        .collect();
+    let removed_checks = recorded
+        .difference(&reprojected)
+        .map(|check| (*check).clone())
+        .collect();
+
+    RequiredProjectionVerifyResult {
+        errors,
+        added_checks,
+        removed_checks,
+        derived,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(paths: &[&str]) -> RequiredProjectionRequest {
+        RequiredProjectionRequest {
+            changed_paths: paths.iter().map(|path| (*path).to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn verify_required_projection_accepts_matching_projection() {
+        let request = request(&["crates/premath-kernel/src/lib.rs"]);
+        let recorded = project_required_checks(&request.changed_paths);
+        let verified = verify_required_projection(&request, &recorded);
+        assert!(verified.errors.is_empty(), "{:?}", verified.errors);
+        assert!(verified.added_checks.is_empty());
+        assert!(verified.removed_checks.is_empty());
+        assert_eq!(verified.derived, recorded);
+    }
+
+    #[test]
+    fn verify_required_projection_reports_drifted_checks() {
+        let recorded = project_required_checks(&["crates/premath-kernel/src/lib.rs".to_string()]);
+        let drifted = request(&["crates/premath-coherence/src/lib.rs", "tests/conformance/x"]);
+        let verified = verify_required_projection(&drifted, &recorded);
+
+        assert!(
+            verified
+                .errors
+                .iter()
+                .any(|error| error.starts_with("requiredChecks mismatch"))
+        );
+        assert!(
+            verified
+                .errors
+                .iter()
+                .any(|error| error.starts_with("projectionDigest mismatch"))
+        );
+        assert_eq!(
+            verified.added_checks,
+            vec![
+                "conformance-check".to_string(),
+                "conformance-run".to_string()
+            ]
+        );
+        assert!(verified.removed_checks.is_empty());
+
+        let verified = verify_required_projection(&request(&["README.md"]), &recorded);
+        assert!(verified.added_checks.is_empty());
+        assert_eq!(
+            verified.removed_checks,
+            vec![
+                "build".to_string(),
+                "test".to_string(),
+                "test-kcir-toy".to_string(),
+                "test-toy".to_string(),
+            ]
+        );
+    }
+}
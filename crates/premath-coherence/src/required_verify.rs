@@ -1,3 +1,4 @@
+use crate::failure_class_set_diff;
 use crate::required::{RequiredWitnessError, compute_typed_core_projection_digest};
 use crate::required_projection::{
     PROJECTION_POLICY, normalize_paths as normalize_projection_paths, project_required_checks,
@@ -803,9 +804,11 @@ pub fn verify_required_witness_payload(
             .cloned()
             .collect::<Vec<String>>(),
     );
-    if failure_classes != expected_failure_classes {
+    let failure_class_diff = failure_class_set_diff(&expected_failure_classes, &failure_classes);
+    if !failure_class_diff.is_empty() {
         errors.push(format!(
-            "failureClasses mismatch (expected={expected_failure_classes:?}, actual={failure_classes:?})"
+            "failureClasses mismatch (missing={:?}, unexpected={:?})",
+            failure_class_diff.missing, failure_class_diff.unexpected
         ));
     }
 
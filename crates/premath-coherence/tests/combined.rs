@@ -0,0 +1,66 @@
+#![cfg(feature = "tusk")]
+
+use premath_coherence::run_combined_check;
+use premath_tusk::{DescentCore, DescentPack, GlueProposal, ModeBinding};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+fn repo_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .and_then(|path| path.parent())
+        .expect("repo root should resolve")
+        .to_path_buf()
+}
+
+fn pack_with_proposals(proposal_ids: &[&str]) -> DescentPack {
+    let mut locals = BTreeMap::new();
+    locals.insert("part:a".to_string(), json!({"value": 1}));
+    DescentPack {
+        core: DescentCore {
+            cover_id: "cover:combined".to_string(),
+            locals,
+            compat: Vec::new(),
+            mode: ModeBinding {
+                normalizer_id: "normalizer.v1".to_string(),
+                policy_digest: "policy.v1".to_string(),
+            },
+        },
+        glue_proposals: proposal_ids
+            .iter()
+            .map(|proposal_id| GlueProposal {
+                proposal_id: (*proposal_id).to_string(),
+                payload: json!({"selected": proposal_id}),
+            })
+            .collect(),
+        contractibility_basis: None,
+        core_fingerprint: None,
+    }
+}
+
+#[test]
+fn combined_check_rejects_when_descent_fails_after_coherence_passes() {
+    let root = repo_root();
+    let contract_path = root.join("specs/premath/draft/COHERENCE-CONTRACT.json");
+
+    let combined = run_combined_check(
+        &root,
+        &contract_path,
+        &pack_with_proposals(&["proposal:a", "proposal:b"]),
+    )
+    .expect("combined check should run");
+    assert_eq!(combined.coherence.result, "accepted");
+    let descent = combined.descent.as_ref().expect("descent should run");
+    assert!(descent.glue_result.is_none());
+    assert_eq!(combined.result, "rejected");
+    assert_eq!(
+        combined.failure_classes,
+        vec!["glue_non_contractible".to_string()]
+    );
+
+    let combined = run_combined_check(&root, &contract_path, &pack_with_proposals(&["proposal:a"]))
+        .expect("combined check should run");
+    assert_eq!(combined.result, "accepted");
+    assert!(combined.failure_classes.is_empty());
+}
@@ -323,6 +323,9 @@ pub mod failure_class {
     pub const DESCENT_FAILURE: &str = "descent_failure";
     pub const GLUE_NON_CONTRACTIBLE: &str = "glue_non_contractible";
     pub const ADJOINT_TRIPLE_COHERENCE_FAILURE: &str = "adjoint_triple_coherence_failure";
+    /// Input rejected before any law was checked (undecodable or unsupported
+    /// schema). Not a GATE.md §4 law class; paired with `law_ref::NON_LAW`.
+    pub const INPUT_FAILURE: &str = "input_failure";
 }
 
 /// Law reference constants.
@@ -332,6 +335,8 @@ pub mod law_ref {
     pub const DESCENT: &str = "GATE-3.3";
     pub const UNIQUENESS: &str = "GATE-3.4";
     pub const ADJOINT_TRIPLE: &str = "GATE-3.5";
+    /// Marks a failure that does not cite any Gate law.
+    pub const NON_LAW: &str = "NON-LAW";
 }
 
 #[cfg(test)]
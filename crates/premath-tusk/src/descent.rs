@@ -1,6 +1,11 @@
+use crate::eval::EvalOutcome;
+use crate::identity::{canonical_json_bytes, hex_lower};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::BTreeMap;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -28,6 +33,15 @@ pub struct DescentCore {
     pub mode: ModeBinding,
 }
 
+impl DescentCore {
+    /// Content-addressed fingerprint over the canonical JSON of the core.
+    pub fn fingerprint(&self) -> String {
+        let value = serde_json::to_value(self).expect("DescentCore must serialize");
+        let hash = Sha256::digest(canonical_json_bytes(&value));
+        format!("dcore1_{}", hex_lower(&hash))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct GlueProposal {
@@ -43,6 +57,65 @@ pub type GlueProposalSet = Vec<GlueProposal>;
 pub struct DescentPack {
     pub core: DescentCore,
     pub glue_proposals: GlueProposalSet,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contractibility_basis: Option<ContractibilityBasis>,
+    /// `core.fingerprint()` recorded when the pack was built.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub core_fingerprint: Option<String>,
+}
+
+impl DescentPack {
+    /// Content-addressed fingerprint over the canonical JSON of the pack.
+    ///
+    /// Computed over `normalized()` with the recorded core fingerprint
+    /// excluded, so the result depends only on pack content.
+    pub fn fingerprint(&self) -> String {
+        let mut value =
+            serde_json::to_value(self.clone().normalized()).expect("DescentPack must serialize");
+        if let Value::Object(map) = &mut value {
+            map.remove("coreFingerprint");
+        }
+        let hash = Sha256::digest(canonical_json_bytes(&value));
+        format!("dpack1_{}", hex_lower(&hash))
+    }
+
+    /// Put every order-insensitive list into canonical order.
+    ///
+    /// Glue proposals sort by id, compat witnesses by `(part_i, part_j,
+    /// overlap_id)`, and contractibility evidence refs lexically; payloads
+    /// break ties. A recorded core fingerprint that verified before sorting
+    /// is recomputed so it keeps verifying; a stale one stays stale.
+    pub fn normalized(mut self) -> DescentPack {
+        let core_fingerprint_verified = self.verify_core_fingerprint();
+        self.glue_proposals.sort_by_cached_key(|proposal| {
+            (
+                proposal.proposal_id.clone(),
+                canonical_json_bytes(&proposal.payload),
+            )
+        });
+        self.core.compat.sort_by_cached_key(|witness| {
+            (
+                witness.part_i.clone(),
+                witness.part_j.clone(),
+                witness.overlap_id.clone(),
+                canonical_json_bytes(&witness.payload),
+            )
+        });
+        if let Some(basis) = &mut self.contractibility_basis {
+            basis.evidence_refs.sort();
+        }
+        if core_fingerprint_verified {
+            self.core_fingerprint = Some(self.core.fingerprint());
+        }
+        self
+    }
+
+    /// Whether the core still matches the fingerprint recorded at build time.
+    ///
+    /// Packs without a recorded fingerprint cannot be verified.
+    pub fn verify_core_fingerprint(&self) -> bool {
+        self.core_fingerprint.as_deref() == Some(self.core.fingerprint().as_str())
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -70,10 +143,415 @@ pub struct GlueResult {
     pub normal_form_ref: Option<String>,
 }
 
+/// Structured audit record for a world-owned glue selection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GlueAuditReport {
+    pub run_id: String,
+    pub pack_digest: String,
+    pub selected_method: Option<String>,
+    /// Diagnostic kinds (snake_case) of a failed selection, in emission order.
+    pub selection_failures: Vec<String>,
+    pub compat_witnesses_used: usize,
+    /// SHA-256 over the canonical JSON of every other field.
+    pub report_digest: String,
+}
+
+impl GlueAuditReport {
+    /// Recompute the digest over the report material, excluding `report_digest`.
+    pub fn compute_digest(&self) -> String {
+        let material = json!({
+            "runId": self.run_id,
+            "packDigest": self.pack_digest,
+            "selectedMethod": self.selected_method,
+            "selectionFailures": self.selection_failures,
+            "compatWitnessesUsed": self.compat_witnesses_used,
+        });
+        let hash = Sha256::digest(canonical_json_bytes(&material));
+        format!("glueaudit1_{}", hex_lower(&hash))
+    }
+}
+
+impl GlueResult {
+    /// Project this selection into an audit report bound to a run and pack;
+    /// see `EvalOutcome::to_audit_report`.
+    pub fn to_audit_report(&self, run_id: &str, pack: &DescentPack) -> GlueAuditReport {
+        EvalOutcome {
+            diagnostics: Vec::new(),
+            glue_result: Some(self.clone()),
+        }
+        .to_audit_report(run_id, pack)
+    }
+}
+
+/// Assemble a digested audit report. `compat_witnesses_used` counts the
+/// distinct compatibility witnesses whose parts are both local states of the
+/// cover, i.e. the overlaps the glue was checked on.
+pub(crate) fn glue_audit_report(
+    run_id: &str,
+    pack: &DescentPack,
+    selected_method: Option<String>,
+    selection_failures: Vec<String>,
+) -> GlueAuditReport {
+    let compat_witnesses_used = pack
+        .core
+        .compat
+        .iter()
+        .filter(|witness| {
+            pack.core.locals.contains_key(&witness.part_i)
+                && pack.core.locals.contains_key(&witness.part_j)
+        })
+        .map(|witness| {
+            (
+                witness.part_i.as_str(),
+                witness.part_j.as_str(),
+                witness.overlap_id.as_str(),
+                canonical_json_bytes(&witness.payload),
+            )
+        })
+        .collect::<BTreeSet<_>>()
+        .len();
+    let mut report = GlueAuditReport {
+        run_id: run_id.to_string(),
+        pack_digest: pack.fingerprint(),
+        selected_method,
+        selection_failures,
+        compat_witnesses_used,
+        report_digest: String::new(),
+    };
+    report.report_digest = report.compute_digest();
+    report
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum GlueSelectionFailure {
     NoValidProposal,
     NonContractibleSelection,
     ModeComparisonUnavailable,
+    /// The proposal set exceeds the evaluation budget and was not enumerated.
+    TooManyProposals {
+        count: usize,
+        limit: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DescentPackBuildError {
+    MissingContractibilityBasis,
+    EmptyGlueProposals,
+}
+
+impl Display for DescentPackBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingContractibilityBasis => {
+                write!(f, "descent pack requires a contractibility basis")
+            }
+            Self::EmptyGlueProposals => {
+                write!(f, "descent pack requires at least one glue proposal")
+            }
+        }
+    }
+}
+
+impl Error for DescentPackBuildError {}
+
+/// Validated construction path from a `DescentCore` to a `DescentPack`.
+#[derive(Debug, Clone)]
+pub struct DescentPackBuilder {
+    core: DescentCore,
+    glue_proposals: GlueProposalSet,
+    contractibility_basis: Option<ContractibilityBasis>,
+}
+
+impl DescentPackBuilder {
+    pub fn new(core: DescentCore) -> Self {
+        Self {
+            core,
+            glue_proposals: Vec::new(),
+            contractibility_basis: None,
+        }
+    }
+
+    pub fn add_glue_proposal(mut self, proposal: GlueProposal) -> Self {
+        self.glue_proposals.push(proposal);
+        self
+    }
+
+    pub fn add_compat_witness(mut self, witness: CompatWitness) -> Self {
+        self.core.compat.push(witness);
+        self
+    }
+
+    pub fn set_contractibility_basis(mut self, basis: ContractibilityBasis) -> Self {
+        self.contractibility_basis = Some(basis);
+        self
+    }
+
+    #[tracing::instrument(
+        name = "descent_pack_build",
+        level = "debug",
+        skip(self),
+        fields(cover_id = %self.core.cover_id, proposals = self.glue_proposals.len())
+    )]
+    pub fn build(self) -> Result<DescentPack, DescentPackBuildError> {
+        if self.glue_proposals.is_empty() {
+            return Err(DescentPackBuildError::EmptyGlueProposals);
+        }
+        let Some(contractibility_basis) = self.contractibility_basis else {
+            return Err(DescentPackBuildError::MissingContractibilityBasis);
+        };
+        let core_fingerprint = self.core.fingerprint();
+        Ok(DescentPack {
+            core: self.core,
+            glue_proposals: self.glue_proposals,
+            contractibility_basis: Some(contractibility_basis),
+            core_fingerprint: Some(core_fingerprint),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mode() -> ModeBinding {
+        ModeBinding {
+            normalizer_id: "normalizer.v1".to_string(),
+            policy_digest: "policy.v1".to_string(),
+        }
+    }
+
+    fn core() -> DescentCore {
+        let mut locals = BTreeMap::new();
+        locals.insert("part:a".to_string(), json!({"value": 1}));
+        locals.insert("part:b".to_string(), json!({"value": 1}));
+        DescentCore {
+            cover_id: "cover:demo".to_string(),
+            locals,
+            compat: vec![],
+            mode: mode(),
+        }
+    }
+
+    fn basis() -> ContractibilityBasis {
+        ContractibilityBasis {
+            mode: mode(),
+            method: GlueMethod::EquivWitness,
+            evidence_refs: vec!["evidence:1".to_string()],
+        }
+    }
+
+    fn proposal() -> GlueProposal {
+        GlueProposal {
+            proposal_id: "proposal:1".to_string(),
+            payload: json!({"selected": true}),
+        }
+    }
+
+    fn glue_result(method: GlueMethod) -> GlueResult {
+        GlueResult {
+            selected: "proposal:1".to_string(),
+            contractibility_basis: ContractibilityBasis { method, ..basis() },
+            normal_form_ref: None,
+        }
+    }
+
+    fn compat(part_j: &str, overlap_id: &str) -> CompatWitness {
+        CompatWitness {
+            part_i: "part:a".to_string(),
+            part_j: part_j.to_string(),
+            overlap_id: overlap_id.to_string(),
+            payload: json!({}),
+        }
+    }
+
+    fn pack() -> DescentPack {
+        DescentPackBuilder::new(core())
+            .add_compat_witness(compat("part:b", "overlap:ab"))
+            .add_glue_proposal(proposal())
+            .set_contractibility_basis(basis())
+            .build()
+            .expect("pack should build")
+    }
+
+    #[test]
+    fn audit_report_binds_run_and_selection() {
+        let pack = pack();
+        let report = glue_result(GlueMethod::EquivWitness).to_audit_report("run1_abc", &pack);
+        assert_eq!(report.run_id, "run1_abc");
+        assert_eq!(report.pack_digest, pack.fingerprint());
+        assert_eq!(report.selected_method.as_deref(), Some("equiv_witness"));
+        assert!(report.selection_failures.is_empty());
+        assert_eq!(report.compat_witnesses_used, 1);
+        assert!(report.report_digest.starts_with("glueaudit1_"));
+        assert_eq!(report.report_digest, report.compute_digest());
+
+        let roundtrip: GlueAuditReport =
+            serde_json::from_value(serde_json::to_value(&report).expect("report should serialize"))
+                .expect("report should deserialize");
+        assert_eq!(roundtrip, report);
+    }
+
+    #[test]
+    fn normalized_pack_is_order_insensitive() {
+        let witness = |overlap_id: &str| CompatWitness {
+            part_i: "part:a".to_string(),
+            part_j: "part:b".to_string(),
+            overlap_id: overlap_id.to_string(),
+            payload: json!({}),
+        };
+        let build = |overlaps: [&str; 2], proposals: [&str; 2], evidence: [&str; 2]| {
+            DescentPackBuilder::new(core())
+                .add_compat_witness(witness(overlaps[0]))
+                .add_compat_witness(witness(overlaps[1]))
+                .add_glue_proposal(GlueProposal {
+                    proposal_id: proposals[0].to_string(),
+                    ..proposal()
+                })
+                .add_glue_proposal(GlueProposal {
+                    proposal_id: proposals[1].to_string(),
+                    ..proposal()
+                })
+                .set_contractibility_basis(ContractibilityBasis {
+                    evidence_refs: evidence.iter().map(|e| e.to_string()).collect(),
+                    ..basis()
+                })
+                .build()
+                .expect("pack should build")
+        };
+
+        let canonical = build(["o:1", "o:2"], ["p:1", "p:2"], ["e:1", "e:2"]);
+        let shuffled = build(["o:2", "o:1"], ["p:2", "p:1"], ["e:2", "e:1"]);
+        assert_ne!(canonical, shuffled);
+        assert_eq!(canonical.fingerprint(), shuffled.fingerprint());
+
+        let normalized = shuffled.normalized();
+        assert_eq!(normalized, canonical.clone().normalized());
+        assert_eq!(normalized, canonical);
+        assert!(normalized.verify_core_fingerprint());
+
+        let mut stale = canonical;
+        stale.core_fingerprint = Some("dcore1_stale".to_string());
+        assert!(!stale.normalized().verify_core_fingerprint());
+    }
+
+    #[test]
+    fn audit_report_digest_changes_with_selected_method() {
+        let pack = pack();
+        let equiv = glue_result(GlueMethod::EquivWitness).to_audit_report("run1_abc", &pack);
+        let normal = glue_result(GlueMethod::NormalForm).to_audit_report("run1_abc", &pack);
+        assert_ne!(equiv.report_digest, normal.report_digest);
+
+        let mut cleared = equiv.clone();
+        cleared.selected_method = None;
+        assert_ne!(cleared.compute_digest(), equiv.report_digest);
+    }
+
+    #[test]
+    fn audit_report_counts_distinct_applied_compat_witnesses() {
+        let mut pack = pack();
+        // Duplicate witness, a second overlap, and one naming no local state.
+        pack.core.compat.push(compat("part:b", "overlap:ab"));
+        pack.core.compat.push(compat("part:b", "overlap:ab2"));
+        pack.core.compat.push(compat("part:z", "overlap:az"));
+        let report = glue_result(GlueMethod::EquivWitness).to_audit_report("run1_abc", &pack);
+        assert_eq!(report.compat_witnesses_used, 2);
+
+        // Evidence refs do not contribute to the count.
+        let mut result = glue_result(GlueMethod::EquivWitness);
+        result.contractibility_basis.evidence_refs = vec![
+            "evidence:1".to_string(),
+            "evidence:2".to_string(),
+            "evidence:3".to_string(),
+        ];
+        assert_eq!(
+            result
+                .to_audit_report("run1_abc", &pack)
+                .compat_witnesses_used,
+            2
+        );
+    }
+
+    #[test]
+    fn builder_assembles_validated_pack() {
+        let pack = DescentPackBuilder::new(core())
+            .add_compat_witness(CompatWitness {
+                part_i: "part:a".to_string(),
+                part_j: "part:b".to_string(),
+                overlap_id: "overlap:ab".to_string(),
+                payload: json!({}),
+            })
+            .add_glue_proposal(proposal())
+            .set_contractibility_basis(basis())
+            .build()
+            .expect("pack should build");
+
+        assert_eq!(pack.core.compat.len(), 1);
+        assert_eq!(pack.glue_proposals, vec![proposal()]);
+        assert_eq!(pack.contractibility_basis, Some(basis()));
+    }
+
+    #[test]
+    fn pack_fingerprint_tracks_content_and_proposal_order_is_irrelevant() {
+        let second = GlueProposal {
+            proposal_id: "proposal:2".to_string(),
+            payload: json!({"selected": false}),
+        };
+        let pack = DescentPackBuilder::new(core())
+            .add_glue_proposal(proposal())
+            .add_glue_proposal(second.clone())
+            .set_contractibility_basis(basis())
+            .build()
+            .expect("pack should build");
+        let fingerprint = pack.fingerprint();
+        assert!(fingerprint.starts_with("dpack1_"));
+
+        let mut reordered = pack.clone();
+        reordered.glue_proposals = vec![second, proposal()];
+        assert_eq!(reordered.fingerprint(), fingerprint);
+
+        let mut mutated = pack.clone();
+        mutated.core.cover_id = "cover:other".to_string();
+        assert_ne!(mutated.fingerprint(), fingerprint);
+    }
+
+    #[test]
+    fn core_fingerprint_detects_in_place_core_mutation() {
+        let mut pack = DescentPackBuilder::new(core())
+            .add_glue_proposal(proposal())
+            .set_contractibility_basis(basis())
+            .build()
+            .expect("pack should build");
+        assert!(pack.core.fingerprint().starts_with("dcore1_"));
+        assert!(pack.verify_core_fingerprint());
+
+        pack.core
+            .locals
+            .insert("part:c".to_string(), json!({"value": 2}));
+        assert!(!pack.verify_core_fingerprint());
+
+        pack.core_fingerprint = None;
+        assert!(!pack.verify_core_fingerprint());
+    }
+
+    #[test]
+    fn builder_rejects_empty_glue_proposals() {
+        let err = DescentPackBuilder::new(core())
+            .set_contractibility_basis(basis())
+            .build()
+            .expect_err("empty proposals should fail");
+        assert_eq!(err, DescentPackBuildError::EmptyGlueProposals);
+    }
+
+    #[test]
+    fn builder_rejects_missing_contractibility_basis() {
+        let err = DescentPackBuilder::new(core())
+            .add_glue_proposal(proposal())
+            .build()
+            .expect_err("missing basis should fail");
+        assert_eq!(err, DescentPackBuildError::MissingContractibilityBasis);
+    }
 }
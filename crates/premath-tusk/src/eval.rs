@@ -1,8 +1,43 @@
-use crate::descent::{ContractibilityBasis, DescentPack, GlueMethod, GlueResult};
-use crate::mapping::{TuskDiagnosticFailure, TuskFailureKind};
+//! Deterministic v0 evaluation of descent packs.
+//!
+//! Tracing (all at `DEBUG`):
+//! - span `evaluate_descent_pack` with `cover_id`, `locals`, `proposals`;
+//!   events inside it report `diagnostics` after the preflight checks and the
+//!   glue selection outcome (`selected`, or `candidates` when ambiguous).
+//! - span `descent_pack_build` (from `DescentPackBuilder::build`) with
+//!   `cover_id` and `proposals`.
+
+use crate::descent::{
+    ContractibilityBasis, DescentPack, GlueAuditReport, GlueMethod, GlueResult,
+    GlueSelectionFailure, glue_audit_report,
+};
+use crate::mapping::{
+    InMemoryEventCollector, TuskDiagnosticFailure, TuskFailureKind, glue_selection_diagnostic,
+    map_glue_selection_failure_to_emitter,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+/// Proposal budget applied by `evaluate_descent_pack`.
+pub const DEFAULT_MAX_GLUE_PROPOSALS: usize = 4096;
+
+/// Evaluation budget controls.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EvalOptions {
+    /// Packs with more glue proposals than this are rejected with
+    /// `GlueSelectionFailure::TooManyProposals` before any proposal is read.
+    pub max_proposals: usize,
+}
+
+impl Default for EvalOptions {
+    fn default() -> Self {
+        Self {
+            max_proposals: DEFAULT_MAX_GLUE_PROPOSALS,
+        }
+    }
+}
+
 /// Deterministic v0 evaluation output for a `DescentPack`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -12,29 +47,81 @@ pub struct EvalOutcome {
     pub glue_result: Option<GlueResult>,
 }
 
+impl EvalOutcome {
+    /// Project this outcome into an audit report bound to a run and pack.
+    ///
+    /// `selection_failures` lists the diagnostic kinds in emission order, and
+    /// `selected_method` is `None` unless a glue result was selected.
+    pub fn to_audit_report(&self, run_id: &str, pack: &DescentPack) -> GlueAuditReport {
+        let selected_method = self
+            .glue_result
+            .as_ref()
+            .filter(|_| self.diagnostics.is_empty())
+            .and_then(|result| serde_json::to_value(result.contractibility_basis.method).ok())
+            .and_then(|value| value.as_str().map(str::to_string));
+        let selection_failures = self
+            .diagnostics
+            .iter()
+            .filter_map(|diagnostic| serde_json::to_value(diagnostic.kind).ok())
+            .filter_map(|value| value.as_str().map(str::to_string))
+            .collect();
+        glue_audit_report(run_id, pack, selected_method, selection_failures)
+    }
+}
+
+/// Evaluate a `DescentPack` with the default `EvalOptions`.
+pub fn evaluate_descent_pack(pack: &DescentPack) -> EvalOutcome {
+    evaluate_descent_pack_with_options(pack, EvalOptions::default())
+}
+
 /// Evaluate a `DescentPack` with a deterministic v0 policy.
 ///
+/// The pack is evaluated in `DescentPack::normalized` form, so input order
+/// of proposals and compat witnesses does not affect the outcome.
+///
 /// This is intentionally minimal and conservative:
 /// - enforces non-empty locals
 /// - enforces overlap evidence presence for multi-local packs
 /// - enforces single-proposal contractibility
 /// - returns a world-owned `GlueResult` only when checks pass
-pub fn evaluate_descent_pack(pack: &DescentPack) -> EvalOutcome {
+#[tracing::instrument(
+    name = "evaluate_descent_pack",
+    level = "debug",
+    skip(pack, options),
+    fields(
+        cover_id = %pack.core.cover_id,
+        locals = pack.core.locals.len(),
+        proposals = pack.glue_proposals.len(),
+    )
+)]
+pub fn evaluate_descent_pack_with_options(pack: &DescentPack, options: EvalOptions) -> EvalOutcome {
+    if pack.glue_proposals.len() > options.max_proposals {
+        tracing::debug!(
+            limit = options.max_proposals,
+            "glue selection failed: proposal budget exceeded"
+        );
+        let mut collector = InMemoryEventCollector::default();
+        map_glue_selection_failure_to_emitter(
+            GlueSelectionFailure::TooManyProposals {
+                count: pack.glue_proposals.len(),
+                limit: options.max_proposals,
+            },
+            &mut collector,
+        );
+        return EvalOutcome {
+            diagnostics: collector.events,
+            glue_result: None,
+        };
+    }
+    let pack = &pack.clone().normalized();
     let mut diagnostics = Vec::new();
 
     if pack.core.mode.normalizer_id.trim().is_empty()
         || pack.core.mode.policy_digest.trim().is_empty()
     {
-        diagnostics.push(TuskDiagnosticFailure {
-            kind: TuskFailureKind::ModeComparisonUnavailable,
-            message: "mode binding missing normalizer_id or policy_digest".to_string(),
-            token_path: Some("descent.core.mode".to_string()),
-            context: None,
-            details: Some(json!({
-                "phase": "normalize",
-                "responsibleComponent": "world",
-            })),
-        });
+        diagnostics.push(glue_selection_diagnostic(
+            GlueSelectionFailure::ModeComparisonUnavailable,
+        ));
     }
 
     if pack.core.locals.is_empty() {
@@ -65,47 +152,50 @@ pub fn evaluate_descent_pack(pack: &DescentPack) -> EvalOutcome {
         });
     }
 
+    tracing::debug!(diagnostics = diagnostics.len(), "descent preflight checked");
     let glue_result = if diagnostics.is_empty() {
         match pack.glue_proposals.as_slice() {
             [] => {
-                diagnostics.push(TuskDiagnosticFailure {
-                    kind: TuskFailureKind::NoValidGlueProposal,
-                    message: "no glue proposals provided".to_string(),
-                    token_path: Some("descent.glueProposals".to_string()),
-                    context: None,
-                    details: Some(json!({
-                        "phase": "select_glue",
-                        "responsibleComponent": "world",
-                    })),
-                });
+                tracing::debug!("glue selection failed: no proposals");
+                diagnostics.push(glue_selection_diagnostic(
+                    GlueSelectionFailure::NoValidProposal,
+                ));
                 None
             }
-            [only] => Some(GlueResult {
-                selected: only.proposal_id.clone(),
-                contractibility_basis: ContractibilityBasis {
-                    mode: pack.core.mode.clone(),
-                    method: GlueMethod::EquivWitness,
-                    evidence_refs: Vec::new(),
-                },
-                normal_form_ref: None,
-            }),
-            _ => {
+            [only] => {
+                tracing::debug!(selected = %only.proposal_id, "glue proposal selected");
+                Some(GlueResult {
+                    selected: only.proposal_id.clone(),
+                    contractibility_basis: ContractibilityBasis {
+                        mode: pack.core.mode.clone(),
+                        method: GlueMethod::EquivWitness,
+                        evidence_refs: Vec::new(),
+                    },
+                    normal_form_ref: None,
+                })
+            }
+            proposals => {
+                // Already in id order via `normalized()`.
+                let candidate_proposal_ids: Vec<&str> = proposals
+                    .iter()
+                    .map(|proposal| proposal.proposal_id.as_str())
+                    .collect();
+                tracing::debug!(
+                    candidates = ?candidate_proposal_ids,
+                    "glue selection failed: multiple proposals remain"
+                );
                 diagnostics.push(TuskDiagnosticFailure {
-                    kind: TuskFailureKind::NonContractibleSelection,
-                    message: "multiple glue proposals remain under v0 mode".to_string(),
-                    token_path: Some("descent.glueProposals".to_string()),
                     context: Some(json!({
                         "proposalCount": pack.glue_proposals.len(),
+                        "candidateProposalIds": candidate_proposal_ids,
                     })),
-                    details: Some(json!({
-                        "phase": "select_glue",
-                        "responsibleComponent": "world",
-                    })),
+                    ..glue_selection_diagnostic(GlueSelectionFailure::NonContractibleSelection)
                 });
                 None
             }
         }
     } else {
+        tracing::debug!("glue selection skipped after preflight diagnostics");
         None
     };
 
@@ -120,6 +210,7 @@ mod tests {
     use super::*;
     use crate::descent::{DescentCore, GlueProposal, ModeBinding};
     use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
 
     fn base_pack() -> DescentPack {
         let mut locals = BTreeMap::new();
@@ -139,6 +230,8 @@ mod tests {
                 proposal_id: "proposal:1".to_string(),
                 payload: json!({"selected": true}),
             }],
+            contractibility_basis: None,
+            core_fingerprint: None,
         }
     }
 
@@ -171,6 +264,174 @@ mod tests {
         );
     }
 
+    #[test]
+    fn audit_report_records_failed_selection() {
+        let mut pack = base_pack();
+        pack.glue_proposals.push(GlueProposal {
+            proposal_id: "proposal:2".to_string(),
+            payload: json!({}),
+        });
+        let outcome = evaluate_descent_pack(&pack);
+        let report = outcome.to_audit_report("run1_abc", &pack);
+        assert_eq!(report.selected_method, None);
+        assert_eq!(
+            report.selection_failures,
+            vec!["non_contractible_selection"]
+        );
+        assert_eq!(report.report_digest, report.compute_digest());
+
+        let selected =
+            evaluate_descent_pack(&base_pack()).to_audit_report("run1_abc", &base_pack());
+        assert_eq!(selected.selected_method.as_deref(), Some("equiv_witness"));
+        assert!(selected.selection_failures.is_empty());
+        assert_ne!(selected.report_digest, report.report_digest);
+    }
+
+    #[test]
+    fn emitted_glue_failures_match_evaluator_diagnostics() {
+        let mut no_proposals = base_pack();
+        no_proposals.glue_proposals.clear();
+        let mut unbound_mode = base_pack();
+        unbound_mode.core.mode.policy_digest = String::new();
+        let mut ambiguous = base_pack();
+        ambiguous.glue_proposals.push(GlueProposal {
+            proposal_id: "proposal:2".to_string(),
+            payload: json!({}),
+        });
+
+        for (pack, failure, message) in [
+            (
+                no_proposals,
+                GlueSelectionFailure::NoValidProposal,
+                "no glue proposals provided",
+            ),
+            (
+                unbound_mode,
+                GlueSelectionFailure::ModeComparisonUnavailable,
+                "mode binding missing normalizer_id or policy_digest",
+            ),
+            (
+                ambiguous,
+                GlueSelectionFailure::NonContractibleSelection,
+                "multiple glue proposals remain under v0 mode",
+            ),
+        ] {
+            let mut collector = InMemoryEventCollector::default();
+            map_glue_selection_failure_to_emitter(failure, &mut collector);
+            let [emitted] = collector.events.as_slice() else {
+                panic!("expected one emitted event");
+            };
+            let outcome = evaluate_descent_pack(&pack);
+            let [evaluated] = outcome.diagnostics.as_slice() else {
+                panic!("expected one diagnostic, got {:?}", outcome.diagnostics);
+            };
+            assert_eq!(emitted.message, message);
+            assert_eq!(evaluated.message, message);
+            assert_eq!(emitted.kind, evaluated.kind);
+            assert_eq!(emitted.token_path, evaluated.token_path);
+            assert_eq!(emitted.details, evaluated.details);
+        }
+    }
+
+    #[test]
+    fn evaluation_emits_debug_spans() {
+        #[derive(Clone, Default)]
+        struct Captured(Arc<Mutex<Vec<u8>>>);
+        impl std::io::Write for Captured {
+            fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+                self.0
+                    .lock()
+                    .expect("capture lock")
+                    .extend_from_slice(bytes);
+                Ok(bytes.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            let outcome = evaluate_descent_pack(&base_pack());
+            assert!(outcome.glue_result.is_some());
+        });
+
+        let logs = String::from_utf8(captured.0.lock().expect("capture lock").clone())
+            .expect("logs should be utf-8");
+        assert!(logs.contains("evaluate_descent_pack{"), "{logs}");
+        assert!(logs.contains("cover_id=cover:demo"), "{logs}");
+        assert!(logs.contains("glue proposal selected"), "{logs}");
+    }
+
+    #[test]
+    fn shuffled_pack_evaluates_like_canonical_pack() {
+        let mut canonical = base_pack();
+        canonical
+            .core
+            .locals
+            .insert("part:b".to_string(), json!({"value": 2}));
+        for overlap_id in ["overlap:1", "overlap:2"] {
+            canonical.core.compat.push(crate::descent::CompatWitness {
+                part_i: "part:a".to_string(),
+                part_j: "part:b".to_string(),
+                overlap_id: overlap_id.to_string(),
+                payload: json!({}),
+            });
+        }
+        canonical.glue_proposals.push(GlueProposal {
+            proposal_id: "proposal:2".to_string(),
+            payload: json!({"selected": false}),
+        });
+
+        let mut shuffled = canonical.clone();
+        shuffled.core.compat.reverse();
+        shuffled.glue_proposals.reverse();
+
+        let outcome = evaluate_descent_pack(&canonical);
+        assert!(!outcome.diagnostics.is_empty());
+        assert_eq!(outcome, evaluate_descent_pack(&shuffled));
+        assert_eq!(
+            serde_json::to_vec(&outcome).expect("outcome should serialize"),
+            serde_json::to_vec(&evaluate_descent_pack(&shuffled))
+                .expect("outcome should serialize")
+        );
+        assert_eq!(canonical.fingerprint(), shuffled.fingerprint());
+    }
+
+    #[test]
+    fn rejects_proposal_sets_over_budget() {
+        let mut pack = base_pack();
+        pack.glue_proposals.push(GlueProposal {
+            proposal_id: "proposal:2".to_string(),
+            payload: json!({"selected": false}),
+        });
+
+        let outcome = evaluate_descent_pack_with_options(&pack, EvalOptions { max_proposals: 1 });
+        assert!(outcome.glue_result.is_none());
+        assert_eq!(outcome.diagnostics.len(), 1);
+        assert_eq!(
+            outcome.diagnostics[0].kind,
+            TuskFailureKind::TooManyGlueProposals
+        );
+        assert_eq!(
+            outcome.diagnostics[0].context,
+            Some(json!({"proposalCount": 2, "maxProposals": 1}))
+        );
+
+        let within = evaluate_descent_pack_with_options(&pack, EvalOptions { max_proposals: 2 });
+        assert_eq!(within, evaluate_descent_pack(&pack));
+        assert_eq!(
+            within.diagnostics[0].kind,
+            TuskFailureKind::NonContractibleSelection
+        );
+    }
+
     #[test]
     fn rejects_multiple_proposals_as_non_contractible() {
         let mut pack = base_pack();
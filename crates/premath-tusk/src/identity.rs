@@ -11,6 +11,9 @@ pub struct IntentSpec {
     pub requested_outcomes: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub constraints: Option<Value>,
+    /// Set for sub-run intents so a child never shares its parent's `intent_id`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_run_id: Option<String>,
 }
 
 impl IntentSpec {
@@ -21,6 +24,13 @@ impl IntentSpec {
         out.requested_outcomes.dedup();
         out
     }
+
+    /// Sub-run intent of the run identified by `parent_run_id`;
+    /// [`RunIdentity::with_parent`] derives the child's `intent_id` from it.
+    pub fn with_parent(mut self, parent_run_id: &str) -> IntentSpec {
+        self.parent_run_id = Some(parent_run_id.to_string());
+        self
+    }
 }
 
 /// Deterministic run identity material.
@@ -43,6 +53,13 @@ pub struct RunIdentity {
     /// Audit material by default. Optional identity material under hardening.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cover_strategy_digest: Option<String>,
+    /// Run IDs of enclosing runs, nearest parent first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ancestor_run_ids: Vec<String>,
+    /// `RunIdOptions::digest` of the last `compute_run_id` call. Audit only;
+    /// never part of the run ID material.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_digest: Option<String>,
 }
 
 /// Run ID hardening controls.
@@ -52,20 +69,80 @@ pub struct RunIdOptions {
     pub include_cover_strategy_digest: bool,
 }
 
+impl RunIdOptions {
+    /// Content-addressed digest over the canonical JSON of the options.
+    pub fn digest(&self) -> String {
+        let value = serde_json::to_value(self).expect("RunIdOptions must serialize");
+        let hash = Sha256::digest(canonical_json_bytes(&value));
+        format!("runopts1_{}", hex_lower(&hash))
+    }
+}
+
+/// Inputs a run ID was derived from, recorded for audit.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunProvenance {
+    pub intent_id: String,
+    /// `None` until `compute_run_id` has run for this identity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_digest: Option<String>,
+    /// `premath-tusk` version that computed the run ID.
+    pub checker_version: String,
+}
+
 impl RunIdentity {
-    /// Deterministic run identifier derived from canonical identity material.
-    pub fn compute_run_id(&self, options: RunIdOptions) -> String {
+    /// Provenance of the last `compute_run_id` call on this identity.
+    pub fn provenance(&self) -> RunProvenance {
+        RunProvenance {
+            intent_id: self.intent_id.clone(),
+            options_digest: self.options_digest.clone(),
+            checker_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+
+    /// Deterministic run identifier derived from canonical identity material,
+    /// recording `options` for `provenance`.
+    pub fn compute_run_id(&mut self, options: RunIdOptions) -> String {
+        self.options_digest = Some(options.digest());
+        self.run_id(options)
+    }
+
+    /// `compute_run_id` without recording the options.
+    pub(crate) fn run_id(&self, options: RunIdOptions) -> String {
         let mut value = serde_json::to_value(self).expect("RunIdentity must serialize");
-        if !options.include_cover_strategy_digest
-            && let Value::Object(map) = &mut value
-        {
-            map.remove("coverStrategyDigest");
+        if let Value::Object(map) = &mut value {
+            map.remove("optionsDigest");
+            if !options.include_cover_strategy_digest {
+                map.remove("coverStrategyDigest");
+            }
         }
 
         let bytes = canonical_json_bytes(&value);
         let hash = Sha256::digest(bytes);
         format!("run1_{}", hex_lower(&hash))
     }
+
+    /// Child of the run identified by `parent_id`, taking `self` as that
+    /// parent's identity material (its ancestry becomes the child's) and
+    /// `parent_intent` as the parent's intent.
+    ///
+    /// The child's `intent_id` is derived from `parent_intent` bound to
+    /// `parent_id` via [`IntentSpec::with_parent`], so it never matches the
+    /// parent's.
+    pub fn with_parent(mut self, parent_id: &str, parent_intent: &IntentSpec) -> RunIdentity {
+        self.intent_id = compute_intent_id(&parent_intent.clone().with_parent(parent_id));
+        self.ancestor_run_ids.insert(0, parent_id.to_string());
+        self.options_digest = None;
+        self
+    }
+
+    pub fn parent_id(&self) -> Option<&str> {
+        self.ancestor_run_ids.first().map(String::as_str)
+    }
+
+    pub fn is_descendant_of(&self, ancestor_id: &str) -> bool {
+        self.ancestor_run_ids.iter().any(|id| id == ancestor_id)
+    }
 }
 
 /// Deterministic `intent_id` from canonical `IntentSpec`.
@@ -77,7 +154,7 @@ pub fn compute_intent_id(spec: &IntentSpec) -> String {
     format!("intent1_{}", hex_lower(&hash))
 }
 
-fn canonical_json_bytes(value: &Value) -> Vec<u8> {
+pub(crate) fn canonical_json_bytes(value: &Value) -> Vec<u8> {
     match value {
         Value::Null => b"null".to_vec(),
         Value::Bool(true) => b"true".to_vec(),
@@ -122,7 +199,7 @@ fn canonical_json_bytes(value: &Value) -> Vec<u8> {
     }
 }
 
-fn hex_lower(bytes: &[u8]) -> String {
+pub(crate) fn hex_lower(bytes: &[u8]) -> String {
     const HEX: &[u8; 16] = b"0123456789abcdef";
     let mut out = String::with_capacity(bytes.len() * 2);
     for byte in bytes {
@@ -136,6 +213,16 @@ fn hex_lower(bytes: &[u8]) -> String {
 mod tests {
     use super::*;
 
+    fn fixture_intent() -> IntentSpec {
+        IntentSpec {
+            intent_kind: "plan".into(),
+            target_scope: "repo".into(),
+            requested_outcomes: vec!["summary".into()],
+            constraints: None,
+            parent_run_id: None,
+        }
+    }
+
     fn fixture_identity() -> RunIdentity {
         RunIdentity {
             world_id: "world.dev".into(),
@@ -151,6 +238,8 @@ mod tests {
             normalizer_id: "norm.v1".into(),
             policy_digest: "policy.deadbeef".into(),
             cover_strategy_digest: Some("strategy.v1".into()),
+            ancestor_run_ids: Vec::new(),
+            options_digest: None,
         }
     }
 
@@ -161,6 +250,7 @@ mod tests {
             target_scope: "repo".into(),
             requested_outcomes: vec!["obligations".into(), "summary".into()],
             constraints: Some(serde_json::json!({"maxDepth": 3})),
+            parent_run_id: None,
         };
 
         let b = IntentSpec {
@@ -171,9 +261,113 @@ mod tests {
         assert_eq!(compute_intent_id(&a), compute_intent_id(&b));
     }
 
+    #[test]
+    fn provenance_records_intent_and_options() {
+        let intent_id = compute_intent_id(&IntentSpec {
+            intent_kind: "plan".into(),
+            target_scope: "repo".into(),
+            requested_outcomes: vec!["summary".into()],
+            constraints: None,
+            parent_run_id: None,
+        });
+        let identity = RunIdentity {
+            intent_id: intent_id.clone(),
+            ..fixture_identity()
+        };
+
+        let mut identity = identity;
+        assert_eq!(identity.provenance().options_digest, None);
+        let run_id = identity.compute_run_id(RunIdOptions::default());
+        let provenance = identity.provenance();
+        assert_eq!(provenance.intent_id, intent_id);
+        assert_eq!(
+            provenance.options_digest,
+            Some(RunIdOptions::default().digest())
+        );
+        assert_eq!(provenance.checker_version, env!("CARGO_PKG_VERSION"));
+
+        // The recorded digest is excluded from the run ID material.
+        assert_eq!(identity.compute_run_id(RunIdOptions::default()), run_id);
+
+        let hardened = RunIdOptions {
+            include_cover_strategy_digest: true,
+        };
+        let mut hardened_identity = fixture_identity();
+        hardened_identity.intent_id = intent_id.clone();
+        hardened_identity.compute_run_id(hardened);
+        assert_eq!(
+            hardened_identity.provenance().options_digest,
+            Some(hardened.digest())
+        );
+        assert_ne!(
+            hardened_identity.provenance().options_digest,
+            provenance.options_digest
+        );
+
+        let roundtrip: RunProvenance = serde_json::from_value(
+            serde_json::to_value(&provenance).expect("provenance should serialize"),
+        )
+        .expect("provenance should deserialize");
+        assert_eq!(roundtrip, provenance);
+    }
+
+    #[test]
+    fn child_identities_track_ancestry_and_differ_from_parents() {
+        let opts = RunIdOptions::default();
+        let mut root = fixture_identity();
+        let root_id = root.compute_run_id(opts);
+        assert_eq!(root.parent_id(), None);
+
+        let root_spec = fixture_intent();
+        let mut shard = RunIdentity {
+            unit_id: "unit.1.shard0".into(),
+            ..root.clone().with_parent(&root_id, &root_spec)
+        };
+        let shard_id = shard.compute_run_id(opts);
+        assert_ne!(shard_id, root_id);
+        assert_eq!(shard.parent_id(), Some(root_id.as_str()));
+
+        let shard_spec = root_spec.clone().with_parent(&root_id);
+        assert_eq!(shard.intent_id, compute_intent_id(&shard_spec));
+        let mut leaf = shard.clone().with_parent(&shard_id, &shard_spec);
+        assert_eq!(leaf.parent_id(), Some(shard_id.as_str()));
+        assert!(leaf.is_descendant_of(&shard_id));
+        assert!(leaf.is_descendant_of(&root_id));
+        let leaf_id = leaf.compute_run_id(opts);
+        assert!(!leaf.is_descendant_of(&leaf_id));
+        assert!(!root.is_descendant_of(&shard_id));
+        assert_ne!(leaf.intent_id, shard.intent_id);
+
+        assert_eq!(shard_spec.parent_run_id.as_deref(), Some(root_id.as_str()));
+        assert_ne!(
+            compute_intent_id(&root_spec),
+            compute_intent_id(&shard_spec)
+        );
+    }
+
+    #[test]
+    fn with_parent_alone_changes_run_id() {
+        let opts = RunIdOptions::default();
+        let intent = fixture_intent();
+        let mut root = RunIdentity {
+            intent_id: compute_intent_id(&intent),
+            ..fixture_identity()
+        };
+        let root_id = root.compute_run_id(opts);
+
+        let mut child = root.clone().with_parent(&root_id, &intent);
+        assert_eq!(child.unit_id, root.unit_id);
+        assert_ne!(child.intent_id, root.intent_id);
+        assert_ne!(child.compute_run_id(opts), root_id);
+
+        let mut sibling = root.clone().with_parent("run1_other", &intent);
+        assert_ne!(sibling.intent_id, child.intent_id);
+        assert_ne!(sibling.compute_run_id(opts), child.compute_run_id(opts));
+    }
+
     #[test]
     fn run_id_is_stable_for_same_identity() {
-        let id = fixture_identity();
+        let mut id = fixture_identity();
         let run_a = id.compute_run_id(RunIdOptions::default());
         let run_b = id.compute_run_id(RunIdOptions::default());
         assert_eq!(run_a, run_b);
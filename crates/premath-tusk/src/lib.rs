@@ -14,13 +14,19 @@ pub mod typestate;
 pub mod witness;
 
 pub use descent::{
-    CompatWitness, ContractibilityBasis, DescentCore, DescentPack, GlueMethod, GlueProposal,
-    GlueProposalSet, GlueResult, GlueSelectionFailure, ModeBinding,
+    CompatWitness, ContractibilityBasis, DescentCore, DescentPack, DescentPackBuildError,
+    DescentPackBuilder, GlueAuditReport, GlueMethod, GlueProposal, GlueProposalSet, GlueResult,
+    GlueSelectionFailure, ModeBinding,
 };
-pub use eval::{EvalOutcome, evaluate_descent_pack};
-pub use identity::{IntentSpec, RunIdOptions, RunIdentity, compute_intent_id};
+pub use eval::{
+    DEFAULT_MAX_GLUE_PROPOSALS, EvalOptions, EvalOutcome, evaluate_descent_pack,
+    evaluate_descent_pack_with_options,
+};
+pub use identity::{IntentSpec, RunIdOptions, RunIdentity, RunProvenance, compute_intent_id};
 pub use mapping::{
-    TuskDiagnosticFailure, TuskFailureKind, map_glue_selection_failure, map_tusk_failure_kind,
+    DiagnosticEventEmitter, InMemoryEventCollector, TuskDiagnosticFailure, TuskFailureKind,
+    glue_selection_diagnostic, map_glue_selection_failure, map_glue_selection_failure_to_emitter,
+    map_tusk_failure_kind,
 };
 pub use typestate::{
     CallSpecInput, HandoffObservationInput, JoinClosedInput, MutationReadyInput,
@@ -1,7 +1,7 @@
 use crate::descent::GlueSelectionFailure;
 use premath_kernel::witness::{GateFailure, failure_class, law_ref};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -12,6 +12,11 @@ pub enum TuskFailureKind {
     NoValidGlueProposal,
     NonContractibleSelection,
     ModeComparisonUnavailable,
+    TooManyGlueProposals,
+    /// Serialized envelope carries a `schemaVersion` this build cannot read.
+    UnsupportedEnvelopeSchema,
+    /// Serialized envelope is not JSON or does not match the envelope schema.
+    MalformedEnvelope,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -20,6 +25,13 @@ pub struct GateClassMapping {
     pub law_ref: &'static str,
 }
 
+impl GateClassMapping {
+    /// Whether the failure cites a Gate law, as opposed to rejected input.
+    pub fn is_law_failure(&self) -> bool {
+        self.law_ref != law_ref::NON_LAW
+    }
+}
+
 pub fn map_tusk_failure_kind(kind: TuskFailureKind) -> GateClassMapping {
     match kind {
         TuskFailureKind::StabilityMismatch => GateClassMapping {
@@ -38,6 +50,14 @@ pub fn map_tusk_failure_kind(kind: TuskFailureKind) -> GateClassMapping {
                 law_ref: law_ref::DESCENT,
             }
         }
+        // Exceeding the proposal budget rejects the input before any
+        // proposal is read, so no descent law was evaluated.
+        TuskFailureKind::TooManyGlueProposals
+        | TuskFailureKind::UnsupportedEnvelopeSchema
+        | TuskFailureKind::MalformedEnvelope => GateClassMapping {
+            class: failure_class::INPUT_FAILURE,
+            law_ref: law_ref::NON_LAW,
+        },
         TuskFailureKind::NonContractibleSelection => GateClassMapping {
             class: failure_class::GLUE_NON_CONTRACTIBLE,
             law_ref: law_ref::UNIQUENESS,
@@ -52,9 +72,79 @@ pub fn map_glue_selection_failure(failure: GlueSelectionFailure) -> TuskFailureK
         GlueSelectionFailure::ModeComparisonUnavailable => {
             TuskFailureKind::ModeComparisonUnavailable
         }
+        GlueSelectionFailure::TooManyProposals { .. } => TuskFailureKind::TooManyGlueProposals,
+    }
+}
+
+/// Sink for diagnostics as they are produced, instead of a returned list.
+pub trait DiagnosticEventEmitter {
+    fn emit(&mut self, event: TuskDiagnosticFailure);
+}
+
+/// Emitter that keeps every event in arrival order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InMemoryEventCollector {
+    pub events: Vec<TuskDiagnosticFailure>,
+}
+
+impl DiagnosticEventEmitter for InMemoryEventCollector {
+    fn emit(&mut self, event: TuskDiagnosticFailure) {
+        self.events.push(event);
+    }
+}
+
+/// The diagnostic `evaluate_descent_pack` reports for a glue selection
+/// failure. Callers with pack data at hand may attach further `context`.
+pub fn glue_selection_diagnostic(failure: GlueSelectionFailure) -> TuskDiagnosticFailure {
+    let (message, token_path, phase, context) = match failure {
+        GlueSelectionFailure::NoValidProposal => (
+            "no glue proposals provided".to_string(),
+            "descent.glueProposals",
+            "select_glue",
+            None,
+        ),
+        GlueSelectionFailure::NonContractibleSelection => (
+            "multiple glue proposals remain under v0 mode".to_string(),
+            "descent.glueProposals",
+            "select_glue",
+            None,
+        ),
+        GlueSelectionFailure::ModeComparisonUnavailable => (
+            "mode binding missing normalizer_id or policy_digest".to_string(),
+            "descent.core.mode",
+            "normalize",
+            None,
+        ),
+        GlueSelectionFailure::TooManyProposals { count, limit } => (
+            format!("{count} glue proposals exceed the limit of {limit}"),
+            "descent.glueProposals",
+            "select_glue",
+            Some(json!({
+                "proposalCount": count,
+                "maxProposals": limit,
+            })),
+        ),
+    };
+    TuskDiagnosticFailure {
+        kind: map_glue_selection_failure(failure),
+        message,
+        token_path: Some(token_path.to_string()),
+        context,
+        details: Some(json!({
+            "phase": phase,
+            "responsibleComponent": "world",
+        })),
     }
 }
 
+/// Emit the diagnostic for a glue selection failure.
+pub fn map_glue_selection_failure_to_emitter(
+    failure: GlueSelectionFailure,
+    emitter: &mut dyn DiagnosticEventEmitter,
+) {
+    emitter.emit(glue_selection_diagnostic(failure));
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct TuskDiagnosticFailure {
@@ -103,6 +193,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn in_memory_collector_captures_mapped_glue_failures() {
+        let failures = [
+            GlueSelectionFailure::NoValidProposal,
+            GlueSelectionFailure::NonContractibleSelection,
+            GlueSelectionFailure::ModeComparisonUnavailable,
+        ];
+        let mut collector = InMemoryEventCollector::default();
+        for failure in failures {
+            map_glue_selection_failure_to_emitter(failure, &mut collector);
+        }
+
+        let emitted: Vec<TuskFailureKind> =
+            collector.events.iter().map(|event| event.kind).collect();
+        let mapped: Vec<TuskFailureKind> = failures
+            .into_iter()
+            .map(map_glue_selection_failure)
+            .collect();
+        assert_eq!(emitted, mapped);
+        assert_eq!(collector.events[0].message, "no glue proposals provided");
+        assert_eq!(
+            collector.events[1].message,
+            "multiple glue proposals remain under v0 mode"
+        );
+        assert_eq!(
+            collector.events[2].message,
+            "mode binding missing normalizer_id or policy_digest"
+        );
+    }
+
     #[test]
     fn gate_class_mapping_is_spec_aligned() {
         let locality = map_tusk_failure_kind(TuskFailureKind::MissingRequiredRestrictions);
@@ -112,6 +232,18 @@ mod tests {
         let descent = map_tusk_failure_kind(TuskFailureKind::ModeComparisonUnavailable);
         assert_eq!(descent.class, failure_class::DESCENT_FAILURE);
         assert_eq!(descent.law_ref, law_ref::DESCENT);
+        assert!(descent.is_law_failure());
+
+        for kind in [
+            TuskFailureKind::TooManyGlueProposals,
+            TuskFailureKind::UnsupportedEnvelopeSchema,
+            TuskFailureKind::MalformedEnvelope,
+        ] {
+            let input = map_tusk_failure_kind(kind);
+            assert_eq!(input.class, failure_class::INPUT_FAILURE);
+            assert_eq!(input.law_ref, law_ref::NON_LAW);
+            assert!(!input.is_law_failure());
+        }
 
         let uniq = map_tusk_failure_kind(TuskFailureKind::NonContractibleSelection);
         assert_eq!(uniq.class, failure_class::GLUE_NON_CONTRACTIBLE);
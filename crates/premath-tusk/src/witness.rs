@@ -1,12 +1,18 @@
 use crate::identity::{RunIdOptions, RunIdentity};
-use crate::mapping::TuskDiagnosticFailure;
+use crate::mapping::{TuskDiagnosticFailure, TuskFailureKind};
 use premath_kernel::witness::GateFailure;
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+const ENVELOPE_SCHEMA_VERSION: u32 = 1;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct GateWitnessEnvelope {
     pub witness_schema: u32,
+    /// Tusk serialization version of this envelope, distinct from the Gate
+    /// `witnessSchema`; checked by `from_bytes`.
+    pub schema_version: u32,
     pub witness_kind: String,
     pub run_id: String,
     pub world_id: String,
@@ -26,8 +32,9 @@ impl GateWitnessEnvelope {
     pub fn accepted(identity: &RunIdentity, run_id_options: RunIdOptions) -> Self {
         Self {
             witness_schema: 1,
+            schema_version: Self::current_schema_version(),
             witness_kind: "gate".to_string(),
-            run_id: identity.compute_run_id(run_id_options),
+            run_id: identity.run_id(run_id_options),
             world_id: identity.world_id.clone(),
             context_id: identity.context_id.clone(),
             intent_id: identity.intent_id.clone(),
@@ -50,8 +57,9 @@ impl GateWitnessEnvelope {
         failures.sort();
         Self {
             witness_schema: 1,
+            schema_version: Self::current_schema_version(),
             witness_kind: "gate".to_string(),
-            run_id: identity.compute_run_id(run_id_options),
+            run_id: identity.run_id(run_id_options),
             world_id: identity.world_id.clone(),
             context_id: identity.context_id.clone(),
             intent_id: identity.intent_id.clone(),
@@ -82,12 +90,67 @@ impl GateWitnessEnvelope {
 
         Self::rejected(identity, run_id_options, failures)
     }
+
+    pub const fn current_schema_version() -> u32 {
+        ENVELOPE_SCHEMA_VERSION
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("gate witness envelope should serialize")
+    }
+
+    /// Decode an envelope, checking `schemaVersion` before the remaining
+    /// fields so envelopes from another tusk version are reported as such
+    /// rather than as a field-level parse error.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TuskDiagnosticFailure> {
+        let value: Value = serde_json::from_slice(bytes).map_err(|err| {
+            malformed_envelope_failure(format!("envelope is not valid json: {err}"))
+        })?;
+        let found = value.get("schemaVersion").cloned().unwrap_or(Value::Null);
+        if found != json!(Self::current_schema_version()) {
+            return Err(envelope_schema_failure(
+                format!(
+                    "unsupported gate witness envelope schemaVersion {found} (expected {})",
+                    Self::current_schema_version()
+                ),
+                found,
+            ));
+        }
+        serde_json::from_value(value).map_err(|err| {
+            malformed_envelope_failure(format!("envelope does not match schema: {err}"))
+        })
+    }
+}
+
+fn malformed_envelope_failure(message: String) -> TuskDiagnosticFailure {
+    TuskDiagnosticFailure {
+        kind: TuskFailureKind::MalformedEnvelope,
+        message,
+        token_path: None,
+        context: None,
+        details: None,
+    }
+}
+
+fn envelope_schema_failure(message: String, found: Value) -> TuskDiagnosticFailure {
+    TuskDiagnosticFailure {
+        kind: TuskFailureKind::UnsupportedEnvelopeSchema,
+        message,
+        token_path: Some("schemaVersion".to_string()),
+        context: Some(json!({
+            "schemaVersion": found,
+            "supportedSchemaVersion": GateWitnessEnvelope::current_schema_version(),
+        })),
+        details: None,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::mapping::TuskFailureKind;
+    use crate::descent::{DescentCore, DescentPack, GlueProposal, ModeBinding};
+    use crate::eval::evaluate_descent_pack;
+    use std::collections::BTreeMap;
 
     fn fixture_identity() -> RunIdentity {
         RunIdentity {
@@ -104,6 +167,8 @@ mod tests {
             normalizer_id: "norm.v1".into(),
             policy_digest: "policy.deadbeef".into(),
             cover_strategy_digest: None,
+            ancestor_run_ids: Vec::new(),
+            options_digest: None,
         }
     }
 
@@ -116,6 +181,70 @@ mod tests {
         assert!(env.failures.is_empty());
         assert_eq!(env.witness_kind, "gate");
         assert_eq!(env.witness_schema, 1);
+        assert_eq!(
+            env.schema_version,
+            GateWitnessEnvelope::current_schema_version()
+        );
+    }
+
+    #[test]
+    fn envelope_bytes_round_trip() {
+        let id = fixture_identity();
+        let env = GateWitnessEnvelope::from_diagnostics(
+            &id,
+            RunIdOptions::default(),
+            vec![TuskDiagnosticFailure {
+                kind: TuskFailureKind::NonContractibleSelection,
+                message: "two glues".into(),
+                token_path: Some("descent.glueProposals".into()),
+                context: None,
+                details: None,
+            }],
+        );
+        let bytes = env.to_bytes();
+        assert_eq!(
+            GateWitnessEnvelope::from_bytes(&bytes).expect("envelope should decode"),
+            env
+        );
+    }
+
+    #[test]
+    fn envelope_from_bytes_rejects_unknown_schema_version() {
+        let id = fixture_identity();
+        let env = GateWitnessEnvelope::accepted(&id, RunIdOptions::default());
+        let mut payload = serde_json::to_value(&env).expect("envelope should serialize");
+
+        payload["schemaVersion"] = json!(GateWitnessEnvelope::current_schema_version() + 1);
+        let failure = GateWitnessEnvelope::from_bytes(&serde_json::to_vec(&payload).unwrap())
+            .expect_err("future schema version should be rejected");
+        assert_eq!(failure.kind, TuskFailureKind::UnsupportedEnvelopeSchema);
+        assert_eq!(failure.context.expect("context")["schemaVersion"], json!(2));
+
+        payload.as_object_mut().unwrap().remove("schemaVersion");
+        let failure = GateWitnessEnvelope::from_bytes(&serde_json::to_vec(&payload).unwrap())
+            .expect_err("missing schema version should be rejected");
+        assert_eq!(failure.kind, TuskFailureKind::UnsupportedEnvelopeSchema);
+    }
+
+    #[test]
+    fn envelope_from_bytes_reports_malformed_envelopes() {
+        let failure = GateWitnessEnvelope::from_bytes(b"{not json")
+            .expect_err("invalid json should be rejected");
+        assert_eq!(failure.kind, TuskFailureKind::MalformedEnvelope);
+        assert_eq!(failure.token_path, None);
+
+        let id = fixture_identity();
+        let env = GateWitnessEnvelope::accepted(&id, RunIdOptions::default());
+        let mut payload = serde_json::to_value(&env).expect("envelope should serialize");
+        payload.as_object_mut().unwrap().remove("runId");
+        let failure = GateWitnessEnvelope::from_bytes(&serde_json::to_vec(&payload).unwrap())
+            .expect_err("envelope missing fields should be rejected");
+        assert_eq!(failure.kind, TuskFailureKind::MalformedEnvelope);
+        assert!(
+            failure
+                .message
+                .starts_with("envelope does not match schema")
+        );
     }
 
     #[test]
@@ -150,4 +279,46 @@ mod tests {
         // Deterministic failure order and witness IDs after sorting.
         assert_eq!(env_a.failures, env_b.failures);
     }
+
+    #[test]
+    fn candidate_proposal_order_does_not_change_envelope_bytes() {
+        let id = fixture_identity();
+        let proposal = |proposal_id: &str| GlueProposal {
+            proposal_id: proposal_id.to_string(),
+            payload: serde_json::json!({"id": proposal_id}),
+        };
+        let pack = |glue_proposals: Vec<GlueProposal>| DescentPack {
+            core: DescentCore {
+                cover_id: "cover:demo".to_string(),
+                locals: BTreeMap::from([("part:a".to_string(), serde_json::json!({"value": 1}))]),
+                compat: vec![],
+                mode: ModeBinding {
+                    normalizer_id: "norm.v1".to_string(),
+                    policy_digest: "policy.deadbeef".to_string(),
+                },
+            },
+            glue_proposals,
+            contractibility_basis: None,
+            core_fingerprint: None,
+        };
+        let envelope_bytes = |pack: &DescentPack| {
+            let outcome = evaluate_descent_pack(pack);
+            let env = GateWitnessEnvelope::from_diagnostics(
+                &id,
+                RunIdOptions::default(),
+                outcome.diagnostics,
+            );
+            serde_json::to_vec(&env).expect("envelope should serialize")
+        };
+
+        let forward = pack(vec![proposal("proposal:a"), proposal("proposal:b")]);
+        let reverse = pack(vec![proposal("proposal:b"), proposal("proposal:a")]);
+        assert_eq!(envelope_bytes(&forward), envelope_bytes(&reverse));
+
+        let outcome = evaluate_descent_pack(&reverse);
+        assert_eq!(
+            outcome.diagnostics[0].context.as_ref().expect("context")["candidateProposalIds"],
+            serde_json::json!(["proposal:a", "proposal:b"])
+        );
+    }
 }
@@ -437,16 +437,30 @@ def _require_schema_kind_family(value: Any, label: str) -> Dict[str, Any]:
             raise ValueError(
                 f"{label}.compatibilityAliases[{idx}].aliasKind must differ from canonicalKind"
             )
-        if replacement_kind != canonical_kind:
-            raise ValueError(
-                f"{label}.compatibilityAliases[{idx}].replacementKind must match canonicalKind"
-            )
         if alias_kind in aliases:
             raise ValueError(f"{label}.compatibilityAliases aliasKind values must be unique")
         aliases[alias_kind] = {
             "supportUntilEpoch": support_until_epoch,
             "replacementKind": replacement_kind,
         }
+    # An alias may replace into another alias of the family, as long as every
+    # chain reaches canonicalKind without revisiting an alias.
+    for alias_kind in aliases:
+        visited = set()
+        current = alias_kind
+        while current != canonical_kind:
+            if current in visited:
+                raise ValueError(
+                    f"{label}.compatibilityAliases chain from {alias_kind!r} cycles at {current!r}"
+                )
+            visited.add(current)
+            alias_row = aliases.get(current)
+            if alias_row is None:
+                raise ValueError(
+                    f"{label}.compatibilityAliases chain from {alias_kind!r} ends at "
+                    f"{current!r} instead of canonicalKind {canonical_kind!r}"
+                )
+            current = alias_row["replacementKind"]
     return {
         "canonicalKind": canonical_kind,
         "compatibilityAliases": aliases,
@@ -471,7 +485,21 @@ def _resolve_kind_in_family(
             f"{label} kind {kind!r} is not supported for schemaLifecycle.kindFamilies.{family_id} "
             f"(canonicalKind={canonical_kind!r})"
         )
+    # A chain is only supported while every hop is: its support window is the
+    # earliest supportUntilEpoch along the way.
     support_until_epoch = alias_row["supportUntilEpoch"]
+    visited = {kind}
+    current = alias_row["replacementKind"]
+    while current != canonical_kind:
+        hop_row = aliases.get(current)
+        if hop_row is None or current in visited:
+            raise ValueError(
+                f"{label} kind {kind!r} does not resolve to canonicalKind={canonical_kind!r} "
+                f"for schemaLifecycle.kindFamilies.{family_id}"
+            )
+        visited.add(current)
+        support_until_epoch = min(support_until_epoch, hop_row["supportUntilEpoch"])
+        current = hop_row["replacementKind"]
     if active_epoch > support_until_epoch:
         raise ValueError(
             f"{label} kind {kind!r} expired at supportUntilEpoch={support_until_epoch!r} "
@@ -946,6 +946,70 @@ class ControlPlaneContractTests(unittest.TestCase):
                 active_epoch="2026-07",
             )
 
+    def test_load_accepts_alias_chain_to_canonical_kind(self) -> None:
+        payload = _base_payload()
+        payload["schemaLifecycle"]["kindFamilies"]["requiredWitnessKind"][
+            "compatibilityAliases"
+        ].insert(
+            0,
+            {
+                "aliasKind": "ci.required.v-1",
+                "supportUntilEpoch": "2026-06",
+                "replacementKind": "ci.required.v0",
+            },
+        )
+        loaded = self._load(payload)
+        family = loaded["schemaLifecycle"]["kindFamilies"]["requiredWitnessKind"]
+        self.assertEqual(
+            control_plane_contract._resolve_kind_in_family(
+                "requiredWitnessKind",
+                family=family,
+                kind="ci.required.v-1",
+                active_epoch="2026-02",
+                label="requiredWitness.witnessKind",
+            ),
+            "ci.required.v1",
+        )
+
+    def test_load_rejects_cyclic_alias_chain(self) -> None:
+        payload = _base_payload()
+        aliases = payload["schemaLifecycle"]["kindFamilies"]["requiredWitnessKind"][
+            "compatibilityAliases"
+        ]
+        aliases[0]["replacementKind"] = "ci.required.v-1"
+        aliases.append(
+            {
+                "aliasKind": "ci.required.v-1",
+                "supportUntilEpoch": aliases[0]["supportUntilEpoch"],
+                "replacementKind": "ci.required.v0",
+            }
+        )
+        with self.assertRaisesRegex(ValueError, "cycles"):
+            self._load(payload)
+
+    def test_resolve_kind_rejects_chain_through_expired_hop(self) -> None:
+        family = {
+            "canonicalKind": "ci.required.v2",
+            "compatibilityAliases": {
+                "ci.required.v0": {
+                    "supportUntilEpoch": "2026-06",
+                    "replacementKind": "ci.required.v1",
+                },
+                "ci.required.v1": {
+                    "supportUntilEpoch": "2026-01",
+                    "replacementKind": "ci.required.v2",
+                },
+            },
+        }
+        with self.assertRaisesRegex(ValueError, "expired at supportUntilEpoch='2026-01'"):
+            control_plane_contract._resolve_kind_in_family(
+                "requiredWitnessKind",
+                family=family,
+                kind="ci.required.v0",
+                active_epoch="2026-02",
+                label="requiredWitness.witnessKind",
+            )
+
     def test_load_rejects_mixed_rollover_epochs(self) -> None:
         payload = _base_payload()
         payload["schemaLifecycle"]["kindFamilies"]["requiredWitnessKind"][